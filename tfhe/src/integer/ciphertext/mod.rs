@@ -22,6 +22,53 @@ pub type RadixCiphertext = BaseRadixCiphertext<ShortintCiphertext>;
 /// Structure containing a **compressed** ciphertext in radix decomposition.
 pub type CompressedRadixCiphertext = BaseRadixCiphertext<CompressedShortintCiphertext>;
 
+/// Structure containing a signed ciphertext in radix decomposition.
+///
+/// The integer is stored in two's complement, its sign is the msb of the last block.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct BaseSignedRadixCiphertext<Block> {
+    /// The blocks are stored from LSB to MSB
+    pub(crate) blocks: Vec<Block>,
+}
+
+impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
+    fn from(blocks: Vec<Block>) -> Self {
+        Self { blocks }
+    }
+}
+
+/// Structure containing a signed ciphertext in radix decomposition.
+pub type SignedRadixCiphertext = BaseSignedRadixCiphertext<ShortintCiphertext>;
+
+/// Structure containing a **compressed** signed ciphertext in radix decomposition.
+pub type CompressedSignedRadixCiphertext = BaseSignedRadixCiphertext<CompressedShortintCiphertext>;
+
+impl From<CompressedSignedRadixCiphertext> for SignedRadixCiphertext {
+    fn from(compressed: CompressedSignedRadixCiphertext) -> Self {
+        Self::from(
+            compressed
+                .blocks
+                .into_iter()
+                .map(From::from)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Reinterprets the two's complement bits of a signed ciphertext as an unsigned one.
+impl From<SignedRadixCiphertext> for RadixCiphertext {
+    fn from(signed: SignedRadixCiphertext) -> Self {
+        Self::from(signed.blocks)
+    }
+}
+
+/// Reinterprets the bits of an unsigned ciphertext as a two's complement signed one.
+impl From<RadixCiphertext> for SignedRadixCiphertext {
+    fn from(unsigned: RadixCiphertext) -> Self {
+        Self::from(unsigned.blocks)
+    }
+}
+
 impl From<CompressedRadixCiphertext> for RadixCiphertext {
     fn from(compressed: CompressedRadixCiphertext) -> Self {
         Self::from(
@@ -58,6 +105,31 @@ impl IntegerCiphertext for RadixCiphertext {
     }
 }
 
+impl IntegerCiphertext for SignedRadixCiphertext {
+    fn blocks(&self) -> &[ShortintCiphertext] {
+        &self.blocks
+    }
+    fn blocks_mut(&mut self) -> &mut [ShortintCiphertext] {
+        &mut self.blocks
+    }
+    fn from_blocks(blocks: Vec<ShortintCiphertext>) -> Self {
+        Self { blocks }
+    }
+}
+
+/// Ciphertexts in radix decomposition, either signed or unsigned.
+pub trait IntegerRadixCiphertext: IntegerCiphertext + Sync + Send {
+    const IS_SIGNED: bool;
+}
+
+impl IntegerRadixCiphertext for RadixCiphertext {
+    const IS_SIGNED: bool = false;
+}
+
+impl IntegerRadixCiphertext for SignedRadixCiphertext {
+    const IS_SIGNED: bool = true;
+}
+
 impl IntegerCiphertext for CrtCiphertext {
     fn blocks(&self) -> &[ShortintCiphertext] {
         &self.blocks
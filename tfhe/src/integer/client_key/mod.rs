@@ -9,9 +9,13 @@ pub(crate) mod utils;
 
 use crate::integer::ciphertext::{
     CompressedCrtCiphertext, CompressedRadixCiphertext, CrtCiphertext, RadixCiphertext,
+    SignedRadixCiphertext,
 };
 use crate::integer::client_key::utils::i_crt;
-use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl, AsLittleEndianWords};
+use crate::integer::encryption::{
+    encrypt_crt, encrypt_signed_radix_impl, encrypt_words_radix_impl, AsLittleEndianWords,
+    SignedInteger,
+};
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     CiphertextBig as ShortintCiphertext, ClientKey as ShortintClientKey,
@@ -181,6 +185,39 @@ impl ClientKey {
         encrypt_words_radix_impl(&self.key, message_words, num_blocks, encrypt_block)
     }
 
+    /// Encrypts a signed integer in radix decomposition, using two's complement
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_block = 4;
+    ///
+    /// let msg = -103_i8;
+    ///
+    /// // 2 * 4 = 8 bits of message
+    /// let ct = cks.encrypt_signed_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec: i8 = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_signed_radix<T: SignedInteger>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertext {
+        encrypt_signed_radix_impl(
+            &self.key,
+            message,
+            num_blocks,
+            crate::shortint::ClientKey::encrypt,
+        )
+    }
+
     /// Encrypts one block.
     ///
     /// This returns a shortint ciphertext.
@@ -248,6 +285,55 @@ impl ClientKey {
         );
     }
 
+    /// Decrypts a ciphertext encrypting a signed radix integer
+    ///
+    /// The value is sign-extended from the number of bits of the ciphertext,
+    /// then truncated to the output type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_block = 8;
+    ///
+    /// let msg = -29_123_i16;
+    ///
+    /// // Encryption
+    /// let ct = cks.encrypt_signed_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec: i64 = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(i64::from(msg), dec);
+    /// ```
+    pub fn decrypt_signed_radix<T: SignedInteger>(&self, ctxt: &SignedRadixCiphertext) -> T {
+        let message_modulus = self.key.parameters.message_modulus.0 as u128;
+        let bits_in_block = message_modulus.trailing_zeros() as usize;
+
+        let mut result = 0u128;
+        for (i, block) in ctxt.blocks.iter().enumerate() {
+            let shift = i * bits_in_block;
+            if shift >= 128 {
+                break;
+            }
+            // carries are added to the next blocks
+            let block_value = self.key.decrypt_message_and_carry(block) as u128;
+            result = result.wrapping_add(block_value << shift);
+        }
+
+        // Sign extension from the msb of the ciphertext
+        let num_bits = ctxt.blocks.len() * bits_in_block;
+        let result = if num_bits < 128 {
+            let unused_bits = 128 - num_bits;
+            ((result << unused_bits) as i128) >> unused_bits
+        } else {
+            result as i128
+        };
+        T::wrapping_from_i128(result)
+    }
+
     /// Decrypts a ciphertext encrypting an radix integer encrypted without padding
     ///
     /// # Example
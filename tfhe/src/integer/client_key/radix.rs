@@ -1,7 +1,8 @@
 //! Definition of the client key for radix decomposition
 
 use super::ClientKey;
-use crate::integer::RadixCiphertext;
+use crate::integer::encryption::SignedInteger;
+use crate::integer::{RadixCiphertext, SignedRadixCiphertext};
 use crate::shortint::{CiphertextBig as ShortintCiphertext, Parameters as ShortintParameters};
 
 use serde::{Deserialize, Serialize};
@@ -57,6 +58,14 @@ impl RadixClientKey {
         self.key.decrypt_radix(ciphertext)
     }
 
+    pub fn encrypt_signed<T: SignedInteger>(&self, message: T) -> SignedRadixCiphertext {
+        self.key.encrypt_signed_radix(message, self.num_blocks)
+    }
+
+    pub fn decrypt_signed<T: SignedInteger>(&self, ciphertext: &SignedRadixCiphertext) -> T {
+        self.key.decrypt_signed_radix(ciphertext)
+    }
+
     /// Returns the parameters used by the client key.
     pub fn parameters(&self) -> ShortintParameters {
         self.key.parameters()
@@ -1,4 +1,4 @@
-use super::U256;
+use super::StaticUnsignedBigInt;
 use crate::shortint::parameters::MessageModulus;
 
 pub trait AsLittleEndianWords {
@@ -79,8 +79,8 @@ impl AsLittleEndianWords for u128 {
     }
 }
 
-#[cfg(target_endian = "little")]
-impl AsLittleEndianWords for U256 {
+// The words are already stored in little endian order, whatever the target endianness
+impl<const N: usize> AsLittleEndianWords for StaticUnsignedBigInt<N> {
     type Iter<'a> = std::slice::Iter<'a, u64>;
 
     type IterMut<'a> = std::slice::IterMut<'a, u64>;
@@ -94,21 +94,33 @@ impl AsLittleEndianWords for U256 {
     }
 }
 
-#[cfg(target_endian = "big")]
-impl AsLittleEndianWords for U256 {
-    type Iter<'a> = core::iter::Rev<std::slice::Iter<'a, u64>>;
+/// Primitive signed integers, encrypted in two's complement
+pub trait SignedInteger: Copy {
+    /// Sign-extends the value to 128 bits
+    fn to_i128(self) -> i128;
 
-    type IterMut<'a> = core::iter::Rev<std::slice::IterMut<'a, u64>>;
-
-    fn as_little_endian_iter(&self) -> Self::Iter<'_> {
-        self.0.as_slice().iter().rev()
-    }
+    /// Keeps the low bits of a 128 bits value
+    fn wrapping_from_i128(value: i128) -> Self;
+}
 
-    fn as_little_endian_iter_mut(&mut self) -> Self::IterMut<'_> {
-        self.0.as_mut_slice().iter_mut().rev()
-    }
+macro_rules! impl_signed_integer {
+    ($($type:ty),*) => {
+        $(
+            impl SignedInteger for $type {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn wrapping_from_i128(value: i128) -> Self {
+                    value as Self
+                }
+            }
+        )*
+    };
 }
 
+impl_signed_integer!(i8, i16, i32, i64, i128);
+
 pub(crate) trait BlockEncryptionKey {
     fn parameters(&self) -> &crate::shortint::Parameters;
 }
@@ -221,6 +233,37 @@ where
     RadixCiphertextType::from(blocks)
 }
 
+/// Encrypts a signed number in two's complement under radix decomposition
+///
+/// The number is sign-extended if the ciphertext has more bits than the clear type.
+pub(crate) fn encrypt_signed_radix_impl<BlockKey, Block, RadixCiphertextType, T, F>(
+    encrypting_key: &BlockKey,
+    message: T,
+    num_blocks: usize,
+    encrypt_block: F,
+) -> RadixCiphertextType
+where
+    T: SignedInteger,
+    BlockKey: BlockEncryptionKey,
+    F: Fn(&BlockKey, u64) -> Block,
+    RadixCiphertextType: From<Vec<Block>>,
+{
+    let message_modulus = encrypting_key.parameters().message_modulus.0 as u64;
+    let bits_in_block = message_modulus.trailing_zeros() as usize;
+    let message = message.to_i128();
+
+    let blocks = (0..num_blocks)
+        .map(|i| {
+            // The arithmetic shift fills the bits above the 128th with the sign bit
+            let shift = (i * bits_in_block).min(127);
+            let block_value = (message >> shift) as u64 % message_modulus;
+            encrypt_block(encrypting_key, block_value)
+        })
+        .collect::<Vec<_>>();
+
+    RadixCiphertextType::from(blocks)
+}
+
 pub(crate) fn encrypt_crt<BlockKey, Block, CrtCiphertextType, F>(
     encrypting_key: &BlockKey,
     message: u64,
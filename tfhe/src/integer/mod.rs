@@ -59,13 +59,18 @@ pub mod keycache;
 pub mod parameters;
 pub mod public_key;
 pub mod server_key;
+pub mod static_unsigned;
 pub mod u256;
 pub mod wopbs;
 
-pub use ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext};
+pub use ciphertext::{
+    CrtCiphertext, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext,
+    SignedRadixCiphertext,
+};
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
 pub use public_key::{CompressedPublicKey, PublicKey};
-pub use server_key::{CheckError, ServerKey};
+pub use server_key::{CheckError, CompressedServerKey, ServerKey};
+pub use static_unsigned::StaticUnsignedBigInt;
 pub use u256::U256;
 
 /// Generate a couple of client and server keys with given parameters
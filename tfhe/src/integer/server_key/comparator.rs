@@ -1,7 +1,10 @@
 use rayon::prelude::*;
 
 use super::ServerKey;
-use crate::integer::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::{
+    IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext,
+};
 use crate::shortint::server_key::LookupTableOwned;
 
 /// Simple enum to select whether we are looking for the min or the max
@@ -10,8 +13,8 @@ enum MinMaxSelector {
     Min,
 }
 
-fn has_non_zero_carries(ct: &RadixCiphertext) -> bool {
-    ct.blocks
+pub(crate) fn has_non_zero_carries<T: IntegerRadixCiphertext>(ct: &T) -> bool {
+    ct.blocks()
         .iter()
         .any(|block| block.degree.0 >= block.message_modulus.0)
 }
@@ -27,12 +30,13 @@ pub struct Comparator<'a> {
     mask_accumulator: LookupTableOwned,
     x_accumulator: LookupTableOwned,
     y_accumulator: LookupTableOwned,
+    sign_bit_flip_accumulator: LookupTableOwned,
 }
 
 impl<'a> Comparator<'a> {
-    const IS_INFERIOR: u64 = 0;
-    const IS_EQUAL: u64 = 1;
-    const IS_SUPERIOR: u64 = 2;
+    pub(crate) const IS_INFERIOR: u64 = 0;
+    pub(crate) const IS_EQUAL: u64 = 1;
+    pub(crate) const IS_SUPERIOR: u64 = 2;
 
     /// Creates a new Comparator for the given ServerKey
     ///
@@ -107,6 +111,11 @@ impl<'a> Comparator<'a> {
             }
         });
 
+        // Flipping the sign bit maps the two's complement order to the unsigned order
+        let sign_bit_flip_accumulator = server_key
+            .key
+            .generate_accumulator(|x| (x ^ (message_modulus / 2)) % message_modulus);
+
         Self {
             server_key,
             sign_accumulator,
@@ -114,6 +123,7 @@ impl<'a> Comparator<'a> {
             mask_accumulator,
             x_accumulator,
             y_accumulator,
+            sign_bit_flip_accumulator,
         }
     }
 
@@ -159,7 +169,7 @@ impl<'a> Comparator<'a> {
     // - 0 if lhs < rhs
     // - 1 if lhs == rhs
     // - 2 if lhs > rhs
-    fn compare_block_assign(
+    pub(crate) fn compare_block_assign(
         &self,
         lhs: &mut crate::shortint::CiphertextBig,
         rhs: &crate::shortint::CiphertextBig,
@@ -187,6 +197,69 @@ impl<'a> Comparator<'a> {
         self.server_key.key.unchecked_scalar_add_assign(lhs, 1);
     }
 
+    /// Reduces the block comparisons (least significant first) into a single one
+    ///
+    /// Each comparison is expected to be one of
+    /// `Self::IS_INFERIOR`, `Self::IS_EQUAL` or `Self::IS_SUPERIOR`
+    pub(crate) fn reduce_signs(
+        &self,
+        comparisons: Vec<crate::shortint::CiphertextBig>,
+    ) -> crate::shortint::CiphertextBig {
+        // Iterate block from most significant to less significant
+        let mut selection = comparisons.last().cloned().unwrap();
+        for comparison in comparisons[0..comparisons.len() - 1].iter().rev() {
+            self.server_key
+                .key
+                .unchecked_scalar_mul_assign(&mut selection, 4);
+            self.server_key
+                .key
+                .unchecked_add_assign(&mut selection, comparison);
+
+            self.server_key
+                .key
+                .apply_lookup_table_assign(&mut selection, &self.selection_accumulator);
+        }
+
+        selection
+    }
+
+    /// Same as [Self::reduce_signs] but using a tree reduction
+    pub(crate) fn reduce_signs_parallelized(
+        &self,
+        mut comparisons: Vec<crate::shortint::CiphertextBig>,
+    ) -> crate::shortint::CiphertextBig {
+        let mut comparisons_2 = Vec::with_capacity(comparisons.len() / 2);
+        while comparisons.len() != 1 {
+            comparisons
+                .par_chunks_exact(2)
+                .map(|chunk| {
+                    let (low, high) = (&chunk[0], &chunk[1]);
+                    let mut high = high.clone();
+
+                    // We don't use pack_block_assign as the offset '4' does not depend on params
+                    self.server_key
+                        .key
+                        .unchecked_scalar_mul_assign(&mut high, 4);
+                    self.server_key.key.unchecked_add_assign(&mut high, low);
+
+                    self.server_key
+                        .key
+                        .apply_lookup_table_assign(&mut high, &self.selection_accumulator);
+                    high
+                })
+                .collect_into_vec(&mut comparisons_2);
+
+            if (comparisons.len() % 2) == 1 {
+                comparisons_2.push(comparisons[comparisons.len() - 1].clone());
+            }
+
+            std::mem::swap(&mut comparisons_2, &mut comparisons);
+        }
+        let selection = comparisons.drain(..).next().unwrap();
+
+        selection
+    }
+
     /// returns:
     ///
     /// - 0 if lhs < rhs
@@ -238,22 +311,7 @@ impl<'a> Comparator<'a> {
             comparisons
         };
 
-        // Iterate block from most significant to less significant
-        let mut selection = comparisons.last().cloned().unwrap();
-        for comparison in comparisons[0..comparisons.len() - 1].iter().rev() {
-            self.server_key
-                .key
-                .unchecked_scalar_mul_assign(&mut selection, 4);
-            self.server_key
-                .key
-                .unchecked_add_assign(&mut selection, comparison);
-
-            self.server_key
-                .key
-                .apply_lookup_table_assign(&mut selection, &self.selection_accumulator);
-        }
-
-        selection
+        self.reduce_signs(comparisons)
     }
 
     /// Expects the carry buffers to be empty
@@ -269,7 +327,7 @@ impl<'a> Comparator<'a> {
         let num_block = lhs.blocks.len();
         let num_block_is_odd = num_block % 2;
 
-        let mut comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
+        let comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
             let mut comparisons = Vec::with_capacity(num_block);
             lhs.blocks
                 .par_iter()
@@ -307,36 +365,7 @@ impl<'a> Comparator<'a> {
             comparisons
         };
 
-        let mut comparisons_2 = Vec::with_capacity(comparisons.len() / 2);
-        while comparisons.len() != 1 {
-            comparisons
-                .par_chunks_exact(2)
-                .map(|chunk| {
-                    let (low, high) = (&chunk[0], &chunk[1]);
-                    let mut high = high.clone();
-
-                    // We don't use pack_block_assign as the offset '4' does not depend on params
-                    self.server_key
-                        .key
-                        .unchecked_scalar_mul_assign(&mut high, 4);
-                    self.server_key.key.unchecked_add_assign(&mut high, low);
-
-                    self.server_key
-                        .key
-                        .apply_lookup_table_assign(&mut high, &self.selection_accumulator);
-                    high
-                })
-                .collect_into_vec(&mut comparisons_2);
-
-            if (comparisons.len() % 2) == 1 {
-                comparisons_2.push(comparisons[comparisons.len() - 1].clone());
-            }
-
-            std::mem::swap(&mut comparisons_2, &mut comparisons);
-        }
-        let selection = comparisons.drain(..).next().unwrap();
-
-        selection
+        self.reduce_signs_parallelized(comparisons)
     }
 
     fn smart_compare(
@@ -412,23 +441,60 @@ impl<'a> Comparator<'a> {
         RadixCiphertext { blocks: result }
     }
 
+    /// Returns a block encrypting `message_modulus` if `lhs < rhs` and 0 otherwise
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_min_max_mask_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let mut mask = self.unchecked_compare_parallelized(lhs, rhs);
+        self.server_key
+            .key
+            .apply_lookup_table_assign(&mut mask, &self.mask_accumulator);
+        mask
+    }
+
     /// Expects the carry buffers to be empty
     fn unchecked_min_or_max_parallelized(
         &self,
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
         selector: MinMaxSelector,
+    ) -> RadixCiphertext {
+        let mask = self.unchecked_min_max_mask_parallelized(lhs, rhs);
+        self.unchecked_select_with_mask_parallelized(lhs, rhs, &mask, selector)
+    }
+
+    /// Computes both the min and the max of the inputs, sharing the comparison
+    ///
+    /// Expects the carry buffers to be empty
+    pub(crate) fn unchecked_min_max_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let mask = self.unchecked_min_max_mask_parallelized(lhs, rhs);
+        rayon::join(
+            || self.unchecked_select_with_mask_parallelized(lhs, rhs, &mask, MinMaxSelector::Min),
+            || self.unchecked_select_with_mask_parallelized(lhs, rhs, &mask, MinMaxSelector::Max),
+        )
+    }
+
+    /// Selects the min or the max of the inputs given the mask of their comparison
+    fn unchecked_select_with_mask_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        mask: &crate::shortint::CiphertextBig,
+        selector: MinMaxSelector,
     ) -> RadixCiphertext {
         let (x_accumulator, y_accumulator) = match selector {
             MinMaxSelector::Max => (&self.x_accumulator, &self.y_accumulator),
             MinMaxSelector::Min => (&self.y_accumulator, &self.x_accumulator),
         };
 
-        let mut mask = self.unchecked_compare_parallelized(lhs, rhs);
-        self.server_key
-            .key
-            .apply_lookup_table_assign(&mut mask, &self.mask_accumulator);
-
         let blocks = lhs
             .blocks
             .par_iter()
@@ -436,14 +502,14 @@ impl<'a> Comparator<'a> {
             .map(|(lhs_block, rhs_block)| {
                 let (maybe_x, maybe_y) = rayon::join(
                     || {
-                        let mut lhs_masked = self.server_key.key.unchecked_add(lhs_block, &mask);
+                        let mut lhs_masked = self.server_key.key.unchecked_add(lhs_block, mask);
                         self.server_key
                             .key
                             .apply_lookup_table_assign(&mut lhs_masked, x_accumulator);
                         lhs_masked
                     },
                     || {
-                        let mut rhs_masked = self.server_key.key.unchecked_add(rhs_block, &mask);
+                        let mut rhs_masked = self.server_key.key.unchecked_add(rhs_block, mask);
                         self.server_key
                             .key
                             .apply_lookup_table_assign(&mut rhs_masked, y_accumulator);
@@ -494,12 +560,11 @@ impl<'a> Comparator<'a> {
         self.unchecked_min_or_max_parallelized(lhs, rhs, selector)
     }
 
-    fn map_comparison_result<F>(
+    fn map_comparison_result_to_block<F>(
         &self,
         comparison: crate::shortint::CiphertextBig,
         sign_result_handler_fn: F,
-        num_blocks: usize,
-    ) -> RadixCiphertext
+    ) -> crate::shortint::CiphertextBig
     where
         F: Fn(u64) -> u64,
     {
@@ -507,7 +572,19 @@ impl<'a> Comparator<'a> {
             .server_key
             .key
             .generate_accumulator(sign_result_handler_fn);
-        let result_block = self.server_key.key.apply_lookup_table(&comparison, &acc);
+        self.server_key.key.apply_lookup_table(&comparison, &acc)
+    }
+
+    fn map_comparison_result<F>(
+        &self,
+        comparison: crate::shortint::CiphertextBig,
+        sign_result_handler_fn: F,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let result_block = self.map_comparison_result_to_block(comparison, sign_result_handler_fn);
 
         let mut blocks = Vec::with_capacity(num_blocks);
         blocks.push(result_block);
@@ -600,6 +677,15 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn unchecked_ne(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_comparison_impl(
+            Self::unchecked_compare,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn unchecked_max(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
         self.unchecked_min_or_max(lhs, rhs, MinMaxSelector::Max)
     }
@@ -677,6 +763,19 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn unchecked_ne_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_comparison_impl(
+            Self::unchecked_compare_parallelized,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn unchecked_max_parallelized(
         &self,
         lhs: &RadixCiphertext,
@@ -762,6 +861,19 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn smart_ne(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.smart_comparison_impl(
+            Self::smart_compare,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn smart_max(
         &self,
         lhs: &mut RadixCiphertext,
@@ -847,6 +959,19 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn smart_ne_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.smart_comparison_impl(
+            Self::smart_compare_parallelized,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn smart_max_parallelized(
         &self,
         lhs: &mut RadixCiphertext,
@@ -862,6 +987,1345 @@ impl<'a> Comparator<'a> {
     ) -> RadixCiphertext {
         self.smart_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
+
+    //======================================
+    // Block returning operations
+    //======================================
+
+    pub fn unchecked_eq_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_ne_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_gt_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn unchecked_ge_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn unchecked_lt_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn unchecked_le_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    pub fn unchecked_eq_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_ne_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_gt_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn unchecked_ge_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn unchecked_lt_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn unchecked_le_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    pub fn smart_eq_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn smart_ne_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn smart_gt_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn smart_ge_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn smart_lt_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn smart_le_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    pub fn smart_eq_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn smart_ne_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn smart_gt_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn smart_ge_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn smart_lt_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn smart_le_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let comparison = self.smart_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    //======================================
+    // Signed operations
+    //======================================
+
+    /// Flips the sign bit of the ciphertext, which is the msb of its last block
+    ///
+    /// Expects the carry buffers to be empty
+    fn flip_sign_bit_assign(&self, ct: &mut RadixCiphertext) {
+        if let Some(last_block) = ct.blocks.last_mut() {
+            self.server_key
+                .key
+                .apply_lookup_table_assign(last_block, &self.sign_bit_flip_accumulator);
+        }
+    }
+
+    /// Returns the operands with their sign bit flipped, comparing them
+    /// as unsigned integers gives the result of the signed comparison
+    ///
+    /// Expects the carry buffers to be empty
+    fn flip_sign_bits(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let mut lhs = RadixCiphertext::from(lhs.blocks().to_vec());
+        let mut rhs = RadixCiphertext::from(rhs.blocks().to_vec());
+        self.flip_sign_bit_assign(&mut lhs);
+        self.flip_sign_bit_assign(&mut rhs);
+        (lhs, rhs)
+    }
+
+    /// Expects the carry buffers to be empty
+    fn flip_sign_bits_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        rayon::join(
+            || {
+                let mut lhs = RadixCiphertext::from(lhs.blocks().to_vec());
+                self.flip_sign_bit_assign(&mut lhs);
+                lhs
+            },
+            || {
+                let mut rhs = RadixCiphertext::from(rhs.blocks().to_vec());
+                self.flip_sign_bit_assign(&mut rhs);
+                rhs
+            },
+        )
+    }
+
+    fn propagate_signed_carries(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) {
+        if has_non_zero_carries(lhs) {
+            self.server_key.full_propagate(lhs);
+        }
+        if has_non_zero_carries(rhs) {
+            self.server_key.full_propagate(rhs);
+        }
+    }
+
+    fn propagate_signed_carries_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) {
+        rayon::join(
+            || {
+                if has_non_zero_carries(lhs) {
+                    self.server_key.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if has_non_zero_carries(rhs) {
+                    self.server_key.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+    }
+
+    pub fn unchecked_signed_gt(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_gt(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_ge(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_ge(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_lt(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_lt(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_le(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_le(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_max(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        let mut result = self.unchecked_max(&lhs, &rhs);
+        self.flip_sign_bit_assign(&mut result);
+        SignedRadixCiphertext::from(result)
+    }
+
+    pub fn unchecked_signed_min(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        let mut result = self.unchecked_min(&lhs, &rhs);
+        self.flip_sign_bit_assign(&mut result);
+        SignedRadixCiphertext::from(result)
+    }
+
+    pub fn unchecked_signed_gt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_gt_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_ge_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_ge_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_lt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_lt_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_le_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_le_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_max_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        let mut result = self.unchecked_max_parallelized(&lhs, &rhs);
+        self.flip_sign_bit_assign(&mut result);
+        SignedRadixCiphertext::from(result)
+    }
+
+    pub fn unchecked_signed_min_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        let mut result = self.unchecked_min_parallelized(&lhs, &rhs);
+        self.flip_sign_bit_assign(&mut result);
+        SignedRadixCiphertext::from(result)
+    }
+
+    pub fn smart_signed_gt(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_gt(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_ge(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_lt(lhs, rhs)
+    }
+
+    pub fn smart_signed_le(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_le(lhs, rhs)
+    }
+
+    pub fn smart_signed_max(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_max(lhs, rhs)
+    }
+
+    pub fn smart_signed_min(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_min(lhs, rhs)
+    }
+
+    pub fn smart_signed_gt_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_gt_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_ge_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_lt_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_le_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_le_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_max_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_max_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_min_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_min_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_gt_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_gt_as_block(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_ge_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_ge_as_block(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_lt_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_lt_as_block(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_le_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits(lhs, rhs);
+        self.unchecked_le_as_block(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_gt_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_gt_as_block_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_ge_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_ge_as_block_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_lt_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_lt_as_block_parallelized(&lhs, &rhs)
+    }
+
+    pub fn unchecked_signed_le_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        let (lhs, rhs) = self.flip_sign_bits_parallelized(lhs, rhs);
+        self.unchecked_le_as_block_parallelized(&lhs, &rhs)
+    }
+
+    pub fn smart_signed_gt_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_gt_as_block(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_ge_as_block(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_lt_as_block(lhs, rhs)
+    }
+
+    pub fn smart_signed_le_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries(lhs, rhs);
+        self.unchecked_signed_le_as_block(lhs, rhs)
+    }
+
+    pub fn smart_signed_gt_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_gt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_ge_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_lt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_le_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> crate::shortint::CiphertextBig {
+        self.propagate_signed_carries_parallelized(lhs, rhs);
+        self.unchecked_signed_le_as_block_parallelized(lhs, rhs)
+    }
+
+    //======================================
+    // Scalar operations
+    //======================================
+
+    /// Returns the (possibly packed) blocks of lhs, each paired
+    /// with the clear value it has to be compared to
+    ///
+    /// Expects the carry buffers to be empty
+    fn scalar_comparison_inputs(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar_blocks: &[u64],
+    ) -> Vec<(crate::shortint::CiphertextBig, u64)> {
+        debug_assert_eq!(lhs.blocks.len(), scalar_blocks.len());
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+
+        if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
+            lhs.blocks
+                .iter()
+                .cloned()
+                .zip(scalar_blocks.iter().copied())
+                .collect()
+        } else {
+            let mut lhs_chunks_iter = lhs.blocks.chunks_exact(2);
+            let mut scalar_chunks_iter = scalar_blocks.chunks_exact(2);
+            let mut inputs =
+                Vec::with_capacity(lhs_chunks_iter.len() + lhs_chunks_iter.remainder().len());
+
+            for (lhs_chunk, scalar_chunk) in
+                lhs_chunks_iter.by_ref().zip(scalar_chunks_iter.by_ref())
+            {
+                let packed_lhs = self.pack_block_chunk(lhs_chunk);
+                let packed_scalar = scalar_chunk[0] + scalar_chunk[1] * message_modulus;
+                inputs.push((packed_lhs, packed_scalar));
+            }
+
+            if let ([last_lhs_block], [last_scalar_block]) =
+                (lhs_chunks_iter.remainder(), scalar_chunks_iter.remainder())
+            {
+                inputs.push((last_lhs_block.clone(), *last_scalar_block));
+            }
+
+            inputs
+        }
+    }
+
+    // block will be assigned
+    // - 0 if block < scalar
+    // - 1 if block == scalar
+    // - 2 if block > scalar
+    fn scalar_compare_block_assign(&self, block: &mut crate::shortint::CiphertextBig, scalar: u64) {
+        let acc = self.server_key.key.generate_accumulator(|x| {
+            if x < scalar {
+                Self::IS_INFERIOR
+            } else if x == scalar {
+                Self::IS_EQUAL
+            } else {
+                Self::IS_SUPERIOR
+            }
+        });
+        self.server_key.key.apply_lookup_table_assign(block, &acc);
+    }
+
+    /// returns:
+    ///
+    /// - 0 if lhs < scalar
+    /// - 1 if lhs == scalar
+    /// - 2 if lhs > scalar
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_compare<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: &T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = lhs.blocks.len();
+        if !self.server_key.scalar_fits_in_blocks(scalar, num_blocks) {
+            return self.server_key.key.create_trivial(Self::IS_INFERIOR);
+        }
+
+        let scalar_blocks = self
+            .server_key
+            .scalar_block_decomposition(scalar, num_blocks);
+        let comparisons = self
+            .scalar_comparison_inputs(lhs, &scalar_blocks)
+            .into_iter()
+            .map(|(mut block, scalar_block)| {
+                self.scalar_compare_block_assign(&mut block, scalar_block);
+                block
+            })
+            .collect();
+
+        self.reduce_signs(comparisons)
+    }
+
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_compare_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: &T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = lhs.blocks.len();
+        if !self.server_key.scalar_fits_in_blocks(scalar, num_blocks) {
+            return self.server_key.key.create_trivial(Self::IS_INFERIOR);
+        }
+
+        let scalar_blocks = self
+            .server_key
+            .scalar_block_decomposition(scalar, num_blocks);
+        let comparisons = self
+            .scalar_comparison_inputs(lhs, &scalar_blocks)
+            .into_par_iter()
+            .map(|(mut block, scalar_block)| {
+                self.scalar_compare_block_assign(&mut block, scalar_block);
+                block
+            })
+            .collect();
+
+        self.reduce_signs_parallelized(comparisons)
+    }
+
+    /// Returns the accumulators that select, for each block, either the
+    /// block of lhs (when the condition is 1) or the corresponding scalar block
+    fn scalar_selection_accumulators<T>(
+        &self,
+        scalar: &T,
+        num_blocks: usize,
+    ) -> Vec<LookupTableOwned>
+    where
+        T: AsLittleEndianWords,
+    {
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        self.server_key
+            .scalar_block_decomposition(scalar, num_blocks)
+            .into_iter()
+            .map(|scalar_block| {
+                self.server_key.key.generate_accumulator(|x| {
+                    if x >= message_modulus {
+                        (x - message_modulus) % message_modulus
+                    } else {
+                        scalar_block
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Expects the carry buffers to be empty
+    ///
+    /// When the scalar does not fit in the ciphertext, the max saturates
+    /// to the maximum value the ciphertext can hold
+    fn unchecked_scalar_min_or_max<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+        selector: MinMaxSelector,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = lhs.blocks.len();
+        if !self.server_key.scalar_fits_in_blocks(&scalar, num_blocks) {
+            return match selector {
+                MinMaxSelector::Max => self.server_key.create_trivial_max_radix(num_blocks),
+                MinMaxSelector::Min => lhs.clone(),
+            };
+        }
+
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        let keep_lhs = match selector {
+            MinMaxSelector::Max => self
+                .map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR)),
+            MinMaxSelector::Min => self
+                .map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR)),
+        };
+        let keep_lhs_mask = self
+            .server_key
+            .key
+            .unchecked_scalar_mul(&keep_lhs, self.server_key.key.message_modulus.0 as u8);
+
+        let accumulators = self.scalar_selection_accumulators(&scalar, num_blocks);
+        let blocks = lhs
+            .blocks
+            .iter()
+            .zip(accumulators.iter())
+            .map(|(block, acc)| {
+                let mut block = self.server_key.key.unchecked_add(block, &keep_lhs_mask);
+                self.server_key
+                    .key
+                    .apply_lookup_table_assign(&mut block, acc);
+                block
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_min_or_max_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+        selector: MinMaxSelector,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        let num_blocks = lhs.blocks.len();
+        if !self.server_key.scalar_fits_in_blocks(&scalar, num_blocks) {
+            return match selector {
+                MinMaxSelector::Max => self.server_key.create_trivial_max_radix(num_blocks),
+                MinMaxSelector::Min => lhs.clone(),
+            };
+        }
+
+        let (comparison, accumulators) = rayon::join(
+            || self.unchecked_scalar_compare_parallelized(lhs, &scalar),
+            || self.scalar_selection_accumulators(&scalar, num_blocks),
+        );
+        let keep_lhs = match selector {
+            MinMaxSelector::Max => self
+                .map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR)),
+            MinMaxSelector::Min => self
+                .map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR)),
+        };
+        let keep_lhs_mask = self
+            .server_key
+            .key
+            .unchecked_scalar_mul(&keep_lhs, self.server_key.key.message_modulus.0 as u8);
+
+        let blocks = lhs
+            .blocks
+            .par_iter()
+            .zip(accumulators.par_iter())
+            .map(|(block, acc)| {
+                let mut block = self.server_key.key.unchecked_add(block, &keep_lhs_mask);
+                self.server_key
+                    .key
+                    .apply_lookup_table_assign(&mut block, acc);
+                block
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    fn smart_scalar_propagate(&self, lhs: &mut RadixCiphertext) {
+        if has_non_zero_carries(lhs) {
+            self.server_key.full_propagate(lhs);
+        }
+    }
+
+    fn smart_scalar_propagate_parallelized(&self, lhs: &mut RadixCiphertext) {
+        if has_non_zero_carries(lhs) {
+            self.server_key.full_propagate_parallelized(lhs);
+        }
+    }
+
+    //======================================
+    // Unchecked Scalar Single-Threaded operations
+    //======================================
+
+    pub fn unchecked_scalar_eq<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_scalar_ne<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_scalar_gt<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn unchecked_scalar_ge<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn unchecked_scalar_lt<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn unchecked_scalar_le<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    pub fn unchecked_scalar_max<T>(&self, lhs: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        self.unchecked_scalar_min_or_max(lhs, scalar, MinMaxSelector::Max)
+    }
+
+    pub fn unchecked_scalar_min<T>(&self, lhs: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        self.unchecked_scalar_min_or_max(lhs, scalar, MinMaxSelector::Min)
+    }
+
+    //======================================
+    // Unchecked Scalar Multi-Threaded operations
+    //======================================
+
+    pub fn unchecked_scalar_eq_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_scalar_ne_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x != Self::IS_EQUAL))
+    }
+
+    pub fn unchecked_scalar_gt_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_SUPERIOR))
+    }
+
+    pub fn unchecked_scalar_ge_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR)
+        })
+    }
+
+    pub fn unchecked_scalar_lt_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| u64::from(x == Self::IS_INFERIOR))
+    }
+
+    pub fn unchecked_scalar_le_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, &scalar);
+        self.map_comparison_result_to_block(comparison, |x| {
+            u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR)
+        })
+    }
+
+    pub fn unchecked_scalar_max_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        self.unchecked_scalar_min_or_max_parallelized(lhs, scalar, MinMaxSelector::Max)
+    }
+
+    pub fn unchecked_scalar_min_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        self.unchecked_scalar_min_or_max_parallelized(lhs, scalar, MinMaxSelector::Min)
+    }
+
+    //======================================
+    // Smart Scalar Single-Threaded operations
+    //======================================
+
+    pub fn smart_scalar_eq<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_eq(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ne<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_ne(lhs, scalar)
+    }
+
+    pub fn smart_scalar_gt<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_gt(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ge<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_ge(lhs, scalar)
+    }
+
+    pub fn smart_scalar_lt<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_lt(lhs, scalar)
+    }
+
+    pub fn smart_scalar_le<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_le(lhs, scalar)
+    }
+
+    pub fn smart_scalar_max<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_max(lhs, scalar)
+    }
+
+    pub fn smart_scalar_min<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate(lhs);
+        self.unchecked_scalar_min(lhs, scalar)
+    }
+
+    //======================================
+    // Smart Scalar Multi-Threaded operations
+    //======================================
+
+    pub fn smart_scalar_eq_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_eq_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ne_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_ne_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_gt_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_gt_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ge_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_ge_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_lt_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_lt_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_le_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> crate::shortint::CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_le_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_max_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_max_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_min_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        self.smart_scalar_propagate_parallelized(lhs);
+        self.unchecked_scalar_min_parallelized(lhs, scalar)
+    }
 }
 
 #[cfg(test)]
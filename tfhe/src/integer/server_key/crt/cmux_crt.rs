@@ -0,0 +1,95 @@
+use crate::integer::{CrtCiphertext, ServerKey};
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false).
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages and a condition
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    /// let condition = cks.encrypt_one_block(0);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_if_then_else(&condition, &ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(clear_2, res);
+    /// ```
+    pub fn unchecked_crt_if_then_else(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &CrtCiphertext,
+        false_ct: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_if_then_else_impl(condition, true_ct, false_ct)
+    }
+
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false).
+    ///
+    /// The messages of the inputs are extracted if needed.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages and a condition
+    /// let mut ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let mut ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    /// let condition = cks.encrypt_one_block(1);
+    ///
+    /// // Fill the carries of the first message
+    /// sks.unchecked_crt_add_assign(&mut ctxt_1, &ctxt_2);
+    ///
+    /// let ctxt_res = sks.smart_crt_if_then_else(&condition, &mut ctxt_1, &mut ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!((clear_1 + clear_2) % 30, res);
+    /// ```
+    pub fn smart_crt_if_then_else(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &mut CrtCiphertext,
+        false_ct: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        if self.crt_has_carries(true_ct) {
+            self.full_extract_message_assign(true_ct);
+        }
+        if self.crt_has_carries(false_ct) {
+            self.full_extract_message_assign(false_ct);
+        }
+        self.unchecked_crt_if_then_else(condition, true_ct, false_ct)
+    }
+
+    pub(crate) fn crt_has_carries(&self, ctxt: &CrtCiphertext) -> bool {
+        ctxt.blocks
+            .iter()
+            .any(|ct_i| ct_i.degree.0 >= ct_i.message_modulus.0)
+    }
+}
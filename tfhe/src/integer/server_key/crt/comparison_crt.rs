@@ -0,0 +1,506 @@
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::{CrtCiphertext, ServerKey};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::CarryModulus;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::CiphertextBig;
+
+/// Accumulators used to update the residue modulo `m_j` once the mixed-radix digit
+/// modulo `m_i` is known.
+pub(crate) struct MixedRadixStepAccumulators {
+    /// Maps the digit `a` to `-a mod m_j`
+    pub(crate) neg_digit: LookupTableOwned,
+    /// Maps `y` to `y * m_i^-1 mod m_j`
+    pub(crate) mul_inverse: LookupTableOwned,
+}
+
+impl ServerKey {
+    pub(crate) fn mixed_radix_step_accumulators(
+        &self,
+        modulus_i: u64,
+        modulus_j: u64,
+    ) -> MixedRadixStepAccumulators {
+        let inverse = (1..modulus_j)
+            .find(|x| (modulus_i * x) % modulus_j == 1)
+            .expect("The moduli of a CRT basis must be pairwise coprime");
+
+        let neg_digit = self
+            .key
+            .generate_accumulator(|x| (modulus_j - x % modulus_j) % modulus_j);
+        let mul_inverse = self
+            .key
+            .generate_accumulator(|x| ((x % modulus_j) * inverse) % modulus_j);
+
+        MixedRadixStepAccumulators {
+            neg_digit,
+            mul_inverse,
+        }
+    }
+
+    /// Generates the accumulators of every step of the mixed-radix conversion of a CRT basis.
+    ///
+    /// The accumulators of the pair of moduli `(m_i, m_j)`, with `i < j`,
+    /// are at index `[i][j - i - 1]`.
+    pub(crate) fn mixed_radix_accumulators(
+        &self,
+        moduli: &[u64],
+    ) -> Vec<Vec<MixedRadixStepAccumulators>> {
+        moduli
+            .iter()
+            .enumerate()
+            .map(|(i, &modulus_i)| {
+                moduli[i + 1..]
+                    .iter()
+                    .map(|&modulus_j| self.mixed_radix_step_accumulators(modulus_i, modulus_j))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Replaces the residue in `block_j` by `(block_j - digit) * m_i^-1 mod m_j`,
+    /// where `digit` is the mixed-radix digit associated to the modulus `m_i`.
+    pub(crate) fn mixed_radix_step_assign(
+        &self,
+        block_j: &mut CiphertextBig,
+        digit: &CiphertextBig,
+        accs: &MixedRadixStepAccumulators,
+    ) {
+        let neg_digit = self.key.apply_lookup_table(digit, &accs.neg_digit);
+        self.key.unchecked_add_assign(block_j, &neg_digit);
+        self.key
+            .apply_lookup_table_assign(block_j, &accs.mul_inverse);
+    }
+
+    /// Computes the mixed-radix representation of a CRT ciphertext.
+    ///
+    /// The i-th returned block encrypts the digit `a_i` in `[0, m_i)` such that
+    /// `value = a_0 + a_1 * m_0 + a_2 * m_0 * m_1 + ...`, so the digits can be compared
+    /// lexicographically, the most significant being the last one.
+    ///
+    /// The accumulators are the ones returned by
+    /// [mixed_radix_accumulators](Self::mixed_radix_accumulators) for the basis of `ct`.
+    ///
+    /// Expects the carries to be empty.
+    pub(crate) fn crt_to_mixed_radix(
+        &self,
+        ct: &CrtCiphertext,
+        accumulators: &[Vec<MixedRadixStepAccumulators>],
+    ) -> Vec<CiphertextBig> {
+        let mut digits = ct.blocks.clone();
+        for (i, step_accumulators) in accumulators.iter().enumerate() {
+            let (done, remaining) = digits.split_at_mut(i + 1);
+            let digit = &done[i];
+            for (block_j, accs) in remaining.iter_mut().zip(step_accumulators) {
+                self.mixed_radix_step_assign(block_j, digit, accs);
+            }
+        }
+        digits
+    }
+
+    /// Returns a block encrypting one of `Comparator::IS_INFERIOR`, `Comparator::IS_EQUAL`
+    /// or `Comparator::IS_SUPERIOR`.
+    ///
+    /// Expects the carries to be empty.
+    fn unchecked_crt_compare(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CiphertextBig {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Both ciphertexts must use the same basis"
+        );
+        let comparator = Comparator::new(self);
+
+        let accumulators = self.mixed_radix_accumulators(&lhs.moduli);
+        let lhs_digits = self.crt_to_mixed_radix(lhs, &accumulators);
+        let rhs_digits = self.crt_to_mixed_radix(rhs, &accumulators);
+
+        let comparisons = lhs_digits
+            .into_iter()
+            .zip(rhs_digits.iter())
+            .map(|(mut lhs_digit, rhs_digit)| {
+                comparator.compare_block_assign(&mut lhs_digit, rhs_digit);
+                lhs_digit
+            })
+            .collect();
+
+        comparator.reduce_signs(comparisons)
+    }
+
+    /// Maps the result of a comparison to a CRT ciphertext encrypting 0 or 1 in every residue.
+    pub(crate) fn crt_from_comparison<F>(
+        &self,
+        comparison: &CiphertextBig,
+        moduli: &[u64],
+        sign_result_handler_fn: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let acc = self.key.generate_accumulator(sign_result_handler_fn);
+        let result = self.key.apply_lookup_table(comparison, &acc);
+
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        let blocks = moduli
+            .iter()
+            .map(|&modulus| {
+                let mut block = result.clone();
+                block.message_modulus.0 = modulus as usize;
+                block.carry_modulus = CarryModulus(total_modulus / modulus as usize);
+                block.degree = Degree(1);
+                block
+            })
+            .collect();
+
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    fn unchecked_crt_comparison_impl<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        sign_result_handler_fn: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let comparison = self.unchecked_crt_compare(lhs, rhs);
+        self.crt_from_comparison(&comparison, &lhs.moduli, sign_result_handler_fn)
+    }
+
+    fn smart_crt_extract_messages(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) {
+        if self.crt_has_carries(lhs) {
+            self.full_extract_message_assign(lhs);
+        }
+        if self.crt_has_carries(rhs) {
+            self.full_extract_message_assign(rhs);
+        }
+    }
+
+    /// Compares for equality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_eq(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 == clear_2), res);
+    /// ```
+    pub fn unchecked_crt_eq(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x == Comparator::IS_EQUAL))
+    }
+
+    /// Compares for inequality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_ne(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 != clear_2), res);
+    /// ```
+    pub fn unchecked_crt_ne(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x != Comparator::IS_EQUAL))
+    }
+
+    /// Compares if lhs is strictly greater than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_gt(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 > clear_2), res);
+    /// ```
+    pub fn unchecked_crt_gt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x == Comparator::IS_SUPERIOR))
+    }
+
+    /// Compares if lhs is greater or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_ge(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 >= clear_2), res);
+    /// ```
+    pub fn unchecked_crt_ge(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x != Comparator::IS_INFERIOR))
+    }
+
+    /// Compares if lhs is strictly lower than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_lt(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 < clear_2), res);
+    /// ```
+    pub fn unchecked_crt_lt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x == Comparator::IS_INFERIOR))
+    }
+
+    /// Compares if lhs is lower or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_le(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 <= clear_2), res);
+    /// ```
+    pub fn unchecked_crt_le(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl(lhs, rhs, |x| u64::from(x != Comparator::IS_SUPERIOR))
+    }
+
+    /// Computes homomorphically the max between lhs and rhs.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_max(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(clear_1.max(clear_2), res);
+    /// ```
+    pub fn unchecked_crt_max(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        let is_superior = self.unchecked_crt_gt(lhs, rhs);
+        self.unchecked_crt_if_then_else(&is_superior.blocks[0], lhs, rhs)
+    }
+
+    /// Computes homomorphically the min between lhs and rhs.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_min(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(clear_1.min(clear_2), res);
+    /// ```
+    pub fn unchecked_crt_min(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        let is_inferior = self.unchecked_crt_lt(lhs, rhs);
+        self.unchecked_crt_if_then_else(&is_inferior.blocks[0], lhs, rhs)
+    }
+
+    /// Compares for equality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_eq(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_eq(lhs, rhs)
+    }
+
+    /// Compares for inequality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_ne(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_ne(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_gt(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_gt(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_ge(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_ge(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_lt(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_lt(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_le(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_le(lhs, rhs)
+    }
+
+    /// Computes homomorphically the max between lhs and rhs.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_max(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_max(lhs, rhs)
+    }
+
+    /// Computes homomorphically the min between lhs and rhs.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_min(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.smart_crt_extract_messages(lhs, rhs);
+        self.unchecked_crt_min(lhs, rhs)
+    }
+}
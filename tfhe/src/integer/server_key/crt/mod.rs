@@ -5,6 +5,8 @@ use crate::integer::ServerKey;
 mod tests;
 
 mod add_crt;
+mod cmux_crt;
+mod comparison_crt;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -12,6 +14,8 @@ mod scalar_mul_crt;
 mod scalar_sub_crt;
 mod sub_crt;
 
+pub(crate) use comparison_crt::MixedRadixStepAccumulators;
+
 impl ServerKey {
     /// Extract all the messages.
     ///
@@ -14,6 +14,27 @@ create_parametrized_test!(integer_smart_crt_scalar_mul);
 create_parametrized_test!(integer_smart_crt_scalar_sub);
 create_parametrized_test!(integer_smart_crt_sub);
 
+create_parametrized_test!(integer_smart_crt_if_then_else {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_crt_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_crt_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_crt_min_max {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_crt_min_max {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+
 /// Number of loop iteration within randomized tests
 const NB_TEST: usize = 30;
 
@@ -271,3 +292,167 @@ fn integer_smart_crt_sub(param: Parameters) {
         assert_eq!(clear_0, dec_res);
     }
 }
+
+fn integer_smart_crt_if_then_else(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen::<bool>();
+
+        let mut ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+        let condition = cks.encrypt_one_block(u64::from(clear_condition));
+
+        // Fill the carries so that the smart version has to extract the messages
+        sks.unchecked_crt_add_assign(&mut ct_0, &ct_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_crt_if_then_else(&condition, &mut ct_0, &mut ct_1);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        let expected = if clear_condition { clear_0 } else { clear_1 };
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.unchecked_crt_if_then_else_parallelized(&condition, &ct_0, &ct_1);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_unchecked_crt_comparisons(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equality case is also exercised
+        let clear_1 = if rng.gen::<bool>() {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        let ct_res = sks.unchecked_crt_eq(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_ne(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_gt(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_ge(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_lt(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_le(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 <= clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_smart_crt_comparisons(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equality case is also exercised
+        let clear_1 = if rng.gen::<bool>() {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        // Fill the carries so that the smart version has to extract the messages
+        sks.unchecked_crt_add_assign(&mut ct_0, &ct_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_crt_eq(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_ne(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_gt(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_ge(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_lt(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_le(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 <= clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_unchecked_crt_min_max(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        let ct_res = sks.unchecked_crt_max(&ct_0, &ct_1);
+        assert_eq!(clear_0.max(clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_min(&ct_0, &ct_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_smart_crt_min_max(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        // Fill the carries so that the smart version has to extract the messages
+        sks.unchecked_crt_add_assign(&mut ct_0, &ct_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_crt_max(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0.max(clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_min(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
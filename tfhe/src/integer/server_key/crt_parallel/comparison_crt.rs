@@ -0,0 +1,408 @@
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::server_key::crt::MixedRadixStepAccumulators;
+use crate::integer::{CrtCiphertext, ServerKey};
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes the mixed-radix representation of a CRT ciphertext.
+    ///
+    /// The i-th returned block encrypts the digit `a_i` in `[0, m_i)` such that
+    /// `value = a_0 + a_1 * m_0 + a_2 * m_0 * m_1 + ...`.
+    ///
+    /// The accumulators are the ones returned by
+    /// [mixed_radix_accumulators_parallelized](Self::mixed_radix_accumulators_parallelized)
+    /// for the basis of `ct`.
+    ///
+    /// Expects the carries to be empty.
+    pub(crate) fn crt_to_mixed_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        accumulators: &[Vec<MixedRadixStepAccumulators>],
+    ) -> Vec<CiphertextBig> {
+        let mut digits = ct.blocks.clone();
+        for (i, step_accumulators) in accumulators.iter().enumerate() {
+            let (done, remaining) = digits.split_at_mut(i + 1);
+            let digit = &done[i];
+            remaining
+                .par_iter_mut()
+                .zip(step_accumulators.par_iter())
+                .for_each(|(block_j, accs)| {
+                    self.mixed_radix_step_assign(block_j, digit, accs);
+                });
+        }
+        digits
+    }
+
+    /// Generates the accumulators of every step of the mixed-radix conversion of a CRT basis.
+    ///
+    /// The accumulators of the pair of moduli `(m_i, m_j)`, with `i < j`,
+    /// are at index `[i][j - i - 1]`.
+    pub(crate) fn mixed_radix_accumulators_parallelized(
+        &self,
+        moduli: &[u64],
+    ) -> Vec<Vec<MixedRadixStepAccumulators>> {
+        moduli
+            .par_iter()
+            .enumerate()
+            .map(|(i, &modulus_i)| {
+                moduli[i + 1..]
+                    .par_iter()
+                    .map(|&modulus_j| self.mixed_radix_step_accumulators(modulus_i, modulus_j))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn unchecked_crt_compare_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CiphertextBig {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Both ciphertexts must use the same basis"
+        );
+        let comparator = Comparator::new(self);
+
+        let accumulators = self.mixed_radix_accumulators_parallelized(&lhs.moduli);
+        let (lhs_digits, rhs_digits) = rayon::join(
+            || self.crt_to_mixed_radix_parallelized(lhs, &accumulators),
+            || self.crt_to_mixed_radix_parallelized(rhs, &accumulators),
+        );
+
+        let comparisons = lhs_digits
+            .into_par_iter()
+            .zip(rhs_digits.par_iter())
+            .map(|(mut lhs_digit, rhs_digit)| {
+                comparator.compare_block_assign(&mut lhs_digit, rhs_digit);
+                lhs_digit
+            })
+            .collect();
+
+        comparator.reduce_signs_parallelized(comparisons)
+    }
+
+    fn unchecked_crt_comparison_impl_parallelized<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        sign_result_handler_fn: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let comparison = self.unchecked_crt_compare_parallelized(lhs, rhs);
+        self.crt_from_comparison(&comparison, &lhs.moduli, sign_result_handler_fn)
+    }
+
+    fn smart_crt_extract_messages_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) {
+        rayon::join(
+            || {
+                if self.crt_has_carries(lhs) {
+                    self.full_extract_message_assign_parallelized(lhs);
+                }
+            },
+            || {
+                if self.crt_has_carries(rhs) {
+                    self.full_extract_message_assign_parallelized(rhs);
+                }
+            },
+        );
+    }
+
+    /// Compares for equality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_eq_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 == clear_2), res);
+    /// ```
+    pub fn unchecked_crt_eq_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x == Comparator::IS_EQUAL)
+        })
+    }
+
+    /// Compares for inequality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    pub fn unchecked_crt_ne_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x != Comparator::IS_EQUAL)
+        })
+    }
+
+    /// Compares if lhs is strictly greater than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_gt_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(u64::from(clear_1 > clear_2), res);
+    /// ```
+    pub fn unchecked_crt_gt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x == Comparator::IS_SUPERIOR)
+        })
+    }
+
+    /// Compares if lhs is greater or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    pub fn unchecked_crt_ge_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x != Comparator::IS_INFERIOR)
+        })
+    }
+
+    /// Compares if lhs is strictly lower than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    pub fn unchecked_crt_lt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x == Comparator::IS_INFERIOR)
+        })
+    }
+
+    /// Compares if lhs is lower or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    pub fn unchecked_crt_le_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_comparison_impl_parallelized(lhs, rhs, |x| {
+            u64::from(x != Comparator::IS_SUPERIOR)
+        })
+    }
+
+    /// Computes homomorphically the max between lhs and rhs.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 27;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ctxt_res = sks.unchecked_crt_max_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt_res);
+    /// assert_eq!(clear_1.max(clear_2), res);
+    /// ```
+    pub fn unchecked_crt_max_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let is_superior = self.unchecked_crt_gt_parallelized(lhs, rhs);
+        self.unchecked_crt_if_then_else_parallelized(&is_superior.blocks[0], lhs, rhs)
+    }
+
+    /// Computes homomorphically the min between lhs and rhs.
+    ///
+    /// Both ciphertexts must use the same basis, and their carries must be empty.
+    pub fn unchecked_crt_min_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let is_inferior = self.unchecked_crt_lt_parallelized(lhs, rhs);
+        self.unchecked_crt_if_then_else_parallelized(&is_inferior.blocks[0], lhs, rhs)
+    }
+
+    /// Compares for equality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_eq_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_eq_parallelized(lhs, rhs)
+    }
+
+    /// Compares for inequality 2 ciphertexts.
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_ne_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_ne_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_gt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_gt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_ge_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_ge_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_lt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_lt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs.
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_le_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_le_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the max between lhs and rhs.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_max_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_max_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the min between lhs and rhs.
+    ///
+    /// The messages of the inputs are extracted if needed.
+    pub fn smart_crt_min_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.smart_crt_extract_messages_parallelized(lhs, rhs);
+        self.unchecked_crt_min_parallelized(lhs, rhs)
+    }
+}
@@ -1,4 +1,9 @@
+#[cfg(test)]
+mod tests;
+
 mod add_crt;
+mod cmux_crt;
+mod comparison_crt;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -0,0 +1,165 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::shortint::parameters::*;
+use crate::shortint::Parameters;
+use rand::Rng;
+
+create_parametrized_test!(integer_unchecked_crt_comparisons_parallelized {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_crt_comparisons_parallelized {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_crt_min_max_parallelized {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_crt_min_max_parallelized {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+
+/// Smaller number of loop iteration within randomized test,
+/// meant for test where the function tested is more expensive
+const NB_TEST_SMALLER: usize = 10;
+
+fn make_basis(message_modulus: usize) -> Vec<u64> {
+    match message_modulus {
+        2 => vec![2],
+        3 => vec![2],
+        n if n < 8 => vec![2, 3],
+        n if n < 16 => vec![2, 5, 7],
+        _ => vec![3, 7, 13],
+    }
+}
+
+fn integer_unchecked_crt_comparisons_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equality case is also exercised
+        let clear_1 = if rng.gen::<bool>() {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        let ct_res = sks.unchecked_crt_eq_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_ne_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_gt_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_ge_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_lt_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_le_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 <= clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_smart_crt_comparisons_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equality case is also exercised
+        let clear_1 = if rng.gen::<bool>() {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        // Fill the carries so that the smart version has to extract the messages
+        sks.unchecked_crt_add_assign(&mut ct_0, &ct_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_crt_eq_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_ne_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_gt_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_ge_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_lt_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_le_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 <= clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_unchecked_crt_min_max_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        let ct_res = sks.unchecked_crt_max_parallelized(&ct_0, &ct_1);
+        assert_eq!(clear_0.max(clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.unchecked_crt_min_parallelized(&ct_0, &ct_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
+
+fn integer_smart_crt_min_max_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_crt(clear_0, basis.clone());
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+
+        // Fill the carries so that the smart version has to extract the messages
+        sks.unchecked_crt_add_assign(&mut ct_0, &ct_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_crt_max_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0.max(clear_1), cks.decrypt_crt(&ct_res));
+        let ct_res = sks.smart_crt_min_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt_crt(&ct_res));
+    }
+}
@@ -92,13 +92,40 @@ impl ServerKey {
     }
 }
 
+/// A structure containing a compressed server public key.
+///
+/// It has to be decompressed into a [ServerKey] before doing any computation.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CompressedServerKey {
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
 
 impl CompressedServerKey {
+    /// Generates a compressed server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, CompressedServerKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Generate the compressed server key and decompress it:
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    /// let sks = ServerKey::from(compressed_sks);
+    /// ```
     pub fn new(client_key: &ClientKey) -> CompressedServerKey {
-        let key = crate::shortint::CompressedServerKey::new(&client_key.key);
+        // Same maximum degree as the one of an uncompressed ServerKey
+        let max = (client_key.key.parameters.message_modulus.0 - 1)
+            * client_key.key.parameters.carry_modulus.0
+            - 1;
+
+        let key = crate::shortint::CompressedServerKey::new_with_max_degree(
+            &client_key.key,
+            MaxDegree(max),
+        );
         Self { key }
     }
 }
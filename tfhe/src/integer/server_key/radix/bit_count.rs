@@ -0,0 +1,474 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::CiphertextBig;
+
+/// Selects the end of the ciphertext from which the zeros are counted
+#[derive(Clone, Copy)]
+pub(crate) enum ZeroCountDirection {
+    /// Starting from the most significant bit
+    Leading,
+    /// Starting from the least significant bit
+    Trailing,
+}
+
+impl ZeroCountDirection {
+    /// Returns the number of zeros at the selected end of a block value of `nb_bits` bits
+    pub(crate) fn block_zeros(self, value: u64, nb_bits: u32) -> u64 {
+        if value == 0 {
+            return u64::from(nb_bits);
+        }
+        match self {
+            Self::Leading => u64::from(nb_bits - (u64::BITS - value.leading_zeros())),
+            Self::Trailing => u64::from(value.trailing_zeros()),
+        }
+    }
+}
+
+impl ServerKey {
+    /// Number of bits of message in a block
+    pub(crate) fn message_bits_per_block(&self) -> u32 {
+        (self.key.message_modulus.0 as f64).log2() as u32
+    }
+
+    /// Turns a block encrypting a small value into a radix ciphertext of `num_blocks` blocks.
+    pub(crate) fn block_to_radix(
+        &self,
+        block: CiphertextBig,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let mut blocks = Vec::with_capacity(num_blocks);
+        blocks.push(block);
+        blocks.resize_with(num_blocks, || self.key.create_trivial(0));
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Sums blocks whose values fit in the message space into a radix ciphertext
+    /// of `num_blocks` blocks, with empty carries.
+    fn sum_blocks(&self, blocks: Vec<CiphertextBig>, num_blocks: usize) -> RadixCiphertext {
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        for block in blocks {
+            let mut term = self.block_to_radix(block, num_blocks);
+            self.smart_add_assign(&mut result, &mut term);
+        }
+        self.full_propagate(&mut result);
+        result
+    }
+
+    /// Accumulator computing the number of bits of the block message for which `f` is true
+    pub(crate) fn bit_count_accumulator<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(u64) -> bool,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let nb_bits = self.message_bits_per_block();
+        self.key.generate_accumulator(|x| {
+            let x = x % message_modulus;
+            (0..nb_bits).filter(|i| f((x >> i) & 1)).count() as u64
+        })
+    }
+
+    /// Accumulator computing the AND of two booleans that were added together
+    pub(crate) fn boolean_sum_and_accumulator(&self) -> LookupTableOwned {
+        self.key.generate_accumulator(|x| u64::from(x == 2))
+    }
+
+    /// Accumulator for blocks packed as `all_zero_before * message_modulus + block`.
+    ///
+    /// It outputs the number of zeros of the block at the selected end if all the blocks
+    /// before it (in the counting direction) are zero, and 0 otherwise.
+    pub(crate) fn zero_count_accumulator(&self, direction: ZeroCountDirection) -> LookupTableOwned {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let nb_bits = self.message_bits_per_block();
+        self.key.generate_accumulator(|x| {
+            if x / message_modulus == 1 {
+                direction.block_zeros(x % message_modulus, nb_bits)
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Packs the `all_zero_before` boolean in the carry part of the block.
+    ///
+    /// Requires carry bits of the block to be empty.
+    pub(crate) fn pack_zero_count_input(
+        &self,
+        all_zero_before: &CiphertextBig,
+        block: &CiphertextBig,
+    ) -> CiphertextBig {
+        let mut packed = self
+            .key
+            .unchecked_scalar_mul(all_zero_before, self.key.message_modulus.0 as u8);
+        self.key.unchecked_add_assign(&mut packed, block);
+        packed
+    }
+
+    fn unchecked_count_bits<F>(&self, ct: &RadixCiphertext, f: F) -> RadixCiphertext
+    where
+        F: Fn(u64) -> bool,
+    {
+        let acc = self.bit_count_accumulator(f);
+        let counts = ct
+            .blocks
+            .iter()
+            .map(|block| self.key.apply_lookup_table(block, &acc))
+            .collect();
+        self.sum_blocks(counts, ct.blocks.len())
+    }
+
+    fn unchecked_count_zeros_from(
+        &self,
+        ct: &RadixCiphertext,
+        direction: ZeroCountDirection,
+    ) -> RadixCiphertext {
+        let blocks = match direction {
+            ZeroCountDirection::Leading => ct.blocks.iter().rev().collect::<Vec<_>>(),
+            ZeroCountDirection::Trailing => ct.blocks.iter().collect::<Vec<_>>(),
+        };
+
+        let is_zero_acc = self.key.generate_accumulator(|x| u64::from(x == 0));
+        let and_acc = self.boolean_sum_and_accumulator();
+
+        // all_zero_before[i] encrypts whether all the blocks before the i-th one are zero
+        let mut all_zero_before = Vec::with_capacity(blocks.len());
+        all_zero_before.push(self.key.create_trivial(1));
+        for block in &blocks[..blocks.len() - 1] {
+            let is_zero = self.key.apply_lookup_table(block, &is_zero_acc);
+            let mut all_zero = self
+                .key
+                .unchecked_add(all_zero_before.last().unwrap(), &is_zero);
+            self.key.apply_lookup_table_assign(&mut all_zero, &and_acc);
+            all_zero_before.push(all_zero);
+        }
+
+        let zero_count_acc = self.zero_count_accumulator(direction);
+        let counts = blocks
+            .iter()
+            .zip(all_zero_before.iter())
+            .map(|(block, all_zero)| {
+                let mut packed = self.pack_zero_count_input(all_zero, block);
+                self.key
+                    .apply_lookup_table_assign(&mut packed, &zero_count_acc);
+                packed
+            })
+            .collect();
+
+        self.sum_blocks(counts, ct.blocks.len())
+    }
+
+    /// Computes homomorphically the number of bits set to one in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_count_ones(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_ones()));
+    /// ```
+    pub fn unchecked_count_ones(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_bits(ct, |bit| bit == 1)
+    }
+
+    /// Computes homomorphically the number of bits set to zero in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_count_zeros(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_zeros()));
+    /// ```
+    pub fn unchecked_count_zeros(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_bits(ct, |bit| bit == 0)
+    }
+
+    /// Computes homomorphically the number of leading zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 12u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_leading_zeros(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.leading_zeros()));
+    /// ```
+    pub fn unchecked_leading_zeros(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_zeros_from(ct, ZeroCountDirection::Leading)
+    }
+
+    /// Computes homomorphically the number of trailing zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 96u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_trailing_zeros(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.trailing_zeros()));
+    /// ```
+    pub fn unchecked_trailing_zeros(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_zeros_from(ct, ZeroCountDirection::Trailing)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down.
+    ///
+    /// The result has the same number of blocks as the input.
+    /// If the ciphertext encrypts 0, the result wraps around to the maximum value.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 100u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_ilog2(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn unchecked_ilog2(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let num_bits = self.message_bits_per_block() as u64 * num_blocks as u64;
+
+        let mut leading_zeros = self.unchecked_leading_zeros(ct);
+        let mut result = self.create_trivial_radix(num_bits - 1, num_blocks);
+        let mut result = self.smart_sub(&mut result, &mut leading_zeros);
+        self.full_propagate(&mut result);
+        result
+    }
+
+    /// Computes homomorphically the number of bits set to one in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_count_ones(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_ones()));
+    /// ```
+    pub fn smart_count_ones(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_count_ones(ct)
+    }
+
+    /// Computes homomorphically the number of bits set to zero in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_count_zeros(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_zeros()));
+    /// ```
+    pub fn smart_count_zeros(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_count_zeros(ct)
+    }
+
+    /// Computes homomorphically the number of leading zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 12u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_leading_zeros(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.leading_zeros()));
+    /// ```
+    pub fn smart_leading_zeros(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_leading_zeros(ct)
+    }
+
+    /// Computes homomorphically the number of trailing zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 96u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_trailing_zeros(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.trailing_zeros()));
+    /// ```
+    pub fn smart_trailing_zeros(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_trailing_zeros(ct)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down.
+    ///
+    /// The result has the same number of blocks as the input.
+    /// If the ciphertext encrypts 0, the result wraps around to the maximum value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 100u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_ilog2(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn smart_ilog2(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_ilog2(ct)
+    }
+}
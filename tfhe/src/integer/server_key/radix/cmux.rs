@@ -0,0 +1,208 @@
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::CiphertextBig;
+
+/// Accumulators selecting a block with an encrypted condition.
+///
+/// The condition is packed as the high part of the PBS input, so that blocks of any modulus
+/// (e.g. the blocks of a CRT ciphertext) fit next to it.
+pub(crate) struct CmuxAccumulators {
+    keep_if_true: LookupTableOwned,
+    keep_if_false: LookupTableOwned,
+    block_modulus: u8,
+}
+
+impl ServerKey {
+    pub(crate) fn cmux_accumulators<'a>(
+        &self,
+        blocks: impl Iterator<Item = &'a CiphertextBig>,
+    ) -> CmuxAccumulators {
+        let block_modulus = blocks
+            .map(|block| block.message_modulus.0.max(block.degree.0 + 1))
+            .max()
+            .unwrap_or(self.key.message_modulus.0) as u64;
+
+        let keep_if_true = self.key.generate_accumulator(|x| {
+            if x / block_modulus == 1 {
+                x % block_modulus
+            } else {
+                0
+            }
+        });
+        let keep_if_false = self.key.generate_accumulator(|x| {
+            if x / block_modulus == 1 {
+                0
+            } else {
+                x % block_modulus
+            }
+        });
+
+        CmuxAccumulators {
+            keep_if_true,
+            keep_if_false,
+            block_modulus: block_modulus as u8,
+        }
+    }
+
+    /// Returns homomorphically `block` if `condition` encrypts 1 and 0 if it encrypts 0.
+    pub(crate) fn unchecked_block_keep_if(
+        &self,
+        condition: &CiphertextBig,
+        block: &CiphertextBig,
+        accs: &CmuxAccumulators,
+    ) -> CiphertextBig {
+        let shifted_condition = self.key.unchecked_scalar_mul(condition, accs.block_modulus);
+        let mut result = self.unchecked_block_keep_if_shifted(&shifted_condition, block, accs);
+        result.message_modulus = block.message_modulus;
+        result.carry_modulus = block.carry_modulus;
+        result.degree = block.degree;
+        result
+    }
+
+    fn unchecked_block_keep_if_shifted(
+        &self,
+        shifted_condition: &CiphertextBig,
+        block: &CiphertextBig,
+        accs: &CmuxAccumulators,
+    ) -> CiphertextBig {
+        let mut maybe_block = self.key.unchecked_add(shifted_condition, block);
+        self.key
+            .apply_lookup_table_assign(&mut maybe_block, &accs.keep_if_true);
+        maybe_block
+    }
+
+    /// Selects homomorphically `true_block` if `condition` encrypts 1 and `false_block`
+    /// if it encrypts 0.
+    pub(crate) fn unchecked_block_cmux(
+        &self,
+        condition: &CiphertextBig,
+        true_block: &CiphertextBig,
+        false_block: &CiphertextBig,
+        accs: &CmuxAccumulators,
+    ) -> CiphertextBig {
+        let shifted_condition = self.key.unchecked_scalar_mul(condition, accs.block_modulus);
+
+        let maybe_true = self.unchecked_block_keep_if_shifted(&shifted_condition, true_block, accs);
+        let mut maybe_false = self.key.unchecked_add(&shifted_condition, false_block);
+        self.key
+            .apply_lookup_table_assign(&mut maybe_false, &accs.keep_if_false);
+
+        let mut result = maybe_true;
+        self.key.unchecked_add_assign(&mut result, &maybe_false);
+        // The result is one of the blocks, not a value of the condition's message space
+        result.message_modulus = true_block.message_modulus;
+        result.carry_modulus = true_block.carry_modulus;
+        result.degree = Degree(true_block.degree.0.max(false_block.degree.0));
+        result
+    }
+
+    /// Selects block-wise between two integer ciphertexts of the same shape.
+    pub(crate) fn unchecked_if_then_else_impl<T: IntegerCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T {
+        let accs = self.cmux_accumulators(true_ct.blocks().iter().chain(false_ct.blocks()));
+        let blocks = true_ct
+            .blocks()
+            .iter()
+            .zip(false_ct.blocks().iter())
+            .map(|(true_block, false_block)| {
+                self.unchecked_block_cmux(condition, true_block, false_block, &accs)
+            })
+            .collect();
+        T::from_blocks(blocks)
+    }
+
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false),
+    /// for example the first block of the result of a comparison.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 97;
+    /// let msg2 = 14;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Compute homomorphically if msg1 > msg2 { msg1 - msg2 } else { msg2 - msg1 }
+    /// let is_greater = sks.unchecked_gt(&ct1, &ct2);
+    /// let mut diff = sks.unchecked_sub(&ct1, &ct2);
+    /// let mut opposite_diff = sks.unchecked_sub(&ct2, &ct1);
+    /// sks.full_propagate(&mut diff);
+    /// sks.full_propagate(&mut opposite_diff);
+    /// let ct_res = sks.unchecked_if_then_else(&is_greater.blocks()[0], &diff, &opposite_diff);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg1 - msg2, dec);
+    /// ```
+    pub fn unchecked_if_then_else<T: IntegerRadixCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T {
+        self.unchecked_if_then_else_impl(condition, true_ct, false_ct)
+    }
+
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false).
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 21;
+    /// let msg2 = 140;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let is_equal = sks.smart_eq(&mut ct1, &mut ct2);
+    /// let ct_res = sks.smart_if_then_else(&is_equal.blocks()[0], &mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg2, dec);
+    /// ```
+    pub fn smart_if_then_else<T: IntegerRadixCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &mut T,
+        false_ct: &mut T,
+    ) -> T {
+        if has_non_zero_carries(true_ct) {
+            self.full_propagate(true_ct);
+        }
+        if has_non_zero_carries(false_ct) {
+            self.full_propagate(false_ct);
+        }
+        self.unchecked_if_then_else(condition, true_ct, false_ct)
+    }
+}
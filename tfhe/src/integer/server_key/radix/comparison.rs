@@ -1,7 +1,8 @@
 use super::ServerKey;
 
 use crate::integer::server_key::comparator::Comparator;
-use crate::integer::RadixCiphertext;
+use crate::integer::{RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::CiphertextBig;
 
 impl ServerKey {
     /// Compares for equality 2 ciphertexts
@@ -169,6 +170,39 @@ impl ServerKey {
         Comparator::new(self).unchecked_le(lhs, rhs)
     }
 
+    /// Compares for difference 2 ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_ne(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 != msg2));
+    /// ```
+    pub fn unchecked_ne(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        Comparator::new(self).unchecked_ne(lhs, rhs)
+    }
+
     /// Computes the max of two encrypted values
     ///
     ///
@@ -408,6 +442,41 @@ impl ServerKey {
         Comparator::new(self).smart_le(lhs, rhs)
     }
 
+    /// Compares for difference 2 ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_ne(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 != msg2));
+    /// ```
+    pub fn smart_ne(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_ne(lhs, rhs)
+    }
+
     /// Computes the max of two encrypted values
     ///
     /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
@@ -477,4 +546,1160 @@ impl ServerKey {
     ) -> RadixCiphertext {
         Comparator::new(self).smart_min(lhs, rhs)
     }
+
+    /// Compares if lhs is strictly greater than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_gt(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn unchecked_signed_gt(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_gt(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_ge(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn unchecked_signed_ge(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_ge(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_lt(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn unchecked_signed_lt(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_lt(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_le(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn unchecked_signed_le(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_le(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of lhs and rhs, as signed integers
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_max(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::max(msg1, msg2));
+    /// ```
+    pub fn unchecked_signed_max(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).unchecked_signed_max(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of lhs and rhs, as signed integers
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_min(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::min(msg1, msg2));
+    /// ```
+    pub fn unchecked_signed_min(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).unchecked_signed_min(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_gt(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn smart_signed_gt(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_gt(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_ge(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn smart_signed_ge(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_ge(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_lt(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn smart_signed_lt(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_lt(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs, as signed integers
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_le(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn smart_signed_le(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_le(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of lhs and rhs, as signed integers
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_max(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::max(msg1, msg2));
+    /// ```
+    pub fn smart_signed_max(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).smart_signed_max(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of lhs and rhs, as signed integers
+    ///
+    /// The carries of the inputs are propagated if needed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_min(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::min(msg1, msg2));
+    /// ```
+    pub fn smart_signed_min(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).smart_signed_min(lhs, rhs)
+    }
+
+    /// Compares for equality 2 ciphertexts
+    ///
+    /// Returns a boolean block containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_eq_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 == msg2));
+    /// ```
+    pub fn unchecked_eq_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_eq_as_block(lhs, rhs)
+    }
+
+    /// Compares for difference 2 ciphertexts
+    ///
+    /// Returns a boolean block containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_ne_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 != msg2));
+    /// ```
+    pub fn unchecked_ne_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_ne_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_gt_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn unchecked_gt_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_gt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_ge_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn unchecked_ge_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_ge_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_lt_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn unchecked_lt_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_lt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_le_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn unchecked_le_as_block(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_le_as_block(lhs, rhs)
+    }
+
+    /// Compares for equality 2 ciphertexts
+    ///
+    /// Returns a boolean block containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_eq_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 == msg2));
+    /// ```
+    pub fn smart_eq_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_eq_as_block(lhs, rhs)
+    }
+
+    /// Compares for difference 2 ciphertexts
+    ///
+    /// Returns a boolean block containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_ne_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 != msg2));
+    /// ```
+    pub fn smart_ne_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_ne_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_gt_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn smart_gt_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_gt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_ge_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn smart_ge_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_ge_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_lt_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn smart_lt_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_lt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs
+    ///
+    /// Returns a boolean block containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.smart_le_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn smart_le_as_block(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_le_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_gt_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn unchecked_signed_gt_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_gt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_ge_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn unchecked_signed_ge_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_ge_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_lt_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn unchecked_signed_lt_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_lt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_le_as_block(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn unchecked_signed_le_as_block(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_le_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_gt_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 > msg2));
+    /// ```
+    pub fn smart_signed_gt_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_gt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_ge_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 >= msg2));
+    /// ```
+    pub fn smart_signed_ge_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_ge_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_lt_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 < msg2));
+    /// ```
+    pub fn smart_signed_lt_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_lt_as_block(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs, as signed integers
+    ///
+    /// Returns a boolean block containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let mut ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.smart_signed_le_as_block(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg1 <= msg2));
+    /// ```
+    pub fn smart_signed_le_as_block(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_le_as_block(lhs, rhs)
+    }
 }
@@ -1,17 +1,36 @@
 mod add;
+mod bit_count;
 mod bitwise_op;
+mod cmux;
 mod comparison;
+mod div_mod;
 mod mul;
 mod neg;
+mod oblivious;
+mod overflowing;
+mod pow;
+mod resize;
 mod scalar_add;
+mod scalar_comparison;
+mod scalar_div_mod;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
+mod signed;
+mod sqrt;
 mod sub;
+mod sum;
+mod univariate;
 
 use super::ServerKey;
 
-use crate::integer::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::{IntegerRadixCiphertext, RadixCiphertext};
+
+pub(crate) use bit_count::ZeroCountDirection;
+pub(crate) use scalar_div_mod::{modular_inverse, ScalarDivisor};
+pub(crate) use shift::BarrelShiftKind;
+pub(crate) use signed::SignedOverflowingOp;
 
 #[cfg(test)]
 mod tests;
@@ -45,6 +64,109 @@ impl ServerKey {
         RadixCiphertext { blocks: vec_res }
     }
 
+    /// Create a trivial ciphertext encrypting a clear value
+    ///
+    /// The value is truncated if it does not fit in the given number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ctxt = sks.create_trivial_radix(212u64, num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ctxt);
+    /// assert_eq!(212, dec);
+    /// ```
+    pub fn create_trivial_radix<T: AsLittleEndianWords>(
+        &self,
+        value: T,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let vec_res = self
+            .scalar_block_decomposition(&value, num_blocks)
+            .into_iter()
+            .map(|block_value| self.key.create_trivial(block_value))
+            .collect();
+
+        RadixCiphertext { blocks: vec_res }
+    }
+
+    /// Decomposes a clear value into `num_blocks` block values, least significant first
+    ///
+    /// The bits that do not fit in the given number of blocks are ignored.
+    pub(crate) fn scalar_block_decomposition<T: AsLittleEndianWords>(
+        &self,
+        value: &T,
+        num_blocks: usize,
+    ) -> Vec<u64> {
+        //number of bits of message
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let words = value.as_little_endian_iter().copied().collect::<Vec<_>>();
+
+        (0..num_blocks)
+            .map(|i| {
+                (0..nb_bits)
+                    .map(|j| {
+                        let bit_index = i * nb_bits + j;
+                        let bit = words
+                            .get(bit_index / 64)
+                            .map_or(0, |word| (word >> (bit_index % 64)) & 1);
+                        bit << j
+                    })
+                    .sum::<u64>()
+            })
+            .collect()
+    }
+
+    /// Decomposes the opposite of a clear value modulo `message_modulus^num_blocks`
+    /// into `num_blocks` block values, least significant first
+    pub(crate) fn scalar_negated_block_decomposition<T: AsLittleEndianWords>(
+        &self,
+        value: &T,
+        num_blocks: usize,
+    ) -> Vec<u64> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        // Two's complement in base message_modulus: complement each digit, then add one
+        let mut carry = 1;
+        self.scalar_block_decomposition(value, num_blocks)
+            .into_iter()
+            .map(|digit| {
+                let negated_digit = message_modulus - 1 - digit + carry;
+                carry = negated_digit / message_modulus;
+                negated_digit % message_modulus
+            })
+            .collect()
+    }
+
+    /// Returns whether a clear value can be represented with `num_blocks` blocks
+    pub(crate) fn scalar_fits_in_blocks<T: AsLittleEndianWords>(
+        &self,
+        value: &T,
+        num_blocks: usize,
+    ) -> bool {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_bits = nb_bits * num_blocks;
+
+        value.as_little_endian_iter().enumerate().all(|(i, word)| {
+            let first_bit = i * 64;
+            if first_bit + 64 <= num_bits {
+                true
+            } else if first_bit >= num_bits {
+                *word == 0
+            } else {
+                (word >> (num_bits - first_bit)) == 0
+            }
+        })
+    }
+
     /// Propagate the carry of the 'index' block to the next one.
     ///
     /// # Example
@@ -71,15 +193,15 @@ impl ServerKey {
     /// let res = cks.decrypt_one_block(&ct_res.blocks()[1]);
     /// assert_eq!(3, res);
     /// ```
-    pub fn propagate(&self, ctxt: &mut RadixCiphertext, index: usize) {
-        let carry = self.key.carry_extract(&ctxt.blocks[index]);
+    pub fn propagate<T: IntegerRadixCiphertext>(&self, ctxt: &mut T, index: usize) {
+        let carry = self.key.carry_extract(&ctxt.blocks()[index]);
 
-        ctxt.blocks[index] = self.key.message_extract(&ctxt.blocks[index]);
+        ctxt.blocks_mut()[index] = self.key.message_extract(&ctxt.blocks()[index]);
 
         //add the carry to the next block
-        if index < ctxt.blocks.len() - 1 {
+        if index < ctxt.blocks().len() - 1 {
             self.key
-                .unchecked_add_assign(&mut ctxt.blocks[index + 1], &carry);
+                .unchecked_add_assign(&mut ctxt.blocks_mut()[index + 1], &carry);
         }
     }
 
@@ -109,8 +231,8 @@ impl ServerKey {
     /// let res = cks.decrypt(&ct_res);
     /// assert_eq!(msg + msg, res);
     /// ```
-    pub fn full_propagate(&self, ctxt: &mut RadixCiphertext) {
-        let len = ctxt.blocks.len();
+    pub fn full_propagate<T: IntegerRadixCiphertext>(&self, ctxt: &mut T) {
+        let len = ctxt.blocks().len();
         for i in 0..len {
             self.propagate(ctxt, i);
         }
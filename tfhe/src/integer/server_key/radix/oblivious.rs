@@ -0,0 +1,296 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::{has_non_zero_carries, Comparator};
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    pub(crate) fn assert_table_is_valid(&self, table: &[RadixCiphertext]) {
+        assert!(!table.is_empty(), "The table must not be empty");
+        let num_blocks = table[0].blocks.len();
+        assert!(
+            table.iter().all(|entry| entry.blocks.len() == num_blocks),
+            "All the entries of the table must have the same number of blocks"
+        );
+    }
+
+    /// Maximum number of blocks of which at most one is non zero,
+    /// that can be summed without exceeding the carry space.
+    pub(crate) fn one_hot_sum_chunk_size(&self) -> usize {
+        let message_modulus = self.key.message_modulus.0;
+        let carry_modulus = self.key.carry_modulus.0;
+        ((message_modulus * carry_modulus - 1) / (message_modulus - 1)).max(1)
+    }
+
+    /// Sums blocks of which at most one is non zero.
+    ///
+    /// As the sum is a single message, the carry can be cleaned with a PBS
+    /// (which also resets the noise).
+    pub(crate) fn unchecked_sum_one_hot_chunk(&self, chunk: &[CiphertextBig]) -> CiphertextBig {
+        let mut sum = chunk[0].clone();
+        for block in chunk[1..].iter() {
+            self.key.unchecked_add_assign(&mut sum, block);
+        }
+        if chunk.len() > 1 {
+            self.key.message_extract_assign(&mut sum);
+        }
+        sum
+    }
+
+    /// Sums blocks of which at most one is non zero, using chunks as big as the carry
+    /// space allows.
+    pub(crate) fn unchecked_sum_one_hot_blocks(&self, blocks: &[CiphertextBig]) -> CiphertextBig {
+        if blocks.is_empty() {
+            return self.key.create_trivial(0);
+        }
+        let chunk_size = self.one_hot_sum_chunk_size();
+        let mut sums = blocks.to_vec();
+        while sums.len() > 1 {
+            sums = sums
+                .chunks(chunk_size)
+                .map(|chunk| self.unchecked_sum_one_hot_chunk(chunk))
+                .collect();
+        }
+        sums.pop().unwrap()
+    }
+
+    /// Returns, for each block position, the blocks of the entries masked by their selection
+    /// condition.
+    fn masked_table_blocks(
+        &self,
+        table: &[RadixCiphertext],
+        conditions: &[CiphertextBig],
+    ) -> Vec<Vec<CiphertextBig>> {
+        let accs = self.cmux_accumulators(table.iter().flat_map(|entry| entry.blocks.iter()));
+        let num_blocks = table[0].blocks.len();
+
+        let mut masked_blocks = vec![Vec::with_capacity(table.len()); num_blocks];
+        for (entry, condition) in table.iter().zip(conditions.iter()) {
+            for (masked, block) in masked_blocks.iter_mut().zip(entry.blocks.iter()) {
+                masked.push(self.unchecked_block_keep_if(condition, block, &accs));
+            }
+        }
+        masked_blocks
+    }
+
+    /// Reads homomorphically the entry of the table at the encrypted index.
+    ///
+    /// Each entry is compared to the index, and only the selected one is kept.
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let clear_table = [17u64, 250, 3, 42];
+    /// let table = clear_table
+    ///     .iter()
+    ///     .map(|&value| cks.encrypt(value))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(2u64);
+    ///
+    /// let ct_res = sks.unchecked_oblivious_read(&table, &index);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, clear_table[2]);
+    /// ```
+    pub fn unchecked_oblivious_read(
+        &self,
+        table: &[RadixCiphertext],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.assert_table_is_valid(table);
+        let comparator = Comparator::new(self);
+        let conditions = (0..table.len())
+            .map(|i| comparator.unchecked_scalar_eq(index, i as u64))
+            .collect::<Vec<_>>();
+
+        let blocks = self
+            .masked_table_blocks(table, &conditions)
+            .iter()
+            .map(|masked| self.unchecked_sum_one_hot_blocks(masked))
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Reads homomorphically the entry of the table at the encrypted index.
+    ///
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_oblivious_read(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        for entry in table.iter_mut() {
+            if has_non_zero_carries(entry) {
+                self.full_propagate(entry);
+            }
+        }
+        if has_non_zero_carries(index) {
+            self.full_propagate(index);
+        }
+        self.unchecked_oblivious_read(table, index)
+    }
+
+    /// Writes homomorphically the value in the entry of the table at the encrypted index.
+    ///
+    /// Every entry is updated with a cmux, so that the index is not revealed.
+    /// If the index is out of the table, the table is left unchanged.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let clear_table = [17u64, 250, 3, 42];
+    /// let mut table = clear_table
+    ///     .iter()
+    ///     .map(|&value| cks.encrypt(value))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(1u64);
+    /// let value = cks.encrypt(99u64);
+    ///
+    /// sks.unchecked_oblivious_write(&mut table, &index, &value);
+    ///
+    /// // Decrypt:
+    /// let dec = table.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec, [17, 99, 3, 42]);
+    /// ```
+    pub fn unchecked_oblivious_write(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &RadixCiphertext,
+        value: &RadixCiphertext,
+    ) {
+        self.assert_table_is_valid(table);
+        let comparator = Comparator::new(self);
+        for (i, entry) in table.iter_mut().enumerate() {
+            let is_selected = comparator.unchecked_scalar_eq(index, i as u64);
+            *entry = self.unchecked_if_then_else(&is_selected, value, entry);
+        }
+    }
+
+    /// Writes homomorphically the value in the entry of the table at the encrypted index.
+    ///
+    /// If the index is out of the table, the table is left unchanged.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_oblivious_write(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &mut RadixCiphertext,
+        value: &mut RadixCiphertext,
+    ) {
+        for ct in table.iter_mut().chain([&mut *index, &mut *value]) {
+            if has_non_zero_carries(ct) {
+                self.full_propagate(ct);
+            }
+        }
+        self.unchecked_oblivious_write(table, index, value)
+    }
+
+    /// Returns the clear digits of the table values for the given block position.
+    pub(crate) fn clear_table_digits(&self, table: &[u64], block_index: usize) -> Vec<u64> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        table
+            .iter()
+            .map(|value| {
+                message_modulus
+                    .checked_pow(block_index as u32)
+                    .map_or(0, |shift| (value / shift) % message_modulus)
+            })
+            .collect()
+    }
+
+    /// Returns homomorphically the value of the clear table at the encrypted index,
+    /// as a ciphertext of `num_blocks` blocks.
+    ///
+    /// As the table is clear, the selected value is built from the comparison results
+    /// with scalar multiplications instead of cmuxes.
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 2 * 2 = 4 bits of index
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let table = [1000u64, 2000, 3000, 4000];
+    /// let index = cks.encrypt(3u64);
+    ///
+    /// // The values need 12 bits, so 6 blocks
+    /// let ct_res = sks.unchecked_match_value(&index, &table, 6);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[3]);
+    /// ```
+    pub fn unchecked_match_value(
+        &self,
+        index: &RadixCiphertext,
+        table: &[u64],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let comparator = Comparator::new(self);
+        let conditions = (0..table.len())
+            .map(|i| comparator.unchecked_scalar_eq(index, i as u64))
+            .collect::<Vec<_>>();
+
+        let blocks = (0..num_blocks)
+            .map(|block_index| {
+                let terms = self
+                    .clear_table_digits(table, block_index)
+                    .into_iter()
+                    .zip(conditions.iter())
+                    .filter(|(digit, _)| *digit != 0)
+                    .map(|(digit, condition)| self.key.unchecked_scalar_mul(condition, digit as u8))
+                    .collect::<Vec<_>>();
+                self.unchecked_sum_one_hot_blocks(&terms)
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Returns homomorphically the value of the clear table at the encrypted index,
+    /// as a ciphertext of `num_blocks` blocks.
+    ///
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// The carries of the index are propagated if needed.
+    pub fn smart_match_value(
+        &self,
+        index: &mut RadixCiphertext,
+        table: &[u64],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(index) {
+            self.full_propagate(index);
+        }
+        self.unchecked_match_value(index, table, num_blocks)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically `ct` raised to the power of a clear exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u32;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_scalar_pow(&ct, exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent) as u64);
+    /// ```
+    pub fn unchecked_scalar_pow(&self, ct: &RadixCiphertext, exponent: u32) -> RadixCiphertext {
+        let mut result: Option<RadixCiphertext> = None;
+        let mut base = ct.clone();
+        let mut exponent = exponent;
+
+        // Square and multiply, from the least significant bit of the exponent
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = Some(match result {
+                    None => base.clone(),
+                    Some(mut result) => self.smart_mul(&mut result, &mut base),
+                });
+            }
+            exponent >>= 1;
+            if exponent != 0 {
+                let mut base_copy = base.clone();
+                base = self.smart_mul(&mut base, &mut base_copy);
+            }
+        }
+
+        let mut result = result.unwrap_or_else(|| self.create_trivial_radix(1u64, ct.blocks.len()));
+        self.full_propagate(&mut result);
+        result
+    }
+
+    /// Computes homomorphically `ct` raised to the power of a clear exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u32;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_scalar_pow(&mut ct, exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent) as u64);
+    /// ```
+    pub fn smart_scalar_pow(&self, ct: &mut RadixCiphertext, exponent: u32) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_scalar_pow(ct, exponent)
+    }
+
+    /// Computes homomorphically `ct` raised to the power of an encrypted exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    /// The exponent may have a different number of blocks than `ct`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// let ct_exponent = cks.encrypt(exponent as u64);
+    ///
+    /// let ct_res = sks.unchecked_pow(&ct, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent as u32) as u64);
+    /// ```
+    pub fn unchecked_pow(
+        &self,
+        ct: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let exponent_bits = self.unchecked_radix_to_bits(exponent);
+        let num_bits = exponent_bits.len();
+
+        let mut result = self.create_trivial_radix(1u64, ct.blocks.len());
+        let mut base = ct.clone();
+
+        // Square and multiply, from the least significant bit of the exponent,
+        // the multiplication is only kept if the bit is set
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let product = if i == 0 {
+                base.clone()
+            } else {
+                let mut product = self.smart_mul(&mut result, &mut base);
+                self.full_propagate(&mut product);
+                product
+            };
+            result = self.unchecked_if_then_else(bit, &product, &result);
+
+            if i != num_bits - 1 {
+                let mut base_copy = base.clone();
+                base = self.smart_mul(&mut base, &mut base_copy);
+                self.full_propagate(&mut base);
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `ct` raised to the power of an encrypted exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    /// The exponent may have a different number of blocks than `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    /// let mut ct_exponent = cks.encrypt(exponent as u64);
+    ///
+    /// let ct_res = sks.smart_pow(&mut ct, &mut ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent as u32) as u64);
+    /// ```
+    pub fn smart_pow(
+        &self,
+        ct: &mut RadixCiphertext,
+        exponent: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        if has_non_zero_carries(exponent) {
+            self.full_propagate(exponent);
+        }
+        self.unchecked_pow(ct, exponent)
+    }
+}
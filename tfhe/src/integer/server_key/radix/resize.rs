@@ -0,0 +1,316 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Appends trivially encrypted zero blocks as the most significant blocks of the ciphertext.
+    ///
+    /// The encrypted value is unchanged, and no PBS is computed.
+    ///
+    /// Requires carry bits to be empty, as the carry of the current most significant block
+    /// would not be propagated to the new blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.extend_radix_with_trivial_zero_blocks_msb(&ct, 4);
+    /// assert_eq!(ct_res.blocks().len(), 8);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn extend_radix_with_trivial_zero_blocks_msb(
+        &self,
+        ct: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Appends trivially encrypted zero blocks as the most significant blocks of the ciphertext.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// Requires carry bits to be empty.
+    pub fn extend_radix_with_trivial_zero_blocks_msb_assign(
+        &self,
+        ct: &mut RadixCiphertext,
+        num_blocks: usize,
+    ) {
+        let new_len = ct.blocks.len() + num_blocks;
+        ct.blocks
+            .resize_with(new_len, || self.key.create_trivial(0));
+    }
+
+    /// Appends trivially encrypted zero blocks as the most significant blocks of the ciphertext.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    /// // The addition fills the carries of the ciphertext
+    /// sks.unchecked_add_assign(&mut ct, &cks.encrypt(msg));
+    ///
+    /// let ct_res = sks.smart_extend_radix_with_trivial_zero_blocks_msb(&mut ct, 4);
+    /// assert_eq!(ct_res.blocks().len(), 8);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!((msg + msg) % 256, dec);
+    /// ```
+    pub fn smart_extend_radix_with_trivial_zero_blocks_msb(
+        &self,
+        ct: &mut RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.extend_radix_with_trivial_zero_blocks_msb(ct, num_blocks)
+    }
+
+    /// Appends trivially encrypted zero blocks as the most significant blocks of the ciphertext.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// The carries of the input are propagated if needed.
+    pub fn smart_extend_radix_with_trivial_zero_blocks_msb_assign(
+        &self,
+        ct: &mut RadixCiphertext,
+        num_blocks: usize,
+    ) {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(ct, num_blocks);
+    }
+
+    /// Removes the given number of most significant blocks of the ciphertext.
+    ///
+    /// The result encrypts the value modulo the new number of blocks, no PBS is computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.trim_radix_blocks_msb(&ct, 2);
+    /// assert_eq!(ct_res.blocks().len(), 2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg % 16, dec);
+    /// ```
+    pub fn trim_radix_blocks_msb(
+        &self,
+        ct: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.trim_radix_blocks_msb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Removes the given number of most significant blocks of the ciphertext.
+    ///
+    /// The result is assigned to the input ciphertext.
+    pub fn trim_radix_blocks_msb_assign(&self, ct: &mut RadixCiphertext, num_blocks: usize) {
+        assert!(
+            num_blocks <= ct.blocks.len(),
+            "cannot remove {num_blocks} blocks from a ciphertext of {} blocks",
+            ct.blocks.len()
+        );
+        let new_len = ct.blocks.len() - num_blocks;
+        ct.blocks.truncate(new_len);
+    }
+
+    /// Removes the given number of least significant blocks of the ciphertext.
+    ///
+    /// The result encrypts the value divided by `message_modulus^num_blocks`, no PBS is computed.
+    ///
+    /// The carries of the removed blocks are lost, so they should be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.trim_radix_blocks_lsb(&ct, 2);
+    /// assert_eq!(ct_res.blocks().len(), 2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg / 16, dec);
+    /// ```
+    pub fn trim_radix_blocks_lsb(
+        &self,
+        ct: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.trim_radix_blocks_lsb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Removes the given number of least significant blocks of the ciphertext.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// The carries of the removed blocks are lost, so they should be empty.
+    pub fn trim_radix_blocks_lsb_assign(&self, ct: &mut RadixCiphertext, num_blocks: usize) {
+        assert!(
+            num_blocks <= ct.blocks.len(),
+            "cannot remove {num_blocks} blocks from a ciphertext of {} blocks",
+            ct.blocks.len()
+        );
+        ct.blocks.drain(..num_blocks);
+    }
+
+    /// Casts the ciphertext to the given number of blocks.
+    ///
+    /// If the new number of blocks is greater, the value is zero extended,
+    /// otherwise the most significant blocks are removed and the value wraps around.
+    ///
+    /// Requires carry bits to be empty when the ciphertext is extended.
+    ///
+    /// The blocks of the ciphertext are kept as is, so this can not convert between the
+    /// integer types of the typed API (eg `FheUint8` to `FheUint16`): each of these types
+    /// has its own client key, and its ciphertexts can not be decrypted with the key of another.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.cast_to_num_blocks(&ct, 8);
+    /// assert_eq!(cks.decrypt(&ct_res), msg);
+    ///
+    /// let ct_res = sks.cast_to_num_blocks(&ct, 3);
+    /// assert_eq!(cks.decrypt(&ct_res), msg % 64);
+    /// ```
+    pub fn cast_to_num_blocks(&self, ct: &RadixCiphertext, num_blocks: usize) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.cast_to_num_blocks_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Casts the ciphertext to the given number of blocks.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// Requires carry bits to be empty when the ciphertext is extended.
+    pub fn cast_to_num_blocks_assign(&self, ct: &mut RadixCiphertext, num_blocks: usize) {
+        let current_num_blocks = ct.blocks.len();
+        if num_blocks > current_num_blocks {
+            self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                ct,
+                num_blocks - current_num_blocks,
+            );
+        } else {
+            self.trim_radix_blocks_msb_assign(ct, current_num_blocks - num_blocks);
+        }
+    }
+    /// Casts the ciphertext to the given number of blocks.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 119u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    /// // The addition fills the carries of the ciphertext
+    /// sks.unchecked_add_assign(&mut ct, &cks.encrypt(msg));
+    ///
+    /// let ct_res = sks.smart_cast_to_num_blocks(&mut ct, 8);
+    /// assert_eq!(cks.decrypt(&ct_res), msg + msg);
+    /// ```
+    pub fn smart_cast_to_num_blocks(
+        &self,
+        ct: &mut RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.cast_to_num_blocks(ct, num_blocks)
+    }
+
+    /// Casts the ciphertext to the given number of blocks.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// The carries of the input are propagated if needed.
+    pub fn smart_cast_to_num_blocks_assign(&self, ct: &mut RadixCiphertext, num_blocks: usize) {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.cast_to_num_blocks_assign(ct, num_blocks);
+    }
+}
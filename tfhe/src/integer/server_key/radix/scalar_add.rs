@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
@@ -33,7 +34,10 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn unchecked_scalar_add(&self, ct: &RadixCiphertext, scalar: u64) -> RadixCiphertext {
+    pub fn unchecked_scalar_add<T>(&self, ct: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
         let mut result = ct.clone();
         self.unchecked_scalar_add_assign(&mut result, scalar);
         result
@@ -45,24 +49,37 @@ impl ServerKey {
     /// ciphertext.
     ///
     /// The result is assigned to the `ct_left` ciphertext.
-    pub fn unchecked_scalar_add_assign(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        // Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u64;
-
-        let mut power = 1_u64;
-        // Put each decomposition into a new ciphertext
-        for ct_i in ct.blocks.iter_mut() {
-            let mut decomp = scalar & (mask * power);
-            decomp /= power;
+    pub fn unchecked_scalar_add_assign<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
+    }
 
+    /// Adds block-wise the decomposition of a scalar to a ciphertext.
+    pub(crate) fn unchecked_scalar_decomposition_add_assign(
+        &self,
+        ct: &mut RadixCiphertext,
+        decomposition: &[u64],
+    ) {
+        for (ct_i, &decomp) in ct.blocks.iter_mut().zip(decomposition.iter()) {
             self.key.unchecked_scalar_add_assign(ct_i, decomp as u8);
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            power = new_power;
         }
     }
 
+    /// Verifies if the decomposition of a scalar can be added block-wise to a ciphertext.
+    pub(crate) fn is_scalar_decomposition_add_possible(
+        &self,
+        ct: &RadixCiphertext,
+        decomposition: &[u64],
+    ) -> bool {
+        ct.blocks
+            .iter()
+            .zip(decomposition.iter())
+            .all(|(ct_i, &decomp)| self.key.is_scalar_add_possible(ct_i, decomp as u8))
+    }
+
     /// Verifies if a scalar can be added to a ciphertext.
     ///
     /// # Example
@@ -76,7 +93,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 2;
-    /// let scalar = 40;
+    /// let scalar = 40u64;
     ///
     /// // Encrypt two messages:
     /// let ct1 = cks.encrypt(msg);
@@ -87,25 +104,12 @@ impl ServerKey {
     ///
     /// assert_eq!(true, res);
     /// ```
-    pub fn is_scalar_add_possible(&self, ct: &RadixCiphertext, scalar: u64) -> bool {
-        //Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u64;
-
-        let mut power = 1_u64;
-
-        for ct_i in ct.blocks.iter() {
-            let mut decomp = scalar & (mask * power);
-            decomp /= power;
-
-            if !self.key.is_scalar_add_possible(ct_i, decomp as u8) {
-                return false;
-            }
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            power = new_power;
-        }
-        true
+    pub fn is_scalar_add_possible<T>(&self, ct: &RadixCiphertext, scalar: T) -> bool
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        self.is_scalar_decomposition_add_possible(ct, &decomposition)
     }
 
     /// Computes homomorphically an addition between a scalar and a ciphertext.
@@ -138,13 +142,19 @@ impl ServerKey {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn checked_scalar_add(
+    pub fn checked_scalar_add<T>(
         &self,
         ct: &RadixCiphertext,
-        scalar: u64,
-    ) -> Result<RadixCiphertext, CheckError> {
-        if self.is_scalar_add_possible(ct, scalar) {
-            Ok(self.unchecked_scalar_add(ct, scalar))
+        scalar: T,
+    ) -> Result<RadixCiphertext, CheckError>
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if self.is_scalar_decomposition_add_possible(ct, &decomposition) {
+            let mut result = ct.clone();
+            self.unchecked_scalar_decomposition_add_assign(&mut result, &decomposition);
+            Ok(result)
         } else {
             Err(CarryFull)
         }
@@ -154,13 +164,17 @@ impl ServerKey {
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
     /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
-    pub fn checked_scalar_add_assign(
+    pub fn checked_scalar_add_assign<T>(
         &self,
         ct: &mut RadixCiphertext,
-        scalar: u64,
-    ) -> Result<(), CheckError> {
-        if self.is_scalar_add_possible(ct, scalar) {
-            self.unchecked_scalar_add_assign(ct, scalar);
+        scalar: T,
+    ) -> Result<(), CheckError>
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if self.is_scalar_decomposition_add_possible(ct, &decomposition) {
+            self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
             Ok(())
         } else {
             Err(CarryFull)
@@ -193,13 +207,17 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add(&self, ct: &mut RadixCiphertext, scalar: u64) -> RadixCiphertext {
-        if !self.is_scalar_add_possible(ct, scalar) {
+    pub fn smart_scalar_add<T>(&self, ct: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate(ct);
         }
 
         let mut ct = ct.clone();
-        self.unchecked_scalar_add_assign(&mut ct, scalar);
+        self.unchecked_scalar_decomposition_add_assign(&mut ct, &decomposition);
         ct
     }
 
@@ -229,10 +247,14 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add_assign(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        if !self.is_scalar_add_possible(ct, scalar) {
+    pub fn smart_scalar_add_assign<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate(ct);
         }
-        self.unchecked_scalar_add_assign(ct, scalar);
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
     }
 }
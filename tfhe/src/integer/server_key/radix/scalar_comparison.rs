@@ -0,0 +1,556 @@
+use super::ServerKey;
+
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::RadixCiphertext;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    /// Compares for equality a ciphertext and a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs == scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_eq(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg == scalar));
+    /// ```
+    pub fn unchecked_scalar_eq<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_eq(lhs, scalar)
+    }
+
+    /// Compares for difference a ciphertext and a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs != scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_ne(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg != scalar));
+    /// ```
+    pub fn unchecked_scalar_ne<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_ne(lhs, scalar)
+    }
+
+    /// Compares if lhs is strictly greater than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs > scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_gt(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg > scalar));
+    /// ```
+    pub fn unchecked_scalar_gt<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_gt(lhs, scalar)
+    }
+
+    /// Compares if lhs is greater or equal than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs >= scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_ge(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg >= scalar));
+    /// ```
+    pub fn unchecked_scalar_ge<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_ge(lhs, scalar)
+    }
+
+    /// Compares if lhs is strictly lower than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs < scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_lt(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg < scalar));
+    /// ```
+    pub fn unchecked_scalar_lt<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_lt(lhs, scalar)
+    }
+
+    /// Compares if lhs is lower or equal than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs <= scalar, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_le(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg <= scalar));
+    /// ```
+    pub fn unchecked_scalar_le<T>(&self, lhs: &RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_le(lhs, scalar)
+    }
+
+    /// Computes the max of an encrypted value and a clear value
+    ///
+    /// If the clear value does not fit in the ciphertext,
+    /// the max saturates to the greatest value the ciphertext can hold.
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 237u64;
+    /// let scalar = 23u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_max(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::max(msg, scalar));
+    /// ```
+    pub fn unchecked_scalar_max<T>(&self, lhs: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_max(lhs, scalar)
+    }
+
+    /// Computes the min of an encrypted value and a clear value
+    ///
+    /// If the clear value does not fit in the ciphertext,
+    /// the max saturates to the greatest value the ciphertext can hold.
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 237u64;
+    /// let scalar = 23u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_min(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::min(msg, scalar));
+    /// ```
+    pub fn unchecked_scalar_min<T>(&self, lhs: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_min(lhs, scalar)
+    }
+
+    /// Compares for equality a ciphertext and a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs == scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_eq(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg == scalar));
+    /// ```
+    pub fn smart_scalar_eq<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_eq(lhs, scalar)
+    }
+
+    /// Compares for difference a ciphertext and a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs != scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_ne(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg != scalar));
+    /// ```
+    pub fn smart_scalar_ne<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_ne(lhs, scalar)
+    }
+
+    /// Compares if lhs is strictly greater than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs > scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_gt(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg > scalar));
+    /// ```
+    pub fn smart_scalar_gt<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_gt(lhs, scalar)
+    }
+
+    /// Compares if lhs is greater or equal than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs >= scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_ge(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg >= scalar));
+    /// ```
+    pub fn smart_scalar_ge<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_ge(lhs, scalar)
+    }
+
+    /// Compares if lhs is strictly lower than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs < scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_lt(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg < scalar));
+    /// ```
+    pub fn smart_scalar_lt<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_lt(lhs, scalar)
+    }
+
+    /// Compares if lhs is lower or equal than a clear value
+    ///
+    /// Returns a boolean block containing 1 if lhs <= scalar, otherwise 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 14u64;
+    /// let scalar = 97u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_le(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_one_block(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg <= scalar));
+    /// ```
+    pub fn smart_scalar_le<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_le(lhs, scalar)
+    }
+
+    /// Computes the max of an encrypted value and a clear value
+    ///
+    /// If the clear value does not fit in the ciphertext,
+    /// the max saturates to the greatest value the ciphertext can hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 237u64;
+    /// let scalar = 23u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_max(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::max(msg, scalar));
+    /// ```
+    pub fn smart_scalar_max<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_max(lhs, scalar)
+    }
+
+    /// Computes the min of an encrypted value and a clear value
+    ///
+    /// If the clear value does not fit in the ciphertext,
+    /// the max saturates to the greatest value the ciphertext can hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 237u64;
+    /// let scalar = 23u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_min(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, std::cmp::min(msg, scalar));
+    /// ```
+    pub fn smart_scalar_min<T>(&self, lhs: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_min(lhs, scalar)
+    }
+}
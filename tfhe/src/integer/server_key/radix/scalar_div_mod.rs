@@ -0,0 +1,601 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+
+/// Division algorithm selected from the clear value of a divisor
+pub(crate) enum ScalarDivisor {
+    /// The divisor is zero
+    Zero,
+    /// The divisor is greater than any value the numerator can hold
+    TooLarge,
+    /// The divisor is `2^k`
+    PowerOfTwo(usize),
+    /// The division is a multiplication by a magic number followed by shifts
+    /// (see [choose_multiplier])
+    Multiplier {
+        divisor: u64,
+        multiplier: u64,
+        shift: usize,
+    },
+    /// The divisor does not fit the multiplication, a long division is needed
+    LongDivision,
+}
+
+impl ScalarDivisor {
+    pub(crate) fn new<T: AsLittleEndianWords>(divisor: &T, num_bits: usize) -> Self {
+        let words = divisor.as_little_endian_iter().copied().collect::<Vec<_>>();
+
+        let Some(msw_index) = words.iter().rposition(|word| *word != 0) else {
+            return Self::Zero;
+        };
+        let divisor_bits = msw_index * 64 + (64 - words[msw_index].leading_zeros() as usize);
+        let num_ones = words.iter().map(|word| word.count_ones()).sum::<u32>();
+
+        if num_ones == 1 {
+            let power = divisor_bits - 1;
+            if power >= num_bits {
+                Self::TooLarge
+            } else {
+                Self::PowerOfTwo(power)
+            }
+        } else if divisor_bits > num_bits {
+            Self::TooLarge
+        } else if num_bits <= 64 {
+            let (multiplier, shift) = choose_multiplier(words[0], num_bits);
+            Self::Multiplier {
+                divisor: words[0],
+                multiplier,
+                shift,
+            }
+        } else {
+            Self::LongDivision
+        }
+    }
+}
+
+/// Returns `(m, l)` such that for any `n` on `num_bits` bits,
+/// `n / divisor == (t + ((n - t) >> 1)) >> (l - 1)` where `t = (m * n) >> num_bits`.
+///
+/// See Figure 4.1 of "Division by Invariant Integers using Multiplication"
+/// by Granlund and Montgomery.
+///
+/// `divisor` must be greater than 1 and `num_bits` must be at most 64.
+pub(crate) fn choose_multiplier(divisor: u64, num_bits: usize) -> (u64, usize) {
+    debug_assert!(divisor > 1 && num_bits <= 64);
+    // l = ceil(log2(divisor))
+    let shift = (64 - (divisor - 1).leading_zeros()) as usize;
+    let divisor = divisor as u128;
+    let multiplier = ((1u128 << num_bits) * ((1u128 << shift) - divisor)) / divisor + 1;
+    (multiplier as u64, shift)
+}
+
+/// Returns the inverse of an odd `value` modulo `2^num_bits`, with `num_bits` at most 64.
+pub(crate) fn modular_inverse(value: u64, num_bits: usize) -> u64 {
+    debug_assert!(value % 2 == 1 && num_bits <= 64);
+    // Newton's iteration, each step doubles the number of correct bits:
+    // the value is its own inverse modulo 2^3
+    let mut inverse = value;
+    for _ in 0..5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(value.wrapping_mul(inverse)));
+    }
+    if num_bits < 64 {
+        inverse &= (1 << num_bits) - 1;
+    }
+    inverse
+}
+
+impl ServerKey {
+    /// Returns the number of bits of message a ciphertext holds
+    pub(crate) fn radix_message_bits(&self, ctxt: &RadixCiphertext) -> usize {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        nb_bits * ctxt.blocks.len()
+    }
+
+    /// Creates a trivial ciphertext with all bits of message set to 1
+    pub(crate) fn create_trivial_max_radix(&self, num_blocks: usize) -> RadixCiphertext {
+        let max_block_value = self.key.message_modulus.0 as u64 - 1;
+        RadixCiphertext::from(
+            (0..num_blocks)
+                .map(|_| self.key.create_trivial(max_block_value))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Keeps the `num_bits` lsb of the ciphertext, higher bits are set to zero
+    pub(crate) fn unchecked_scalar_low_bits(
+        &self,
+        ctxt: &RadixCiphertext,
+        num_bits: usize,
+    ) -> RadixCiphertext {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_full_blocks = num_bits / nb_bits;
+
+        let mut result = ctxt.clone();
+        for (i, block) in result.blocks.iter_mut().enumerate().skip(num_full_blocks) {
+            if i == num_full_blocks && num_bits % nb_bits != 0 {
+                let mask = (1 << (num_bits % nb_bits)) - 1;
+                let acc = self.key.generate_accumulator(|x| x & mask);
+                self.key.apply_lookup_table_assign(block, &acc);
+            } else {
+                *block = self.key.create_trivial(0);
+            }
+        }
+        result
+    }
+
+    /// Computes `numerator / divisor` with a multiplication by a magic number,
+    /// see [choose_multiplier].
+    fn unchecked_scalar_div_by_multiplier(
+        &self,
+        numerator: &RadixCiphertext,
+        multiplier: u64,
+        shift: usize,
+    ) -> RadixCiphertext {
+        let num_blocks = numerator.blocks.len();
+
+        // t = (m * n) >> num_bits, the product is computed on twice as many blocks
+        // so that its msb are not lost
+        let mut extended_numerator = numerator.clone();
+        extended_numerator
+            .blocks
+            .extend((0..num_blocks).map(|_| self.key.create_trivial(0)));
+        let mut product = self.smart_scalar_mul(&mut extended_numerator, multiplier);
+        self.full_propagate(&mut product);
+        let mut t = RadixCiphertext::from(product.blocks.split_off(num_blocks));
+
+        // q = (t + ((n - t) >> 1)) >> (l - 1)
+        let mut numerator = numerator.clone();
+        // The shifts require empty carries
+        let mut difference = self.smart_sub(&mut numerator, &mut t);
+        self.full_propagate(&mut difference);
+        let mut half_difference = self.unchecked_scalar_right_shift(&difference, 1);
+        let mut quotient = self.smart_add(&mut t, &mut half_difference);
+        self.full_propagate(&mut quotient);
+        self.unchecked_scalar_right_shift(&quotient, shift - 1)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// Divisions by a power of two are shifts, other divisors use a multiplication by a
+    /// precomputed reciprocal when the ciphertext holds at most 64 bits, and a long division
+    /// otherwise.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 7u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let (q, r) = sks.unchecked_scalar_div_rem(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg / scalar);
+    /// assert_eq!(r, msg % scalar);
+    /// ```
+    pub fn unchecked_scalar_div_rem<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> (RadixCiphertext, RadixCiphertext)
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = numerator.blocks.len();
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero => (self.create_trivial_max_radix(num_blocks), numerator.clone()),
+            ScalarDivisor::TooLarge => (
+                self.create_trivial_zero_radix(num_blocks),
+                numerator.clone(),
+            ),
+            ScalarDivisor::PowerOfTwo(power) => (
+                self.unchecked_scalar_right_shift(numerator, power),
+                self.unchecked_scalar_low_bits(numerator, power),
+            ),
+            ScalarDivisor::Multiplier {
+                divisor,
+                multiplier,
+                shift,
+            } => {
+                let mut quotient =
+                    self.unchecked_scalar_div_by_multiplier(numerator, multiplier, shift);
+                let mut product = self.smart_scalar_mul(&mut quotient, divisor);
+                let mut numerator = numerator.clone();
+                let mut remainder = self.smart_sub(&mut numerator, &mut product);
+                self.full_propagate(&mut remainder);
+                (quotient, remainder)
+            }
+            ScalarDivisor::LongDivision => {
+                let divisor = self.create_trivial_radix(divisor, num_blocks);
+                self.unchecked_div_rem(numerator, &divisor)
+            }
+        }
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar.
+    ///
+    /// See [Self::unchecked_scalar_div_rem] for the algorithms used.
+    ///
+    /// If the divisor is zero, the result is the maximum value that can be represented
+    /// (all bits set to 1).
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 16u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let ct_res = sks.unchecked_scalar_div(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn unchecked_scalar_div<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = numerator.blocks.len();
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero => self.create_trivial_max_radix(num_blocks),
+            ScalarDivisor::TooLarge => self.create_trivial_zero_radix(num_blocks),
+            ScalarDivisor::PowerOfTwo(power) => self.unchecked_scalar_right_shift(numerator, power),
+            ScalarDivisor::Multiplier {
+                multiplier, shift, ..
+            } => self.unchecked_scalar_div_by_multiplier(numerator, multiplier, shift),
+            ScalarDivisor::LongDivision => {
+                let divisor = self.create_trivial_radix(divisor, num_blocks);
+                self.unchecked_div_rem(numerator, &divisor).0
+            }
+        }
+    }
+
+    /// Computes homomorphically the remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the numerator.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 32u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar remainder:
+    /// let ct_res = sks.unchecked_scalar_rem(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg % scalar);
+    /// ```
+    pub fn unchecked_scalar_rem<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero | ScalarDivisor::TooLarge => numerator.clone(),
+            ScalarDivisor::PowerOfTwo(power) => self.unchecked_scalar_low_bits(numerator, power),
+            _ => self.unchecked_scalar_div_rem(numerator, divisor).1,
+        }
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar,
+    /// knowing that the division is exact (the remainder is zero).
+    ///
+    /// The division is a shift followed by a multiplication by the inverse of the divisor
+    /// modulo `2^num_bits` when the ciphertext holds at most 64 bits, which is cheaper
+    /// than [Self::unchecked_scalar_div].
+    ///
+    /// If the division is not exact, the result is meaningless.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 228;
+    /// let scalar = 12u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an exact scalar division:
+    /// let ct_res = sks.unchecked_scalar_div_exact(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn unchecked_scalar_div_exact<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_bits = self.radix_message_bits(numerator);
+        match ScalarDivisor::new(&divisor, num_bits) {
+            ScalarDivisor::Zero => panic!("attempt to divide by zero"),
+            ScalarDivisor::Multiplier { divisor, .. } => {
+                let power = divisor.trailing_zeros() as usize;
+                let odd_divisor = divisor >> power;
+                let mut shifted = self.unchecked_scalar_right_shift(numerator, power);
+                if odd_divisor == 1 {
+                    return shifted;
+                }
+                let mut result =
+                    self.smart_scalar_mul(&mut shifted, modular_inverse(odd_divisor, num_bits));
+                self.full_propagate(&mut result);
+                result
+            }
+            _ => self.unchecked_scalar_div(numerator, divisor),
+        }
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let (q, r) = sks.smart_scalar_div_rem(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg / scalar);
+    /// assert_eq!(r, msg % scalar);
+    /// ```
+    pub fn smart_scalar_div_rem<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> (RadixCiphertext, RadixCiphertext)
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate(numerator);
+        }
+        self.unchecked_scalar_div_rem(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the maximum value that can be represented
+    /// (all bits set to 1).
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let ct_res = sks.smart_scalar_div(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn smart_scalar_div<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate(numerator);
+        }
+        self.unchecked_scalar_div(numerator, divisor)
+    }
+
+    /// Computes homomorphically the remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the numerator.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar remainder:
+    /// let ct_res = sks.smart_scalar_rem(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg % scalar);
+    /// ```
+    pub fn smart_scalar_rem<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate(numerator);
+        }
+        self.unchecked_scalar_rem(numerator, divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_choose_multiplier() {
+        let mut rng = rand::thread_rng();
+
+        for num_bits in [8, 16, 32, 64] {
+            let max = if num_bits == 64 {
+                u64::MAX
+            } else {
+                (1 << num_bits) - 1
+            };
+            for _ in 0..1000 {
+                let divisor = rng.gen_range(3..=max);
+                let numerator = rng.gen::<u64>() & max;
+                let (multiplier, shift) = choose_multiplier(divisor, num_bits);
+
+                let t = ((multiplier as u128 * numerator as u128) >> num_bits) as u64;
+                let quotient = (t + ((numerator - t) >> 1)) >> (shift - 1);
+                assert_eq!(quotient, numerator / divisor);
+            }
+        }
+    }
+
+    #[test]
+    fn test_modular_inverse() {
+        let mut rng = rand::thread_rng();
+
+        for num_bits in [8, 16, 32, 64] {
+            for _ in 0..1000 {
+                let value = rng.gen::<u64>() | 1;
+                let inverse = modular_inverse(value, num_bits);
+                let product = value.wrapping_mul(inverse);
+                if num_bits == 64 {
+                    assert_eq!(product, 1);
+                } else {
+                    assert_eq!(product % (1 << num_bits), 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_divisor_kind() {
+        assert!(matches!(ScalarDivisor::new(&0u64, 8), ScalarDivisor::Zero));
+        assert!(matches!(
+            ScalarDivisor::new(&16u64, 8),
+            ScalarDivisor::PowerOfTwo(4)
+        ));
+        assert!(matches!(
+            ScalarDivisor::new(&256u64, 8),
+            ScalarDivisor::TooLarge
+        ));
+        assert!(matches!(
+            ScalarDivisor::new(&300u64, 8),
+            ScalarDivisor::TooLarge
+        ));
+        assert!(matches!(
+            ScalarDivisor::new(&(1u128 << 100), 128),
+            ScalarDivisor::PowerOfTwo(100)
+        ));
+        assert!(matches!(
+            ScalarDivisor::new(&7u64, 8),
+            ScalarDivisor::Multiplier { divisor: 7, .. }
+        ));
+        assert!(matches!(
+            ScalarDivisor::new(&7u128, 128),
+            ScalarDivisor::LongDivision
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
@@ -317,9 +318,10 @@ impl ServerKey {
     /// let clear = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
-    pub fn smart_scalar_mul(&self, ctxt: &mut RadixCiphertext, scalar: u64) -> RadixCiphertext {
-        let mask = (self.key.message_modulus.0 - 1) as u64;
-
+    pub fn smart_scalar_mul<T>(&self, ctxt: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
         //Propagate the carries before doing the multiplications
         self.full_propagate(ctxt);
 
@@ -330,16 +332,10 @@ impl ServerKey {
 
         let mut tmp;
 
-        let mut b_i = 1_u64;
-        for i in 0..ctxt.blocks.len() {
-            //lambda = sum u_ib^i
-            let u_ib_i = scalar & (mask * b_i);
-            let u_i = u_ib_i / b_i;
-
+        //lambda = sum u_ib^i
+        let decomposition = self.scalar_block_decomposition(&scalar, ctxt.blocks.len());
+        for (i, u_i) in decomposition.into_iter().enumerate() {
             if u_i == 0 {
-                //update the power b^{i+1}
-                let Some(new_power) = b_i.checked_mul(self.key.message_modulus.0 as u64) else {break};
-                b_i = new_power;
                 continue;
             } else if u_i == 1 {
                 // tmp = ctxt * 1 * b^i
@@ -356,16 +352,15 @@ impl ServerKey {
 
             //update the result
             result = self.smart_add(&mut result, &mut tmp);
-
-            //update the power b^{i+1}
-            let Some(new_power) = b_i.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            b_i = new_power;
         }
 
         result
     }
 
-    pub fn smart_scalar_mul_assign(&self, ctxt: &mut RadixCiphertext, scalar: u64) {
+    pub fn smart_scalar_mul_assign<T>(&self, ctxt: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
         *ctxt = self.smart_scalar_mul(ctxt, scalar);
     }
 }
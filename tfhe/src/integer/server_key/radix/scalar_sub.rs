@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
@@ -33,37 +34,21 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn unchecked_scalar_sub(&self, ct: &RadixCiphertext, scalar: u64) -> RadixCiphertext {
+    pub fn unchecked_scalar_sub<T>(&self, ct: &RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
         let mut result = ct.clone();
         self.unchecked_scalar_sub_assign(&mut result, scalar);
         result
     }
 
-    pub fn unchecked_scalar_sub_assign(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        // Widen to 128 bits to avoid overflow when doing wrapped negation.
-        let scalar = scalar as u128;
-        //Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u128;
-
-        let modulus = (self.key.message_modulus.0 as u128).checked_pow(ct.blocks.len() as u32);
-
-        let neg_scalar = match modulus {
-            Some(modul) => scalar.wrapping_neg() % modul,
-            None => scalar.wrapping_neg(),
-        };
-
-        let mut power = 1_u128;
-        //Put each decomposition into a new ciphertext
-        for ct_i in ct.blocks.iter_mut() {
-            let mut decomp = neg_scalar & (mask * power);
-            decomp /= power;
-
-            self.key.unchecked_scalar_add_assign(ct_i, decomp as u8);
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u128) else {break};
-            power = new_power;
-        }
+    pub fn unchecked_scalar_sub_assign<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
     }
 
     /// Verifies if the subtraction of a ciphertext by scalar can be computed.
@@ -79,7 +64,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 40;
-    /// let scalar = 2;
+    /// let scalar = 2u64;
     ///
     /// let ct1 = cks.encrypt(msg);
     ///
@@ -88,33 +73,12 @@ impl ServerKey {
     ///
     /// assert_eq!(true, res);
     /// ```
-    pub fn is_scalar_sub_possible(&self, ct: &RadixCiphertext, scalar: u64) -> bool {
-        //Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u64;
-
-        let modulus = self
-            .key
-            .message_modulus
-            .0
-            .saturating_pow(ct.blocks.len() as u32) as u64;
-
-        let neg_scalar = scalar.wrapping_neg() % modulus;
-
-        let mut power = 1_u64;
-
-        for ct_i in ct.blocks.iter() {
-            let mut decomp = neg_scalar & (mask * power);
-            decomp /= power;
-
-            if !self.key.is_scalar_add_possible(ct_i, decomp as u8) {
-                return false;
-            }
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            power = new_power;
-        }
-        true
+    pub fn is_scalar_sub_possible<T>(&self, ct: &RadixCiphertext, scalar: T) -> bool
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        self.is_scalar_decomposition_add_possible(ct, &decomposition)
     }
 
     /// Computes homomorphically a subtraction of a ciphertext by a scalar.
@@ -147,13 +111,19 @@ impl ServerKey {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn checked_scalar_sub(
+    pub fn checked_scalar_sub<T>(
         &self,
         ct: &RadixCiphertext,
-        scalar: u64,
-    ) -> Result<RadixCiphertext, CheckError> {
-        if self.is_scalar_sub_possible(ct, scalar) {
-            Ok(self.unchecked_scalar_sub(ct, scalar))
+        scalar: T,
+    ) -> Result<RadixCiphertext, CheckError>
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if self.is_scalar_decomposition_add_possible(ct, &decomposition) {
+            let mut result = ct.clone();
+            self.unchecked_scalar_decomposition_add_assign(&mut result, &decomposition);
+            Ok(result)
         } else {
             Err(CarryFull)
         }
@@ -189,13 +159,17 @@ impl ServerKey {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn checked_scalar_sub_assign(
+    pub fn checked_scalar_sub_assign<T>(
         &self,
         ct: &mut RadixCiphertext,
-        scalar: u64,
-    ) -> Result<(), CheckError> {
-        if self.is_scalar_sub_possible(ct, scalar) {
-            self.unchecked_scalar_sub_assign(ct, scalar);
+        scalar: T,
+    ) -> Result<(), CheckError>
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if self.is_scalar_decomposition_add_possible(ct, &decomposition) {
+            self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
             Ok(())
         } else {
             Err(CarryFull)
@@ -226,19 +200,29 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn smart_scalar_sub(&self, ct: &mut RadixCiphertext, scalar: u64) -> RadixCiphertext {
-        if !self.is_scalar_sub_possible(ct, scalar) {
+    pub fn smart_scalar_sub<T>(&self, ct: &mut RadixCiphertext, scalar: T) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate(ct);
         }
 
-        self.unchecked_scalar_sub(ct, scalar)
+        let mut result = ct.clone();
+        self.unchecked_scalar_decomposition_add_assign(&mut result, &decomposition);
+        result
     }
 
-    pub fn smart_scalar_sub_assign(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        if !self.is_scalar_sub_possible(ct, scalar) {
+    pub fn smart_scalar_sub_assign<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate(ct);
         }
 
-        self.unchecked_scalar_sub_assign(ct, scalar);
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
     }
 }
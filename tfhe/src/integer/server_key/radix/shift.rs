@@ -1,5 +1,44 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
 use crate::integer::ServerKey;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::CiphertextBig;
+
+/// The kind of operation computed by the barrel shifter
+#[derive(Clone, Copy)]
+pub(crate) enum BarrelShiftKind {
+    LeftShift,
+    RightShift,
+    LeftRotation,
+    RightRotation,
+}
+
+impl BarrelShiftKind {
+    /// Returns the amount by which the stage handling the `stage`-th bit of the
+    /// shift amount moves the bits, or `None` if the stage shifts out every bit.
+    pub(crate) fn stage_amount(self, stage: usize, num_bits: usize) -> Option<usize> {
+        match self {
+            Self::LeftShift | Self::RightShift => 1usize
+                .checked_shl(stage as u32)
+                .filter(|amount| *amount < num_bits),
+            // Rotations are modulo the number of bits, so is 2^stage
+            Self::LeftRotation | Self::RightRotation => {
+                Some((0..stage).fold(1 % num_bits, |amount, _| (2 * amount) % num_bits))
+            }
+        }
+    }
+
+    /// Returns the index of the bit that ends up at `index` once moved by `amount`,
+    /// or `None` if a zero is shifted in.
+    pub(crate) fn source_bit(self, index: usize, amount: usize, num_bits: usize) -> Option<usize> {
+        match self {
+            Self::LeftShift => index.checked_sub(amount),
+            Self::RightShift => Some(index + amount).filter(|source| *source < num_bits),
+            Self::LeftRotation => Some((index + num_bits - amount) % num_bits),
+            Self::RightRotation => Some((index + amount) % num_bits),
+        }
+    }
+}
 
 impl ServerKey {
     /// Shifts the blocks to the right.
@@ -143,7 +182,7 @@ impl ServerKey {
 
     /// Propagates all carries except the last one.
     /// For development purpose only.
-    fn partial_propagate(&self, ctxt: &mut RadixCiphertext) {
+    pub(crate) fn partial_propagate(&self, ctxt: &mut RadixCiphertext) {
         let len = ctxt.blocks.len() - 1;
         for i in 0..len {
             self.propagate(ctxt, i);
@@ -216,4 +255,485 @@ impl ServerKey {
         let tmp = 1_u64 << shift;
         self.smart_scalar_mul_assign(ct, tmp);
     }
+
+    /// Computes homomorphically a left rotation by a scalar.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a left rotation:
+    /// let ct_res = sks.unchecked_scalar_rotate_left(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_left(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_scalar_rotate_left(&self, ct: &RadixCiphertext, n: usize) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_left_assign(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a left rotation by a scalar.
+    ///
+    /// The result is assigned in the input ciphertext
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_scalar_rotate_left_assign(&self, ct: &mut RadixCiphertext, n: usize) {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        //number of bits of message
+        let nb_bits = (message_modulus as f64).log2() as usize;
+        let num_blocks = ct.blocks.len();
+        let n = n % (nb_bits * num_blocks);
+
+        // Whole blocks are moved around, the remaining bits then go from one block
+        // to the next one
+        ct.blocks.rotate_right(n / nb_bits);
+        let shift_within_block = n % nb_bits;
+        if shift_within_block == 0 {
+            return;
+        }
+
+        let acc = self
+            .key
+            .generate_accumulator_bivariate(|current, previous| {
+                ((current << shift_within_block) | (previous >> (nb_bits - shift_within_block)))
+                    % message_modulus
+            });
+        ct.blocks = (0..num_blocks)
+            .map(|i| {
+                let previous = &ct.blocks[(i + num_blocks - 1) % num_blocks];
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[i], previous, &acc)
+            })
+            .collect();
+    }
+
+    /// Computes homomorphically a right rotation by a scalar.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a right rotation:
+    /// let ct_res = sks.unchecked_scalar_rotate_right(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_scalar_rotate_right(&self, ct: &RadixCiphertext, n: usize) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_right_assign(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a right rotation by a scalar.
+    ///
+    /// The result is assigned in the input ciphertext
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_scalar_rotate_right_assign(&self, ct: &mut RadixCiphertext, n: usize) {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_bits = nb_bits * ct.blocks.len();
+        self.unchecked_scalar_rotate_left_assign(ct, num_bits - (n % num_bits));
+    }
+
+    /// Computes homomorphically a left shift by an encrypted amount.
+    ///
+    /// The bits shifted out are lost, if the amount is greater or equal to the number of bits
+    /// of the ciphertext the result is zero.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 21;
+    /// let shift = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_shift = cks.encrypt(shift);
+    ///
+    /// // Compute homomorphically a left shift:
+    /// let ct_res = sks.unchecked_left_shift(&ct, &ct_shift);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!((msg << shift) % 256, dec);
+    /// ```
+    pub fn unchecked_left_shift(
+        &self,
+        ct: &RadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift(ct, shift, BarrelShiftKind::LeftShift)
+    }
+
+    /// Computes homomorphically a right shift by an encrypted amount.
+    ///
+    /// If the amount is greater or equal to the number of bits of the ciphertext
+    /// the result is zero.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 180;
+    /// let shift = 5;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_shift = cks.encrypt(shift);
+    ///
+    /// // Compute homomorphically a right shift:
+    /// let ct_res = sks.unchecked_right_shift(&ct, &ct_shift);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg >> shift, dec);
+    /// ```
+    pub fn unchecked_right_shift(
+        &self,
+        ct: &RadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift(ct, shift, BarrelShiftKind::RightShift)
+    }
+
+    /// Computes homomorphically a left rotation by an encrypted amount.
+    ///
+    /// The amount is taken modulo the number of bits of the ciphertext.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 5;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// let ct_n = cks.encrypt(n);
+    ///
+    /// // Compute homomorphically a left rotation:
+    /// let ct_res = sks.unchecked_rotate_left(&ct, &ct_n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_left(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_rotate_left(
+        &self,
+        ct: &RadixCiphertext,
+        n: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift(ct, n, BarrelShiftKind::LeftRotation)
+    }
+
+    /// Computes homomorphically a right rotation by an encrypted amount.
+    ///
+    /// The amount is taken modulo the number of bits of the ciphertext.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 5;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// let ct_n = cks.encrypt(n);
+    ///
+    /// // Compute homomorphically a right rotation:
+    /// let ct_res = sks.unchecked_rotate_right(&ct, &ct_n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_rotate_right(
+        &self,
+        ct: &RadixCiphertext,
+        n: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift(ct, n, BarrelShiftKind::RightRotation)
+    }
+
+    /// Computes homomorphically a left shift by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_left_shift(
+        &self,
+        ct: &mut RadixCiphertext,
+        shift: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs(ct, shift);
+        self.unchecked_left_shift(ct, shift)
+    }
+
+    /// Computes homomorphically a right shift by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_right_shift(
+        &self,
+        ct: &mut RadixCiphertext,
+        shift: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs(ct, shift);
+        self.unchecked_right_shift(ct, shift)
+    }
+
+    /// Computes homomorphically a left rotation by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_rotate_left(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs(ct, n);
+        self.unchecked_rotate_left(ct, n)
+    }
+
+    /// Computes homomorphically a right rotation by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_rotate_right(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs(ct, n);
+        self.unchecked_rotate_right(ct, n)
+    }
+
+    fn propagate_barrel_shift_inputs(
+        &self,
+        ct: &mut RadixCiphertext,
+        amount: &mut RadixCiphertext,
+    ) {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        if has_non_zero_carries(amount) {
+            self.full_propagate(amount);
+        }
+    }
+
+    /// Moves the bits of `ct` by the encrypted `amount`, one stage per bit of the amount,
+    /// each stage selecting with an encrypted mux between the moved and the unmoved bits.
+    fn unchecked_barrel_shift(
+        &self,
+        ct: &RadixCiphertext,
+        amount: &RadixCiphertext,
+        kind: BarrelShiftKind,
+    ) -> RadixCiphertext {
+        let mut bits = self.unchecked_radix_to_bits(ct);
+        let amount_bits = self.unchecked_radix_to_bits(amount);
+        let num_bits = bits.len();
+
+        let acc = self.bit_mux_accumulator();
+        let zero = self.key.create_trivial(0);
+
+        let mut shifted_out_selectors = Vec::new();
+        for (stage, selector) in amount_bits.iter().enumerate() {
+            let Some(stage_amount) = kind.stage_amount(stage, num_bits) else {
+                shifted_out_selectors.push(selector.clone());
+                continue;
+            };
+            if stage_amount == 0 {
+                continue;
+            }
+
+            bits = (0..num_bits)
+                .map(|i| {
+                    let moved_bit = kind
+                        .source_bit(i, stage_amount, num_bits)
+                        .map_or(&zero, |source| &bits[source]);
+                    self.unchecked_bit_mux(selector, moved_bit, &bits[i], &acc)
+                })
+                .collect();
+        }
+
+        if !shifted_out_selectors.is_empty() {
+            let is_shifted_out = self.unchecked_any_bit_set(&shifted_out_selectors);
+            for bit in bits.iter_mut() {
+                *bit = self.unchecked_bit_mux(&is_shifted_out, &zero, bit, &acc);
+            }
+        }
+
+        self.unchecked_bits_to_radix(&bits)
+    }
+
+    /// Extracts each bit of the ciphertext in its own block, from the least significant one.
+    ///
+    /// Requires carry bits to be empty.
+    pub(crate) fn unchecked_radix_to_bits(&self, ct: &RadixCiphertext) -> Vec<CiphertextBig> {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let accs = (0..nb_bits)
+            .map(|i| self.key.generate_accumulator(|x| (x >> i) & 1))
+            .collect::<Vec<_>>();
+
+        ct.blocks
+            .iter()
+            .flat_map(|block| {
+                accs.iter()
+                    .map(|acc| self.key.apply_lookup_table(block, acc))
+            })
+            .collect()
+    }
+
+    /// Packs blocks encrypting single bits, from the least significant one,
+    /// back into a radix ciphertext.
+    ///
+    /// No PBS is needed, the blocks of the result have empty carries.
+    pub(crate) fn unchecked_bits_to_radix(&self, bits: &[CiphertextBig]) -> RadixCiphertext {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+
+        let blocks = bits
+            .chunks(nb_bits)
+            .map(|block_bits| {
+                let mut block = block_bits[0].clone();
+                for (i, bit) in block_bits.iter().enumerate().skip(1) {
+                    let shifted_bit = self.key.unchecked_scalar_mul(bit, 1 << i);
+                    self.key.unchecked_add_assign(&mut block, &shifted_bit);
+                }
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Returns the accumulator used by [`Self::unchecked_bit_mux`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    pub(crate) fn bit_mux_accumulator(&self) -> LookupTableOwned {
+        assert!(
+            self.key.message_modulus.0 * self.key.carry_modulus.0 >= 8,
+            "The encrypted mux requires at least 3 bits of message and carry space"
+        );
+        self.key.generate_accumulator(|x| {
+            if (x >> 2) & 1 == 1 {
+                (x >> 1) & 1
+            } else {
+                x & 1
+            }
+        })
+    }
+
+    /// Selects homomorphically `if_true` if `selector` encrypts 1 and `if_false` if it encrypts 0.
+    ///
+    /// All the inputs must encrypt a single bit.
+    pub(crate) fn unchecked_bit_mux(
+        &self,
+        selector: &CiphertextBig,
+        if_true: &CiphertextBig,
+        if_false: &CiphertextBig,
+        acc: &LookupTableOwned,
+    ) -> CiphertextBig {
+        // The three bits are packed as selector * 4 + if_true * 2 + if_false
+        let mut packed = self.key.unchecked_scalar_mul(selector, 4);
+        let shifted_if_true = self.key.unchecked_scalar_mul(if_true, 2);
+        self.key.unchecked_add_assign(&mut packed, &shifted_if_true);
+        self.key.unchecked_add_assign(&mut packed, if_false);
+        self.key.apply_lookup_table(&packed, acc)
+    }
+
+    /// Returns a block encrypting 1 if any of the blocks encrypts a non zero value.
+    ///
+    /// All the inputs must encrypt a single bit.
+    pub(crate) fn unchecked_any_bit_set(&self, bits: &[CiphertextBig]) -> CiphertextBig {
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        let acc = self.key.generate_accumulator(|x| u64::from(x != 0));
+
+        // Sums as many bits as the block can hold before cleaning the sum
+        let mut result = bits[0].clone();
+        let mut num_summed = 1;
+        for bit in &bits[1..] {
+            if num_summed == total_modulus - 1 {
+                self.key.apply_lookup_table_assign(&mut result, &acc);
+                num_summed = 1;
+            }
+            self.key.unchecked_add_assign(&mut result, bit);
+            num_summed += 1;
+        }
+        self.key.apply_lookup_table_assign(&mut result, &acc);
+        result
+    }
 }
@@ -0,0 +1,465 @@
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+
+/// Selects which signed operation an overflow flag is computed for
+#[derive(Clone, Copy)]
+pub(crate) enum SignedOverflowingOp {
+    Add,
+    Sub,
+}
+
+impl SignedOverflowingOp {
+    /// Returns whether the operation overflowed, given the sign bits of its operands
+    /// and of its wrapped result
+    fn overflowed(self, lhs_sign: u64, rhs_sign: u64, result_sign: u64) -> bool {
+        match self {
+            // Adding two values of the same sign must give that sign
+            Self::Add => lhs_sign == rhs_sign && result_sign != lhs_sign,
+            // Subtracting a value of the opposite sign must keep the sign of lhs
+            Self::Sub => lhs_sign != rhs_sign && result_sign != lhs_sign,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Returns a block encrypting 1 if the ciphertext is negative, 0 otherwise
+    ///
+    /// Expects the carry buffers to be empty
+    pub(crate) fn unchecked_sign_bit(&self, ctxt: &SignedRadixCiphertext) -> CiphertextBig {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let acc = self
+            .key
+            .generate_accumulator(|x| u64::from(x >= message_modulus / 2));
+        self.key
+            .apply_lookup_table(ctxt.blocks.last().unwrap(), &acc)
+    }
+
+    /// Returns a block encrypting the sign bits of both operands, packed as
+    /// `lhs_sign + 2 * rhs_sign`
+    ///
+    /// Expects the carry buffers to be empty
+    pub(crate) fn unchecked_sign_bits_of_operands(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let acc = self.key.generate_accumulator_bivariate(|x, y| {
+            u64::from(x >= message_modulus / 2) + 2 * u64::from(y >= message_modulus / 2)
+        });
+        self.key.unchecked_apply_lookup_table_bivariate(
+            lhs.blocks.last().unwrap(),
+            rhs.blocks.last().unwrap(),
+            &acc,
+        )
+    }
+
+    /// Returns a block encrypting 1 if the operation overflowed, 0 otherwise
+    ///
+    /// `operand_signs` is the output of [Self::unchecked_sign_bits_of_operands],
+    /// and `result_sign` the sign bit of the wrapped result.
+    pub(crate) fn unchecked_signed_overflow_flag(
+        &self,
+        mut operand_signs: CiphertextBig,
+        result_sign: &CiphertextBig,
+        op: SignedOverflowingOp,
+    ) -> CiphertextBig {
+        assert!(
+            self.key.message_modulus.0 * self.key.carry_modulus.0 >= 8,
+            "At least 3 bits of space (message + carry) are required to detect signed overflows"
+        );
+
+        // 3 bits packed as: lhs_sign | rhs_sign | result_sign
+        self.key.unchecked_scalar_mul_assign(&mut operand_signs, 2);
+        self.key
+            .unchecked_add_assign(&mut operand_signs, result_sign);
+
+        let acc = self
+            .key
+            .generate_accumulator(|x| u64::from(op.overflowed((x >> 1) & 1, (x >> 2) & 1, x & 1)));
+        self.key.apply_lookup_table_assign(&mut operand_signs, &acc);
+        operand_signs
+    }
+
+    /// Homomorphically computes the opposite of a signed ciphertext.
+    ///
+    /// The negation wraps around: the opposite of the minimum value is itself.
+    ///
+    /// This function computes the opposite of a message without checking if it exceeds the
+    /// capacity of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = -73i8;
+    ///
+    /// let ctxt = cks.encrypt_signed(msg);
+    ///
+    /// // Compute homomorphically a negation
+    /// let ct_res = sks.unchecked_signed_neg(&ctxt);
+    ///
+    /// // Decrypt
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(-msg, dec);
+    /// ```
+    pub fn unchecked_signed_neg(&self, ctxt: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        let mut result = RadixCiphertext::from(ctxt.blocks.clone());
+        self.unchecked_neg_assign(&mut result);
+        SignedRadixCiphertext::from(result)
+    }
+
+    /// Homomorphically computes the opposite of a signed ciphertext.
+    ///
+    /// The negation wraps around: the opposite of the minimum value is itself.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 101i8;
+    ///
+    /// let mut ctxt = cks.encrypt_signed(msg);
+    ///
+    /// // Compute homomorphically a negation
+    /// let ct_res = sks.smart_signed_neg(&mut ctxt);
+    ///
+    /// // Decrypt
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(-msg, dec);
+    /// ```
+    pub fn smart_signed_neg(&self, ctxt: &mut SignedRadixCiphertext) -> SignedRadixCiphertext {
+        if has_non_zero_carries(ctxt) {
+            self.full_propagate(ctxt);
+        }
+        self.unchecked_signed_neg(ctxt)
+    }
+
+    /// Homomorphically computes the absolute value of a signed ciphertext.
+    ///
+    /// The absolute value wraps around: the absolute value of the minimum value is itself.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = -113i8;
+    ///
+    /// let ctxt = cks.encrypt_signed(msg);
+    ///
+    /// // Compute homomorphically an absolute value
+    /// let ct_res = sks.unchecked_signed_abs(&ctxt);
+    ///
+    /// // Decrypt
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(msg.abs(), dec);
+    /// ```
+    pub fn unchecked_signed_abs(&self, ctxt: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let sign = self.unchecked_sign_bit(ctxt);
+
+        // |x| = (x ^ -sign) + sign, the xor flips all the bits of negative values
+        let acc = self.key.generate_accumulator_bivariate(|x, sign| {
+            if sign == 1 {
+                (message_modulus - 1) ^ x
+            } else {
+                x
+            }
+        });
+        let mut blocks = ctxt
+            .blocks
+            .iter()
+            .map(|block| {
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(block, &sign, &acc)
+            })
+            .collect::<Vec<_>>();
+        self.key.unchecked_add_assign(&mut blocks[0], &sign);
+
+        let mut result = SignedRadixCiphertext::from(blocks);
+        self.full_propagate(&mut result);
+        result
+    }
+
+    /// Homomorphically computes the absolute value of a signed ciphertext.
+    ///
+    /// The absolute value wraps around: the absolute value of the minimum value is itself.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = -26i8;
+    ///
+    /// let mut ctxt = cks.encrypt_signed(msg);
+    ///
+    /// // Compute homomorphically an absolute value
+    /// let ct_res = sks.smart_signed_abs(&mut ctxt);
+    ///
+    /// // Decrypt
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(msg.abs(), dec);
+    /// ```
+    pub fn smart_signed_abs(&self, ctxt: &mut SignedRadixCiphertext) -> SignedRadixCiphertext {
+        if has_non_zero_carries(ctxt) {
+            self.full_propagate(ctxt);
+        }
+        self.unchecked_signed_abs(ctxt)
+    }
+
+    /// Computes homomorphically an arithmetic right shift.
+    ///
+    /// The bits shifted in on the left are copies of the sign bit, so the result is
+    /// the quotient of the division by `2^shift`, rounded towards negative infinity.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = -100i8;
+    /// let shift = 3;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// // Compute homomorphically an arithmetic right shift:
+    /// let ct_res = sks.unchecked_signed_scalar_right_shift(&ct, shift);
+    ///
+    /// // Decrypt:
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(msg >> shift, dec);
+    /// ```
+    pub fn unchecked_signed_scalar_right_shift(
+        &self,
+        ct: &SignedRadixCiphertext,
+        shift: usize,
+    ) -> SignedRadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        //number of bits of message
+        let nb_bits = (message_modulus as f64).log2() as usize;
+        let num_bits = nb_bits * ct.blocks.len();
+        let msb_block = ct.blocks.last().unwrap();
+
+        let mut result = if shift >= num_bits {
+            self.create_trivial_zero_radix(ct.blocks.len())
+        } else {
+            self.unchecked_scalar_right_shift(&RadixCiphertext::from(ct.blocks.clone()), shift)
+        };
+
+        // The logical shift filled the `shift` msb with zeros,
+        // they are replaced by copies of the sign bit
+        let padding_start = num_bits - shift.min(num_bits);
+        for (i, block) in result.blocks.iter_mut().enumerate() {
+            let block_start = i * nb_bits;
+            if block_start + nb_bits <= padding_start {
+                continue;
+            }
+            let first_padding_bit = padding_start.saturating_sub(block_start);
+            let padding = (message_modulus - 1) & !((1 << first_padding_bit) - 1);
+
+            let acc =
+                self.key.generate_accumulator(
+                    |x| {
+                        if x >= message_modulus / 2 {
+                            padding
+                        } else {
+                            0
+                        }
+                    },
+                );
+            let padding_block = self.key.apply_lookup_table(msb_block, &acc);
+            self.key.unchecked_add_assign(block, &padding_block);
+        }
+
+        SignedRadixCiphertext::from(result)
+    }
+
+    /// Computes homomorphically the wrapping addition of two signed ciphertexts,
+    /// and whether the addition overflowed.
+    ///
+    /// Returns the result and a block encrypting 1 if the addition overflowed, 0 otherwise.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 100i8;
+    /// let msg2 = 45i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let (ct_res, overflowed) = sks.unchecked_signed_overflowing_add(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// let overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(msg1.overflowing_add(msg2), (dec, overflowed == 1));
+    /// ```
+    pub fn unchecked_signed_overflowing_add(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, CiphertextBig) {
+        let mut result = RadixCiphertext::from(lhs.blocks.clone());
+        self.unchecked_add_assign(&mut result, &RadixCiphertext::from(rhs.blocks.clone()));
+        self.full_propagate(&mut result);
+        let result = SignedRadixCiphertext::from(result);
+
+        let operand_signs = self.unchecked_sign_bits_of_operands(lhs, rhs);
+        let result_sign = self.unchecked_sign_bit(&result);
+        let overflowed = self.unchecked_signed_overflow_flag(
+            operand_signs,
+            &result_sign,
+            SignedOverflowingOp::Add,
+        );
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically the wrapping subtraction of two signed ciphertexts,
+    /// and whether the subtraction overflowed.
+    ///
+    /// Returns the result and a block encrypting 1 if the subtraction overflowed, 0 otherwise.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = 45i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// // Compute homomorphically a subtraction:
+    /// let (ct_res, overflowed) = sks.unchecked_signed_overflowing_sub(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// let overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(msg1.overflowing_sub(msg2), (dec, overflowed == 1));
+    /// ```
+    pub fn unchecked_signed_overflowing_sub(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, CiphertextBig) {
+        let mut result = self.smart_sub(
+            &mut RadixCiphertext::from(lhs.blocks.clone()),
+            &mut RadixCiphertext::from(rhs.blocks.clone()),
+        );
+        self.full_propagate(&mut result);
+        let result = SignedRadixCiphertext::from(result);
+
+        let operand_signs = self.unchecked_sign_bits_of_operands(lhs, rhs);
+        let result_sign = self.unchecked_sign_bit(&result);
+        let overflowed = self.unchecked_signed_overflow_flag(
+            operand_signs,
+            &result_sign,
+            SignedOverflowingOp::Sub,
+        );
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically the wrapping addition of two signed ciphertexts,
+    /// and whether the addition overflowed.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_signed_overflowing_add(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, CiphertextBig) {
+        if has_non_zero_carries(lhs) {
+            self.full_propagate(lhs);
+        }
+        if has_non_zero_carries(rhs) {
+            self.full_propagate(rhs);
+        }
+        self.unchecked_signed_overflowing_add(lhs, rhs)
+    }
+
+    /// Computes homomorphically the wrapping subtraction of two signed ciphertexts,
+    /// and whether the subtraction overflowed.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_signed_overflowing_sub(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, CiphertextBig) {
+        if has_non_zero_carries(lhs) {
+            self.full_propagate(lhs);
+        }
+        if has_non_zero_carries(rhs) {
+            self.full_propagate(rhs);
+        }
+        self.unchecked_signed_overflowing_sub(lhs, rhs)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    /// Number of blocks used for the remainder of the square root computation.
+    ///
+    /// Bringing down two bits makes the remainder up to 2 bits wider than the root.
+    pub(crate) fn sqrt_work_blocks(&self, num_blocks: usize) -> usize {
+        let nb_bits = self.message_bits_per_block() as usize;
+        num_blocks + (2 + nb_bits - 1) / nb_bits
+    }
+
+    /// Returns the two input bits brought down at the given step, packed in a single block.
+    pub(crate) fn sqrt_next_bits(&self, bits: &[CiphertextBig], step: usize) -> CiphertextBig {
+        let mut next_bits = bits[2 * step].clone();
+        if let Some(high_bit) = bits.get(2 * step + 1) {
+            let high_bit = self.key.unchecked_scalar_mul(high_bit, 2);
+            self.key.unchecked_add_assign(&mut next_bits, &high_bit);
+        }
+        next_bits
+    }
+
+    /// Packs bits (from the least significant one) in a radix ciphertext of `num_blocks`,
+    /// the missing high bits being trivial zeros.
+    pub(crate) fn bits_to_padded_radix(
+        &self,
+        bits: &[CiphertextBig],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let nb_bits = self.message_bits_per_block() as usize;
+        let mut bits = bits.to_vec();
+        bits.resize_with(num_blocks * nb_bits, || self.key.create_trivial(0));
+        self.unchecked_bits_to_radix(&bits)
+    }
+
+    /// Computes homomorphically the square root of the ciphertext, rounded down.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_sqrt(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14);
+    /// ```
+    pub fn unchecked_sqrt(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let work_blocks = self.sqrt_work_blocks(num_blocks);
+        let bits = self.unchecked_radix_to_bits(ct);
+        let num_steps = (bits.len() + 1) / 2;
+
+        // Digit by digit algorithm, in base 2:
+        // the root is built one bit at a time, from the most significant one
+        let mut root_bits = Vec::<CiphertextBig>::with_capacity(num_steps);
+        let mut remainder = self.create_trivial_zero_radix(work_blocks);
+        for step in (0..num_steps).rev() {
+            // remainder = 4 * remainder + next two bits of the input
+            let mut shifted = self.unchecked_scalar_left_shift(&remainder, 2);
+            let next_bits = self.sqrt_next_bits(&bits, step);
+            let mut next_bits = self.block_to_radix(next_bits, work_blocks);
+            remainder = self.smart_add(&mut shifted, &mut next_bits);
+            self.full_propagate(&mut remainder);
+
+            // trial = 4 * root + 1
+            let mut trial_bits = vec![self.key.create_trivial(1), self.key.create_trivial(0)];
+            trial_bits.extend(root_bits.iter().cloned());
+            let mut trial = self.bits_to_padded_radix(&trial_bits, work_blocks);
+
+            let is_root_bit_set = self.unchecked_ge_as_block(&remainder, &trial);
+            let mut difference = self.smart_sub(&mut remainder.clone(), &mut trial);
+            self.full_propagate(&mut difference);
+            remainder = self.unchecked_if_then_else(&is_root_bit_set, &difference, &remainder);
+
+            root_bits.insert(0, is_root_bit_set);
+        }
+
+        self.bits_to_padded_radix(&root_bits, num_blocks)
+    }
+
+    /// Computes homomorphically the square root of the ciphertext, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_sqrt(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14);
+    /// ```
+    pub fn smart_sqrt(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_sqrt(ct)
+    }
+}
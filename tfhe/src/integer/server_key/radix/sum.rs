@@ -0,0 +1,238 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    /// Splits the terms in consecutive groups, such that each group can be summed
+    /// with unchecked additions without exceeding the capacity of any block.
+    pub(crate) fn group_terms_for_sum(
+        &self,
+        terms: Vec<RadixCiphertext>,
+    ) -> Vec<Vec<RadixCiphertext>> {
+        let max_degree = self.key.max_degree.0;
+
+        let mut groups = Vec::<Vec<RadixCiphertext>>::new();
+        let mut group_degrees = Vec::<usize>::new();
+        for term in terms {
+            let fits_in_group = !groups.is_empty()
+                && group_degrees
+                    .iter()
+                    .zip(term.blocks.iter())
+                    .all(|(degree, block)| degree + block.degree.0 <= max_degree);
+
+            if fits_in_group {
+                for (degree, block) in group_degrees.iter_mut().zip(term.blocks.iter()) {
+                    *degree += block.degree.0;
+                }
+                groups.last_mut().unwrap().push(term);
+            } else {
+                group_degrees = term.blocks.iter().map(|block| block.degree.0).collect();
+                groups.push(vec![term]);
+            }
+        }
+        groups
+    }
+
+    /// Builds the terms of the carry-save reduction of a ciphertext, from the message and the
+    /// carry of each block.
+    ///
+    /// The carries are shifted by one block, the carry of the last block is dropped.
+    /// Returns `None` for the carries if no block can hold a carry.
+    pub(crate) fn carry_save_terms(
+        &self,
+        ct: &RadixCiphertext,
+        messages: Vec<CiphertextBig>,
+        carries: Vec<Option<CiphertextBig>>,
+    ) -> (RadixCiphertext, Option<RadixCiphertext>) {
+        let message = RadixCiphertext::from(messages);
+        if carries.iter().all(Option::is_none) {
+            return (message, None);
+        }
+
+        let mut carry_blocks = Vec::with_capacity(ct.blocks.len());
+        carry_blocks.push(self.key.create_trivial(0));
+        carry_blocks.extend(
+            carries
+                .into_iter()
+                .take(ct.blocks.len() - 1)
+                .map(|carry| carry.unwrap_or_else(|| self.key.create_trivial(0))),
+        );
+        (message, Some(RadixCiphertext::from(carry_blocks)))
+    }
+
+    /// Returns the terms whose sum is `ct * weight`, `ct` being propagated.
+    ///
+    /// Each term is `ct` multiplied by one digit of the weight and shifted by the digit
+    /// position, so that no PBS is needed.
+    pub(crate) fn weighted_terms(&self, ct: &RadixCiphertext, weight: u64) -> Vec<RadixCiphertext> {
+        let b = self.key.message_modulus.0 as u64;
+
+        let mut terms = Vec::new();
+        let mut weight = weight;
+        for i in 0..ct.blocks.len() {
+            if weight == 0 {
+                break;
+            }
+            let u_i = weight % b;
+            weight /= b;
+
+            if u_i == 0 {
+                continue;
+            } else if self.is_small_scalar_mul_possible(ct, u_i) {
+                let tmp = self.unchecked_small_scalar_mul(ct, u_i);
+                terms.push(self.blockshift(&tmp, i));
+            } else {
+                let tmp = self.blockshift(ct, i);
+                terms.extend(std::iter::repeat(tmp).take(u_i as usize));
+            }
+        }
+        terms
+    }
+
+    /// Computes homomorphically the sum of all the ciphertexts.
+    ///
+    /// The ciphertexts are added with unchecked additions as long as the carry space allows,
+    /// and when it is full, each partial sum is reduced by splitting the message and the carry
+    /// of its blocks. The carries are only propagated once, at the end.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [14u64, 97, 201, 3, 56];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.sum_ciphertexts(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msgs.iter().sum::<u64>() % 256);
+    /// ```
+    pub fn sum_ciphertexts(&self, ciphertexts: &[RadixCiphertext]) -> Option<RadixCiphertext> {
+        let num_blocks = ciphertexts.first()?.blocks.len();
+        assert!(
+            ciphertexts.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "all the ciphertexts must have the same number of blocks"
+        );
+
+        let mut terms = ciphertexts.to_vec();
+        let mut result = loop {
+            let mut sums = self
+                .group_terms_for_sum(terms)
+                .into_iter()
+                .map(|group| {
+                    let mut group = group.into_iter();
+                    let mut sum = group.next().unwrap();
+                    for term in group {
+                        self.unchecked_add_assign(&mut sum, &term);
+                    }
+                    sum
+                })
+                .collect::<Vec<_>>();
+
+            if sums.len() == 1 {
+                break sums.pop().unwrap();
+            }
+
+            terms = Vec::with_capacity(2 * sums.len());
+            for sum in sums {
+                let (message, carries) = self.carry_save_reduce(&sum);
+                terms.push(message);
+                terms.extend(carries);
+            }
+        };
+
+        self.full_propagate(&mut result);
+        Some(result)
+    }
+
+    /// Splits each block of the ciphertext in its message and its carry.
+    fn carry_save_reduce(
+        &self,
+        ct: &RadixCiphertext,
+    ) -> (RadixCiphertext, Option<RadixCiphertext>) {
+        let (messages, carries) = ct
+            .blocks
+            .iter()
+            .map(|block| {
+                if block.degree.0 < self.key.message_modulus.0 {
+                    (block.clone(), None)
+                } else {
+                    (
+                        self.key.message_extract(block),
+                        Some(self.key.carry_extract(block)),
+                    )
+                }
+            })
+            .unzip();
+        self.carry_save_terms(ct, messages, carries)
+    }
+
+    /// Computes homomorphically the sum of the ciphertexts, each multiplied by its clear weight.
+    ///
+    /// The result wraps around the number of blocks of the ciphertexts.
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [14u64, 97, 201];
+    /// let weights = [3u64, 0, 17];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.dot_product_with_clear(&cts, &weights).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// let expected: u64 = msgs.iter().zip(weights.iter()).map(|(m, w)| m * w).sum();
+    /// assert_eq!(dec, expected % 256);
+    /// ```
+    pub fn dot_product_with_clear(
+        &self,
+        ciphertexts: &[RadixCiphertext],
+        weights: &[u64],
+    ) -> Option<RadixCiphertext> {
+        assert_eq!(
+            ciphertexts.len(),
+            weights.len(),
+            "there must be as many weights as ciphertexts"
+        );
+        let num_blocks = ciphertexts.first()?.blocks.len();
+
+        let mut terms = Vec::new();
+        for (ct, &weight) in ciphertexts.iter().zip(weights.iter()) {
+            if has_non_zero_carries(ct) {
+                let mut ct = ct.clone();
+                self.full_propagate(&mut ct);
+                terms.extend(self.weighted_terms(&ct, weight));
+            } else {
+                terms.extend(self.weighted_terms(ct, weight));
+            }
+        }
+
+        if terms.is_empty() {
+            return Some(self.create_trivial_zero_radix(num_blocks));
+        }
+        self.sum_ciphertexts(&terms)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::integer::keycache::KEY_CACHE;
+use crate::integer::U256;
 use crate::shortint::parameters::*;
 use crate::shortint::Parameters;
 use rand::Rng;
@@ -10,6 +11,9 @@ const NB_TEST: usize = 30;
 /// meant for test where the function tested is more expensive
 const NB_TEST_SMALLER: usize = 10;
 const NB_CTXT: usize = 4;
+/// Number of loop iteration within the univariate function tests,
+/// which need in the order of message_modulus^(NB_CTXT - 2) PBS per block
+const NB_TEST_UNIVARIATE: usize = 2;
 
 create_parametrized_test!(integer_encrypt_decrypt);
 create_parametrized_test!(integer_encrypt_decrypt_128_bits);
@@ -32,6 +36,34 @@ create_parametrized_test!(integer_blockshift_right);
 create_parametrized_test!(integer_smart_scalar_mul);
 create_parametrized_test!(integer_unchecked_scalar_left_shift);
 create_parametrized_test!(integer_unchecked_scalar_right_shift);
+create_parametrized_test!(integer_unchecked_scalar_rotate {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_shift {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_rotate {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_if_then_else {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_overflowing_add_sub {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_overflowing_mul {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_saturating_add_sub {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 create_parametrized_test!(integer_unchecked_negation);
 create_parametrized_test!(integer_smart_neg);
 create_parametrized_test!(integer_unchecked_sub);
@@ -39,6 +71,115 @@ create_parametrized_test!(integer_smart_sub);
 create_parametrized_test!(integer_unchecked_block_mul);
 create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_smart_mul);
+create_parametrized_test!(integer_unchecked_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_scalar_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_scalar_div_exact {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_scalar_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+
+create_parametrized_test!(integer_encrypt_decrypt_signed {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_signed_neg_abs {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_signed_scalar_right_shift {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_signed_overflowing_add_sub {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_signed_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_comparisons_as_block {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_signed_comparisons_as_block {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_bit_count {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_bit_count {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_scalar_pow {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_pow {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_sqrt {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_resize {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_resize {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_sum_dot_product {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_scalar_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_scalar_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_apply_univariate_function {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_apply_univariate_function {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_apply_lookup_table_radix {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_apply_lookup_table_radix {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_oblivious_read_write {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
@@ -762,7 +903,14 @@ fn integer_unchecked_scalar_right_shift(param: Parameters) {
     }
 }
 
-fn integer_unchecked_negation(param: Parameters) {
+/// Rotates the `nb_bits` lsb of `value` to the left
+fn clear_rotate_left(value: u64, n: usize, nb_bits: usize) -> u64 {
+    let n = n % nb_bits;
+    let mask = (1u64 << nb_bits) - 1;
+    ((value << n) | (value >> (nb_bits - n))) & mask
+}
+
+fn integer_unchecked_scalar_rotate(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
@@ -771,30 +919,70 @@ fn integer_unchecked_negation(param: Parameters) {
     // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
+    let nb_bits = (param.message_modulus.0 as f64).log2() as usize * NB_CTXT;
+
     for _ in 0..NB_TEST {
-        // Define the cleartexts
         let clear = rng.gen::<u64>() % modulus;
 
-        // println!("clear = {}", clear);
+        let scalar = rng.gen::<usize>() % (2 * nb_bits);
 
-        // Encrypt the integers
-        let ctxt = cks.encrypt_radix(clear, NB_CTXT);
+        // encryption of an integer
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
 
-        // Negates the ctxt
-        let ct_tmp = sks.unchecked_neg(&ctxt);
+        let ct_res = sks.unchecked_scalar_rotate_left(&ct, scalar);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_rotate_left(clear, scalar, nb_bits), dec_res);
 
-        // Decrypt the result
-        let dec = cks.decrypt_radix(&ct_tmp);
+        let ct_res = sks.unchecked_scalar_rotate_right(&ct, scalar);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            clear_rotate_left(clear, nb_bits - scalar % nb_bits, nb_bits),
+            dec_res
+        );
+    }
+}
 
-        // Check the correctness
-        let clear_result = clear.wrapping_neg() % modulus;
+fn integer_unchecked_shift(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
 
-        //println!("clear = {}", clear);
-        assert_eq!(clear_result, dec);
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let nb_bits = (param.message_modulus.0 as f64).log2() as usize * NB_CTXT;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Amounts greater than the number of bits are also tested
+        let shift = rng.gen::<u64>() % (2 * nb_bits as u64);
+
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+        let ct_shift = cks.encrypt_radix(shift, NB_CTXT);
+
+        let ct_res = sks.unchecked_left_shift(&ct, &ct_shift);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        let expected = if shift < nb_bits as u64 {
+            (clear << shift) % modulus
+        } else {
+            0
+        };
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.unchecked_right_shift(&ct, &ct_shift);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        let expected = if shift < nb_bits as u64 {
+            clear >> shift
+        } else {
+            0
+        };
+        assert_eq!(expected, dec_res);
     }
 }
 
-fn integer_smart_neg(param: Parameters) {
+fn integer_unchecked_rotate(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
@@ -803,57 +991,132 @@ fn integer_smart_neg(param: Parameters) {
     // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
-    for _ in 0..NB_TEST {
-        // Define the cleartexts
+    let nb_bits = (param.message_modulus.0 as f64).log2() as usize * NB_CTXT;
+
+    for _ in 0..NB_TEST_SMALLER {
         let clear = rng.gen::<u64>() % modulus;
+        let n = rng.gen::<u64>() % modulus;
 
-        // Encrypt the integers
-        let mut ctxt = cks.encrypt_radix(clear, NB_CTXT);
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+        let ct_n = cks.encrypt_radix(n, NB_CTXT);
 
-        // Negates the ctxt
-        let ct_tmp = sks.smart_neg(&mut ctxt);
+        let ct_res = sks.unchecked_rotate_left(&ct, &ct_n);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_rotate_left(clear, n as usize, nb_bits), dec_res);
 
-        // Decrypt the result
-        let dec = cks.decrypt_radix(&ct_tmp);
+        let ct_res = sks.unchecked_rotate_right(&ct, &ct_n);
+        let dec_res = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            clear_rotate_left(clear, nb_bits - n as usize % nb_bits, nb_bits),
+            dec_res
+        );
+    }
+}
 
-        // Check the correctness
-        let clear_result = clear.wrapping_neg() % modulus;
+fn integer_smart_if_then_else(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
 
-        assert_eq!(clear_result, dec);
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let mut ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+        let ct_2 = cks.encrypt_radix(clear_2, NB_CTXT);
+
+        let condition = sks.unchecked_gt(&ct_0, &ct_1);
+
+        let ct_res = sks.unchecked_if_then_else(&condition.blocks[0], &ct_0, &ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec_res);
+
+        // Fill the carries so that the smart version has to propagate them
+        sks.unchecked_add_assign(&mut ct_0, &ct_2);
+        let ct_res = sks.smart_if_then_else(&condition.blocks[0], &mut ct_0, &mut ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        let expected = if clear_0 > clear_1 {
+            (clear_0 + clear_2) % modulus
+        } else {
+            clear_1
+        };
+        assert_eq!(expected, dec_res);
     }
 }
 
-fn integer_unchecked_sub(param: Parameters) {
+fn integer_unchecked_overflowing_add_sub(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
-    // RNG
+    //RNG
     let mut rng = rand::thread_rng();
 
     // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
-    for _ in 0..NB_TEST {
-        // Define the cleartexts
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
-        // Encrypt the integers
-        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
-        let ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+        let ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
 
-        // Add the ciphertext 1 and 2
-        let ct_tmp = sks.unchecked_sub(&ctxt_1, &ctxt_2);
+        let (ct_res, overflowed) = sks.unchecked_overflowing_add(&ct_0, &ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+        assert_eq!(
+            u64::from(clear_0 + clear_1 >= modulus),
+            cks.decrypt_one_block(&overflowed)
+        );
 
-        // Decrypt the result
-        let dec = cks.decrypt_radix(&ct_tmp);
+        let (ct_res, overflowed) = sks.unchecked_overflowing_sub(&ct_0, &ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.wrapping_sub(clear_1) % modulus, dec_res);
+        assert_eq!(
+            u64::from(clear_0 < clear_1),
+            cks.decrypt_one_block(&overflowed)
+        );
+    }
+}
 
-        // Check the correctness
-        let clear_result = (clear1 - clear2) % modulus;
-        assert_eq!(clear_result, dec);
+fn integer_smart_overflowing_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Small values do not overflow, so both cases are tested
+        let clear_1 =
+            rng.gen::<u64>() % modulus >> rng.gen_range(0..(modulus as f64).log2() as u32);
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let mut ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        let (ct_res, overflowed) = sks.smart_overflowing_mul(&mut ct_0, &mut ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+        assert_eq!(
+            u64::from(clear_0 * clear_1 >= modulus),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let ct_res = sks.smart_saturating_mul(&mut ct_0, &mut ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 * clear_1).min(modulus - 1), dec_res);
     }
 }
 
-fn integer_smart_sub(param: Parameters) {
+fn integer_smart_saturating_add_sub(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
@@ -863,31 +1126,29 @@ fn integer_smart_sub(param: Parameters) {
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
     for _ in 0..NB_TEST_SMALLER {
-        // Define the cleartexts
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
 
-        // Encrypt the integers
-        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
-        let mut ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let mut ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+        let ct_2 = cks.encrypt_radix(clear_2, NB_CTXT);
 
-        let mut res = ctxt_1.clone();
-        let mut clear = clear1;
+        // Fill the carries so that the inputs have to be propagated
+        sks.unchecked_add_assign(&mut ct_0, &ct_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
 
-        //subtract multiple times to raise the degree
-        for _ in 0..NB_TEST_SMALLER {
-            res = sks.smart_sub(&mut res, &mut ctxt_2);
-            clear = (clear - clear2) % modulus;
-            // println!("clear = {}, clear2 = {}", clear, cks.decrypt(&res));
-        }
-        let dec = cks.decrypt_radix(&res);
+        let ct_res = sks.smart_saturating_add(&mut ct_0, &mut ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 + clear_1).min(modulus - 1), dec_res);
 
-        // Check the correctness
-        assert_eq!(clear, dec);
+        let ct_res = sks.smart_saturating_sub(&mut ct_0, &mut ct_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.saturating_sub(clear_1), dec_res);
     }
 }
 
-fn integer_unchecked_block_mul(param: Parameters) {
+fn integer_unchecked_scalar_comparisons(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
@@ -896,31 +1157,68 @@ fn integer_unchecked_block_mul(param: Parameters) {
     // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
-    let block_modulus = param.message_modulus.0 as u64;
-
-    for _ in 0..NB_TEST {
+    for i in 0..NB_TEST_SMALLER {
         let clear_0 = rng.gen::<u64>() % modulus;
+        // Some scalars do not fit in the ciphertext, others are equal to the ciphertext
+        let clear_1 = match i % 3 {
+            0 => modulus + rng.gen::<u64>() % modulus,
+            1 => clear_0,
+            _ => rng.gen::<u64>() % modulus,
+        };
 
-        let clear_1 = rng.gen::<u64>() % block_modulus;
+        let ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
 
-        // encryption of an integer
-        let ct_zero = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_res = sks.unchecked_scalar_eq(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 == clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
 
-        // encryption of an integer
-        let ct_one = cks.encrypt_one_block(clear_1);
+        let ct_res = sks.unchecked_scalar_ne(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 != clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_block_mul(&ct_zero, &ct_one, 0);
+        let ct_res = sks.unchecked_scalar_gt(&ct_0, clear_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&ct_res));
 
-        // decryption of ct_res
-        let dec_res = cks.decrypt_radix(&ct_res);
+        let ct_res = sks.unchecked_scalar_ge(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 >= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
 
-        // assert
-        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+        let ct_res = sks.unchecked_scalar_lt(&ct_0, clear_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.unchecked_scalar_le(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 <= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.unchecked_scalar_max(&ct_0, clear_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.max(clear_1).min(modulus - 1), dec_res);
+
+        let ct_res = sks.unchecked_scalar_min(&ct_0, clear_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec_res);
+
+        // Scalars wider than a single word
+        let ct_res = sks.unchecked_scalar_lt(&ct_0, U256::from((0, 1)));
+        assert_eq!(1, cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.unchecked_scalar_eq(&ct_0, U256::from(clear_1));
+        assert_eq!(
+            u64::from(clear_0 == clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
     }
 }
 
-fn integer_smart_block_mul(param: Parameters) {
+fn integer_smart_scalar_comparisons(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
@@ -929,68 +1227,1148 @@ fn integer_smart_block_mul(param: Parameters) {
     // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
 
-    let block_modulus = param.message_modulus.0 as u64;
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
 
-    for _ in 0..5 {
-        // Define the cleartexts
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % block_modulus;
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_2 = cks.encrypt_radix(clear_2, NB_CTXT);
 
-        // Encrypt the integers
-        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
-        let ctxt_2 = cks.encrypt_one_block(clear2);
+        // Fill the carries so that the input has to be propagated
+        sks.unchecked_add_assign(&mut ct_0, &ct_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
 
-        let mut res = ctxt_1.clone();
-        let mut clear = clear1;
+        let ct_res = sks.smart_scalar_gt(&mut ct_0, clear_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&ct_res));
 
-        res = sks.smart_block_mul(&mut res, &ctxt_2, 0);
-        for _ in 0..5 {
-            res = sks.smart_block_mul(&mut res, &ctxt_2, 0);
-            clear = (clear * clear2) % modulus;
-        }
-        let dec = cks.decrypt_radix(&res);
+        let ct_res = sks.smart_scalar_le(&mut ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 <= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
 
-        clear = (clear * clear2) % modulus;
+        let ct_res = sks.smart_scalar_max(&mut ct_0, clear_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec_res);
+    }
+}
 
-        // Check the correctness
-        assert_eq!(clear, dec);
+fn integer_unchecked_apply_univariate_function(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // With NB_CTXT blocks, the selection tree has more than one level
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let f = |x: u64| (x * x + 3 * x + 1) % modulus;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_apply_univariate_function(&ct, f);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(f(clear), dec_res);
     }
 }
 
-fn integer_smart_mul(param: Parameters) {
+fn integer_smart_apply_univariate_function(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
     //RNG
     let mut rng = rand::thread_rng();
 
-    // message_modulus^vec_length
     let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let f = |x: u64| x / 3;
 
-    for _ in 0..NB_TEST_SMALLER {
-        // Define the cleartexts
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
-        // println!("clear1 = {}, clear2 = {}", clear1, clear2);
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
 
-        // Encrypt the integers
-        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
-        let mut ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+        // Fill the carries so that the smart version has to propagate them
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
 
-        let mut res = ctxt_1.clone();
-        let mut clear = clear1;
+        let ct_res = sks.smart_apply_univariate_function(&mut ct_0, f);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(f(clear), dec_res);
+    }
+}
 
-        res = sks.smart_mul(&mut res, &mut ctxt_2);
-        for _ in 0..5 {
-            res = sks.smart_mul(&mut res, &mut ctxt_2);
-            clear = (clear * clear2) % modulus;
-        }
-        let dec = cks.decrypt_radix(&res);
+fn integer_unchecked_apply_lookup_table_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
 
-        clear = (clear * clear2) % modulus;
+    //RNG
+    let mut rng = rand::thread_rng();
 
-        // Check the correctness
-        assert_eq!(clear, dec);
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let table = (0..modulus)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_apply_lookup_table_radix(&ct, &table);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(table[clear as usize], dec_res);
+    }
+}
+
+fn integer_smart_apply_lookup_table_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        // The table only covers half of the inputs, the other half must map to 0
+        let table = (0..modulus / 2)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Fill the carries so that the smart version has to propagate them
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_apply_lookup_table_radix(&mut ct_0, &table);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        let expected = table.get(clear as usize).copied().unwrap_or(0);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_oblivious_read_write(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // The index is on a single block, the table only covers part of its range
+    let index_modulus = param.message_modulus.0 as u64;
+    let table_len = (index_modulus - 1) as usize;
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear_table = (0..table_len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut table = clear_table
+            .iter()
+            .map(|&value| cks.encrypt_radix(value, NB_CTXT))
+            .collect::<Vec<_>>();
+
+        let clear_index = rng.gen::<u64>() % index_modulus;
+        let clear_value = rng.gen::<u64>() % modulus;
+        let mut index = cks.encrypt_radix(clear_index, 1);
+        let mut value = cks.encrypt_radix(clear_value, NB_CTXT);
+
+        let expected = clear_table.get(clear_index as usize).copied().unwrap_or(0);
+
+        let ct_res = sks.smart_oblivious_read(&mut table, &mut index);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.smart_match_value(&mut index, &clear_table, NB_CTXT);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        sks.smart_oblivious_write(&mut table, &mut index, &mut value);
+        if let Some(entry) = clear_table.get_mut(clear_index as usize) {
+            *entry = clear_value;
+        }
+        let dec_table = table
+            .iter()
+            .map(|ct| cks.decrypt_radix(ct))
+            .collect::<Vec<u64>>();
+        assert_eq!(clear_table, dec_table);
+    }
+}
+
+fn integer_unchecked_negation(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST {
+        // Define the cleartexts
+        let clear = rng.gen::<u64>() % modulus;
+
+        // println!("clear = {}", clear);
+
+        // Encrypt the integers
+        let ctxt = cks.encrypt_radix(clear, NB_CTXT);
+
+        // Negates the ctxt
+        let ct_tmp = sks.unchecked_neg(&ctxt);
+
+        // Decrypt the result
+        let dec = cks.decrypt_radix(&ct_tmp);
+
+        // Check the correctness
+        let clear_result = clear.wrapping_neg() % modulus;
+
+        //println!("clear = {}", clear);
+        assert_eq!(clear_result, dec);
+    }
+}
+
+fn integer_smart_neg(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST {
+        // Define the cleartexts
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Encrypt the integers
+        let mut ctxt = cks.encrypt_radix(clear, NB_CTXT);
+
+        // Negates the ctxt
+        let ct_tmp = sks.smart_neg(&mut ctxt);
+
+        // Decrypt the result
+        let dec = cks.decrypt_radix(&ct_tmp);
+
+        // Check the correctness
+        let clear_result = clear.wrapping_neg() % modulus;
+
+        assert_eq!(clear_result, dec);
+    }
+}
+
+fn integer_unchecked_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST {
+        // Define the cleartexts
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
+        // Encrypt the integers
+        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
+        let ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+
+        // Add the ciphertext 1 and 2
+        let ct_tmp = sks.unchecked_sub(&ctxt_1, &ctxt_2);
+
+        // Decrypt the result
+        let dec = cks.decrypt_radix(&ct_tmp);
+
+        // Check the correctness
+        let clear_result = (clear1 - clear2) % modulus;
+        assert_eq!(clear_result, dec);
+    }
+}
+
+fn integer_smart_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Define the cleartexts
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
+        // Encrypt the integers
+        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
+        let mut ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+
+        let mut res = ctxt_1.clone();
+        let mut clear = clear1;
+
+        //subtract multiple times to raise the degree
+        for _ in 0..NB_TEST_SMALLER {
+            res = sks.smart_sub(&mut res, &mut ctxt_2);
+            clear = (clear - clear2) % modulus;
+            // println!("clear = {}, clear2 = {}", clear, cks.decrypt(&res));
+        }
+        let dec = cks.decrypt_radix(&res);
+
+        // Check the correctness
+        assert_eq!(clear, dec);
+    }
+}
+
+fn integer_unchecked_block_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let block_modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % block_modulus;
+
+        // encryption of an integer
+        let ct_zero = cks.encrypt_radix(clear_0, NB_CTXT);
+
+        // encryption of an integer
+        let ct_one = cks.encrypt_one_block(clear_1);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_block_mul(&ct_zero, &ct_one, 0);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt_radix(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+    }
+}
+
+fn integer_smart_block_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let block_modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..5 {
+        // Define the cleartexts
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % block_modulus;
+
+        // Encrypt the integers
+        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
+        let ctxt_2 = cks.encrypt_one_block(clear2);
+
+        let mut res = ctxt_1.clone();
+        let mut clear = clear1;
+
+        res = sks.smart_block_mul(&mut res, &ctxt_2, 0);
+        for _ in 0..5 {
+            res = sks.smart_block_mul(&mut res, &ctxt_2, 0);
+            clear = (clear * clear2) % modulus;
+        }
+        let dec = cks.decrypt_radix(&res);
+
+        clear = (clear * clear2) % modulus;
+
+        // Check the correctness
+        assert_eq!(clear, dec);
+    }
+}
+
+fn integer_smart_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Define the cleartexts
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
+        // println!("clear1 = {}, clear2 = {}", clear1, clear2);
+
+        // Encrypt the integers
+        let ctxt_1 = cks.encrypt_radix(clear1, NB_CTXT);
+        let mut ctxt_2 = cks.encrypt_radix(clear2, NB_CTXT);
+
+        let mut res = ctxt_1.clone();
+        let mut clear = clear1;
+
+        res = sks.smart_mul(&mut res, &mut ctxt_2);
+        for _ in 0..5 {
+            res = sks.smart_mul(&mut res, &mut ctxt_2);
+            clear = (clear * clear2) % modulus;
+        }
+        let dec = cks.decrypt_radix(&res);
+
+        clear = (clear * clear2) % modulus;
+
+        // Check the correctness
+        assert_eq!(clear, dec);
+    }
+}
+
+fn integer_unchecked_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        let (q, r) = sks.unchecked_div_rem(&ctxt_0, &ctxt_1);
+        let dec_q: u64 = cks.decrypt_radix(&q);
+        let dec_r: u64 = cks.decrypt_radix(&r);
+
+        if clear_1 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_1, dec_q);
+            assert_eq!(clear_0 % clear_1, dec_r);
+        }
+    }
+
+    // Division by zero
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+    let ctxt_1 = cks.encrypt_radix(0u64, NB_CTXT);
+
+    let (q, r) = sks.unchecked_div_rem(&ctxt_0, &ctxt_1);
+    let dec_q: u64 = cks.decrypt_radix(&q);
+    let dec_r: u64 = cks.decrypt_radix(&r);
+
+    assert_eq!(modulus - 1, dec_q);
+    assert_eq!(clear_0, dec_r);
+}
+
+fn integer_smart_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+        let mut ctxt_2 = cks.encrypt_radix(clear_2, NB_CTXT);
+
+        // add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let (q, r) = sks.smart_div_rem(&mut ctxt_0, &mut ctxt_2);
+        let dec_q: u64 = cks.decrypt_radix(&q);
+        let dec_r: u64 = cks.decrypt_radix(&r);
+
+        if clear_2 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_2, dec_q);
+            assert_eq!(clear_0 % clear_2, dec_r);
+        }
+    }
+}
+
+fn integer_unchecked_scalar_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Random divisors, powers of two, zero and divisors larger than the modulus
+    let mut divisors = (0..NB_TEST_SMALLER)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    divisors.extend([0, 1, 2, 8, modulus / 2, modulus - 1, modulus, modulus + 3]);
+
+    for clear_1 in divisors {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+
+        let (q, r) = sks.unchecked_scalar_div_rem(&ctxt_0, clear_1);
+        let dec_q: u64 = cks.decrypt_radix(&q);
+        let dec_r: u64 = cks.decrypt_radix(&r);
+
+        if clear_1 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_1, dec_q);
+            assert_eq!(clear_0 % clear_1, dec_r);
+        }
+
+        let q = sks.unchecked_scalar_div(&ctxt_0, clear_1);
+        let dec_q: u64 = cks.decrypt_radix(&q);
+        let r = sks.unchecked_scalar_rem(&ctxt_0, clear_1);
+        let dec_r: u64 = cks.decrypt_radix(&r);
+
+        if clear_1 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_1, dec_q);
+            assert_eq!(clear_0 % clear_1, dec_r);
+        }
+    }
+}
+
+fn integer_unchecked_scalar_div_exact(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+        let clear_0 = (rng.gen::<u64>() % (modulus / clear_1)) * clear_1;
+
+        let ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+
+        let ct_res = sks.unchecked_scalar_div_exact(&ctxt_0, clear_1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+
+        assert_eq!(clear_0 / clear_1, dec_res);
+    }
+}
+
+fn integer_smart_scalar_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+
+        let mut ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let (q, r) = sks.smart_scalar_div_rem(&mut ctxt_0, clear_2);
+        let dec_q: u64 = cks.decrypt_radix(&q);
+        let dec_r: u64 = cks.decrypt_radix(&r);
+
+        assert_eq!(clear_0 / clear_2, dec_q);
+        assert_eq!(clear_0 % clear_2, dec_r);
+    }
+}
+
+/// Sign-extends the `num_bits` lsb of a value, as the decryption of a signed ciphertext
+fn signed_wrap(value: i64, num_bits: u32) -> i64 {
+    let unused_bits = 64 - num_bits;
+    (value << unused_bits) >> unused_bits
+}
+
+/// Returns a random value that fits in a signed ciphertext of `num_bits`
+fn random_signed_value<R: Rng>(rng: &mut R, num_bits: u32) -> i64 {
+    signed_wrap(rng.gen::<i64>(), num_bits)
+}
+
+fn integer_encrypt_decrypt_signed(param: Parameters) {
+    let (cks, _) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST {
+        let clear = random_signed_value(&mut rng, num_bits);
+
+        //encryption
+        let ct = cks.encrypt_signed_radix(clear, NB_CTXT);
+
+        // decryption
+        let dec: i64 = cks.decrypt_signed_radix(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+
+    // Values are sign extended to and from the 128 bits of an i128
+    let num_block = (136f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+    for clear in [i128::MIN, -1, i128::MAX] {
+        let ct = cks.encrypt_signed_radix(clear, num_block);
+        let dec: i128 = cks.decrypt_signed_radix(&ct);
+        assert_eq!(clear, dec);
+    }
+    let ct = cks.encrypt_signed_radix(-3i8, num_block);
+    let dec: i128 = cks.decrypt_signed_radix(&ct);
+    assert_eq!(-3, dec);
+}
+
+fn integer_unchecked_signed_neg_abs(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = random_signed_value(&mut rng, num_bits);
+
+        let ctxt = cks.encrypt_signed_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_signed_neg(&ctxt);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(signed_wrap(-clear, num_bits), dec);
+
+        let ct_res = sks.unchecked_signed_abs(&ctxt);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(signed_wrap(clear.abs(), num_bits), dec);
+    }
+}
+
+fn integer_unchecked_signed_scalar_right_shift(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = random_signed_value(&mut rng, num_bits);
+        let shift = rng.gen_range(0..=num_bits);
+
+        let ctxt = cks.encrypt_signed_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_signed_scalar_right_shift(&ctxt, shift as usize);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(clear >> shift, dec);
+    }
+}
+
+fn integer_smart_signed_overflowing_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = random_signed_value(&mut rng, num_bits);
+        let clear_1 = random_signed_value(&mut rng, num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let mut ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let (ct_res, overflowed) = sks.smart_signed_overflowing_add(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        let expected = clear_0 + clear_1;
+        assert_eq!(signed_wrap(expected, num_bits), dec);
+        assert_eq!(
+            u64::from(signed_wrap(expected, num_bits) != expected),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let (ct_res, overflowed) = sks.smart_signed_overflowing_sub(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        let expected = clear_0 - clear_1;
+        assert_eq!(signed_wrap(expected, num_bits), dec);
+        assert_eq!(
+            u64::from(signed_wrap(expected, num_bits) != expected),
+            cks.decrypt_one_block(&overflowed)
+        );
+    }
+}
+
+fn integer_smart_signed_comparisons(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = random_signed_value(&mut rng, num_bits);
+        let clear_1 = random_signed_value(&mut rng, num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let mut ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let ct_res = sks.smart_signed_gt(&mut ctxt_0, &mut ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(u64::from(clear_0 > clear_1), dec);
+
+        let ct_res = sks.smart_signed_ge(&mut ctxt_0, &mut ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(u64::from(clear_0 >= clear_1), dec);
+
+        let ct_res = sks.smart_signed_lt(&mut ctxt_0, &mut ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(u64::from(clear_0 < clear_1), dec);
+
+        let ct_res = sks.smart_signed_le(&mut ctxt_0, &mut ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(u64::from(clear_0 <= clear_1), dec);
+
+        let ct_res = sks.smart_signed_max(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec);
+
+        let ct_res = sks.smart_signed_min(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec);
+    }
+}
+
+fn integer_unchecked_comparisons_as_block(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = if i % 3 == 0 {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        let ct_res = sks.unchecked_ne(&ctxt_0, &ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(u64::from(clear_0 != clear_1), dec);
+
+        let eq = sks.unchecked_eq_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_one_block(&eq));
+
+        let ne = sks.unchecked_ne_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt_one_block(&ne));
+
+        let gt = sks.unchecked_gt_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&gt));
+
+        let ge = sks.unchecked_ge_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt_one_block(&ge));
+
+        let lt = sks.unchecked_lt_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_one_block(&lt));
+
+        let le = sks.unchecked_le_as_block(&ctxt_0, &ctxt_1);
+        assert_eq!(u64::from(clear_0 <= clear_1), cks.decrypt_one_block(&le));
+
+        // The blocks can be used directly with shortint operations
+        let ct_res = sks.key.unchecked_bitand(&ge, &ne);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.unchecked_if_then_else(&lt, &ctxt_0, &ctxt_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec);
+    }
+}
+
+fn integer_smart_signed_comparisons_as_block(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = random_signed_value(&mut rng, num_bits);
+        let clear_1 = random_signed_value(&mut rng, num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let mut ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let ct_res = sks.smart_signed_gt_as_block(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.smart_signed_ge_as_block(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(
+            u64::from(clear_0 >= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.smart_signed_lt_as_block(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.smart_signed_le_as_block(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(
+            u64::from(clear_0 <= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+    }
+}
+
+/// Returns the expected (count_ones, count_zeros, leading_zeros, trailing_zeros, ilog2)
+/// of a value on num_bits bits, ilog2 of 0 wrapping around to the maximum value
+fn clear_bit_counts(value: u64, num_bits: u32) -> (u64, u64, u64, u64, u64) {
+    let count_ones = u64::from(value.count_ones());
+    let leading_zeros = u64::from(value.leading_zeros() - (u64::BITS - num_bits));
+    let trailing_zeros = u64::from(value.trailing_zeros().min(num_bits));
+    let ilog2 = (u64::from(num_bits) - 1).wrapping_sub(leading_zeros) % (1 << num_bits);
+    (
+        count_ones,
+        u64::from(num_bits) - count_ones,
+        leading_zeros,
+        trailing_zeros,
+        ilog2,
+    )
+}
+
+fn integer_unchecked_bit_count(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for i in 0..NB_TEST_SMALLER {
+        // Also check values whose high (or low) blocks are zero
+        let clear = match i % 4 {
+            0 => 0,
+            1 => rng.gen::<u64>() % param.message_modulus.0 as u64,
+            2 => (rng.gen::<u64>() % 4) << (num_bits - 2),
+            _ => rng.gen::<u64>() % (1 << num_bits),
+        };
+
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let (count_ones, count_zeros, leading_zeros, trailing_zeros, ilog2) =
+            clear_bit_counts(clear, num_bits);
+
+        let ct_res = sks.unchecked_count_ones(&ct);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(count_ones, dec);
+
+        let ct_res = sks.unchecked_count_zeros(&ct);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(count_zeros, dec);
+
+        let ct_res = sks.unchecked_leading_zeros(&ct);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(leading_zeros, dec);
+
+        let ct_res = sks.unchecked_trailing_zeros(&ct);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(trailing_zeros, dec);
+
+        let ct_res = sks.unchecked_ilog2(&ct);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(ilog2, dec);
+    }
+}
+
+fn integer_smart_bit_count(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Fill the carries so that the input has to be propagated
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let (count_ones, _, leading_zeros, trailing_zeros, ilog2) =
+            clear_bit_counts(clear, num_bits);
+
+        let ct_res = sks.smart_count_ones(&mut ct_0);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(count_ones, dec);
+
+        let ct_res = sks.smart_leading_zeros(&mut ct_0);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(leading_zeros, dec);
+
+        let ct_res = sks.smart_trailing_zeros(&mut ct_0);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(trailing_zeros, dec);
+
+        let ct_res = sks.smart_ilog2(&mut ct_0);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(ilog2, dec);
+    }
+}
+
+/// Returns the square root of value, rounded down
+fn clear_sqrt(value: u64) -> u64 {
+    let mut root = (value as f64).sqrt() as u64;
+    while root * root > value {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= value {
+        root += 1;
+    }
+    root
+}
+
+fn integer_smart_scalar_pow(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen_range(0..16u32);
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Fill the carries so that the input has to be propagated
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_scalar_pow(&mut ct_0, exponent);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear.wrapping_pow(exponent) % modulus, dec_res);
+    }
+}
+
+fn integer_unchecked_pow(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    // The exponent is encrypted on fewer blocks to keep the test short
+    let exponent_num_blocks = 2;
+    let exponent_modulus = param.message_modulus.0.pow(exponent_num_blocks as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % exponent_modulus;
+
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+        let ct_exponent = cks.encrypt_radix(exponent, exponent_num_blocks);
+
+        let ct_res = sks.unchecked_pow(&ct, &ct_exponent);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear.wrapping_pow(exponent as u32) % modulus, dec_res);
+    }
+}
+
+fn integer_unchecked_sqrt(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear = match i {
+            0 => 0,
+            1 => modulus - 1,
+            2 => {
+                let root = clear_sqrt(modulus - 1);
+                root * root
+            }
+            _ => rng.gen::<u64>() % modulus,
+        };
+
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_sqrt(&ct);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_sqrt(clear), dec_res);
+    }
+}
+
+fn integer_resize(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let block_modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let num_blocks = rng.gen_range(0..=NB_CTXT);
+
+        let ct_res = sks.extend_radix_with_trivial_zero_blocks_msb(&ct, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT + num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear, dec_res);
+
+        let ct_res = sks.trim_radix_blocks_msb(&ct, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            clear % block_modulus.pow((NB_CTXT - num_blocks) as u32),
+            dec_res
+        );
+
+        let ct_res = sks.trim_radix_blocks_lsb(&ct, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear / block_modulus.pow(num_blocks as u32), dec_res);
+
+        let new_num_blocks = rng.gen_range(1..=2 * NB_CTXT);
+        let ct_res = sks.cast_to_num_blocks(&ct, new_num_blocks);
+        assert_eq!(ct_res.blocks.len(), new_num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        let new_modulus = block_modulus.pow(new_num_blocks as u32);
+        assert_eq!(clear % new_modulus, dec_res);
+    }
+}
+
+fn integer_smart_resize(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let block_modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear = (clear_0 + clear_1) % modulus;
+
+        // The addition fills the carries, which must be propagated
+        // before the new blocks are appended
+        let mut ct = cks.encrypt_radix(clear_0, NB_CTXT);
+        sks.unchecked_add_assign(&mut ct, &cks.encrypt_radix(clear_1, NB_CTXT));
+
+        let num_blocks = rng.gen_range(0..=NB_CTXT);
+        let mut ct_0 = ct.clone();
+        let ct_res = sks.smart_extend_radix_with_trivial_zero_blocks_msb(&mut ct_0, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT + num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear, dec_res);
+
+        let new_num_blocks = rng.gen_range(1..=2 * NB_CTXT);
+        let mut ct_0 = ct.clone();
+        sks.smart_cast_to_num_blocks_assign(&mut ct_0, new_num_blocks);
+        assert_eq!(ct_0.blocks.len(), new_num_blocks);
+        let dec_res: u64 = cks.decrypt_radix(&ct_0);
+        let new_modulus = block_modulus.pow(new_num_blocks as u32);
+        assert_eq!(clear % new_modulus, dec_res);
+    }
+}
+
+fn integer_sum_dot_product(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    assert!(sks.sum_ciphertexts(&[]).is_none());
+
+    for _ in 0..NB_TEST_SMALLER {
+        let num_ct = rng.gen_range(1..8);
+        let clears = (0..num_ct)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let weights = (0..num_ct)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_radix(clear, NB_CTXT))
+            .collect::<Vec<_>>();
+
+        let ct_res = sks.sum_ciphertexts(&cts).unwrap();
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clears.iter().sum::<u64>() % modulus, dec_res);
+
+        let ct_res = sks.dot_product_with_clear(&cts, &weights).unwrap();
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        let expected = clears
+            .iter()
+            .zip(weights.iter())
+            .fold(0, |acc, (clear, weight)| (acc + clear * weight) % modulus);
+        assert_eq!(expected, dec_res);
     }
 }
 
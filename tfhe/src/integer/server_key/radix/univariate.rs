@@ -0,0 +1,257 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::BivariateLookupTableOwned;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    pub(crate) fn assert_tree_based_lut_is_possible(&self, ct: &RadixCiphertext) {
+        assert!(
+            !ct.blocks.is_empty(),
+            "The ciphertext must have at least one block"
+        );
+        assert!(
+            self.key.carry_modulus.0 >= self.key.message_modulus.0,
+            "The carry modulus must be at least as big as the message modulus \
+            to select blocks with bivariate PBS"
+        );
+    }
+
+    /// Accumulators selecting a block among `message_modulus` candidates.
+    ///
+    /// The i-th accumulator keeps its left input if its right input (the selector) is `i`,
+    /// and returns 0 otherwise.
+    pub(crate) fn tree_selection_accumulators(&self) -> Vec<BivariateLookupTableOwned> {
+        (0..self.key.message_modulus.0 as u64)
+            .map(|i| {
+                self.key
+                    .generate_accumulator_bivariate(move |x, y| if y == i { x } else { 0 })
+            })
+            .collect()
+    }
+
+    /// Returns the `digit`-th block of `f(x)`, where `x` is made of the given `low` part
+    /// (the first two blocks) and `high` part (the remaining blocks).
+    pub(crate) fn tree_leaf_value<F>(&self, f: &F, digit: usize, low: u64, high: u64) -> u64
+    where
+        F: Fn(u64) -> u64,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let x = low + high * message_modulus * message_modulus;
+        (f(x) / message_modulus.pow(digit as u32)) % message_modulus
+    }
+
+    /// Computes the leaves of the tree used to evaluate the `digit`-th output block.
+    ///
+    /// There is one leaf for each possible value of the blocks above the first two,
+    /// each of them being a bivariate PBS over those first two blocks.
+    fn univariate_tree_leaves<F>(
+        &self,
+        ct: &RadixCiphertext,
+        f: &F,
+        digit: usize,
+    ) -> Vec<CiphertextBig>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        if ct.blocks.len() == 1 {
+            let acc = self
+                .key
+                .generate_accumulator(|x| self.tree_leaf_value(f, digit, x, 0));
+            return vec![self.key.apply_lookup_table(&ct.blocks[0], &acc)];
+        }
+
+        let num_leaves = message_modulus.pow(ct.blocks.len() as u32 - 2);
+        (0..num_leaves)
+            .map(|high| {
+                let acc = self.key.generate_accumulator_bivariate(|left, right| {
+                    self.tree_leaf_value(f, digit, right + left * message_modulus, high)
+                });
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[1], &ct.blocks[0], &acc)
+            })
+            .collect()
+    }
+
+    /// Selects, among the candidates, the one whose index is encrypted in `selector`.
+    ///
+    /// As only one of the bivariate PBS keeps its candidate, the sum is a single
+    /// message and not a carry.
+    pub(crate) fn unchecked_tree_select(
+        &self,
+        candidates: &[CiphertextBig],
+        selector: &CiphertextBig,
+        accs: &[BivariateLookupTableOwned],
+    ) -> CiphertextBig {
+        let mut result =
+            self.key
+                .unchecked_apply_lookup_table_bivariate(&candidates[0], selector, &accs[0]);
+        for (candidate, acc) in candidates.iter().zip(accs.iter()).skip(1) {
+            let maybe_candidate = self
+                .key
+                .unchecked_apply_lookup_table_bivariate(candidate, selector, acc);
+            self.key.unchecked_add_assign(&mut result, &maybe_candidate);
+        }
+        result.degree = Degree(self.key.message_modulus.0 - 1);
+        result
+    }
+
+    /// Evaluates homomorphically a univariate function over the whole ciphertext.
+    ///
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// This only uses PBS: the output blocks are the roots of trees of block selections,
+    /// whose leaves are bivariate PBS over the two least significant blocks.
+    /// The number of PBS grows exponentially with the number of blocks,
+    /// so this is only practical for small integers (up to ~12 bits).
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 3 * 2 = 6 bits of message
+    /// let size = 3;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 37u64;
+    /// let f = |x: u64| (x * x + 3) % 64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_apply_univariate_function(&ct, f);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn unchecked_apply_univariate_function<F>(
+        &self,
+        ct: &RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        self.assert_tree_based_lut_is_possible(ct);
+        let message_modulus = self.key.message_modulus.0;
+        let accs = self.tree_selection_accumulators();
+
+        let blocks = (0..ct.blocks.len())
+            .map(|digit| {
+                let mut nodes = self.univariate_tree_leaves(ct, &f, digit);
+                for selector in ct.blocks.iter().skip(2) {
+                    nodes = nodes
+                        .chunks(message_modulus)
+                        .map(|candidates| self.unchecked_tree_select(candidates, selector, &accs))
+                        .collect();
+                }
+                nodes.pop().unwrap()
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Evaluates homomorphically a univariate function over the whole ciphertext.
+    ///
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 3 * 2 = 6 bits of message
+    /// let size = 3;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 37u64;
+    /// let f = |x: u64| x / 3;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_apply_univariate_function(&mut ct, f);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn smart_apply_univariate_function<F>(
+        &self,
+        ct: &mut RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_apply_univariate_function(ct, f)
+    }
+
+    /// Looks up homomorphically the value encrypted in the ciphertext in a clear table.
+    ///
+    /// The i-th element of the table is the output for the input `i`,
+    /// inputs outside of the table are mapped to 0.
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 2 * 2 = 4 bits of message
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let table = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+    /// let msg = 10u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_apply_lookup_table_radix(&ct, &table);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[msg as usize]);
+    /// ```
+    pub fn unchecked_apply_lookup_table_radix(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        self.unchecked_apply_univariate_function(ct, |x| {
+            table.get(x as usize).copied().unwrap_or(0)
+        })
+    }
+
+    /// Looks up homomorphically the value encrypted in the ciphertext in a clear table.
+    ///
+    /// The i-th element of the table is the output for the input `i`,
+    /// inputs outside of the table are mapped to 0.
+    ///
+    /// The carries of the input are propagated if needed.
+    pub fn smart_apply_lookup_table_radix(
+        &self,
+        ct: &mut RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate(ct);
+        }
+        self.unchecked_apply_lookup_table_radix(ct, table)
+    }
+}
@@ -40,8 +40,8 @@ impl ServerKey {
     ) -> RadixCiphertext {
         if !self.is_add_possible(ct_left, ct_right) {
             rayon::join(
-                || self.full_propagate_parallelized(ct_left),
-                || self.full_propagate_parallelized(ct_right),
+                || self.full_propagate_parallelized_low_latency(ct_left),
+                || self.full_propagate_parallelized_low_latency(ct_right),
             );
         }
         self.unchecked_add(ct_left, ct_right)
@@ -54,8 +54,8 @@ impl ServerKey {
     ) {
         if !self.is_add_possible(ct_left, ct_right) {
             rayon::join(
-                || self.full_propagate_parallelized(ct_left),
-                || self.full_propagate_parallelized(ct_right),
+                || self.full_propagate_parallelized_low_latency(ct_left),
+                || self.full_propagate_parallelized_low_latency(ct_right),
             );
         }
         self.unchecked_add_assign(ct_left, ct_right);
@@ -0,0 +1,413 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::server_key::radix::ZeroCountDirection;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Sums blocks whose values fit in the message space into a radix ciphertext
+    /// of `num_blocks` blocks, with empty carries.
+    ///
+    /// The sum is computed as a tree reduction.
+    fn sum_blocks_parallelized(
+        &self,
+        blocks: Vec<CiphertextBig>,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let mut result = blocks
+            .into_par_iter()
+            .map(|block| self.block_to_radix(block, num_blocks))
+            .reduce_with(|mut lhs, mut rhs| {
+                self.smart_add_assign_parallelized(&mut lhs, &mut rhs);
+                lhs
+            })
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    fn unchecked_count_bits_parallelized<F>(&self, ct: &RadixCiphertext, f: F) -> RadixCiphertext
+    where
+        F: Fn(u64) -> bool,
+    {
+        let acc = self.bit_count_accumulator(f);
+        let counts = ct
+            .blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &acc))
+            .collect();
+        self.sum_blocks_parallelized(counts, ct.blocks.len())
+    }
+
+    fn unchecked_count_zeros_from_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        direction: ZeroCountDirection,
+    ) -> RadixCiphertext {
+        let blocks = match direction {
+            ZeroCountDirection::Leading => ct.blocks.iter().rev().collect::<Vec<_>>(),
+            ZeroCountDirection::Trailing => ct.blocks.iter().collect::<Vec<_>>(),
+        };
+        let num_blocks = blocks.len();
+
+        let is_zero_acc = self.key.generate_accumulator(|x| u64::from(x == 0));
+        let and_acc = self.boolean_sum_and_accumulator();
+
+        // Hillis-Steele scan: after the loop, all_zero[i] encrypts
+        // whether all the blocks up to the i-th one (included) are zero
+        let mut all_zero = blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &is_zero_acc))
+            .collect::<Vec<_>>();
+        let mut step = 1;
+        while step < num_blocks {
+            all_zero = (0..num_blocks)
+                .into_par_iter()
+                .map(|i| {
+                    if i < step {
+                        return all_zero[i].clone();
+                    }
+                    let mut both_zero = self.key.unchecked_add(&all_zero[i - step], &all_zero[i]);
+                    self.key.apply_lookup_table_assign(&mut both_zero, &and_acc);
+                    both_zero
+                })
+                .collect();
+            step *= 2;
+        }
+
+        let zero_count_acc = self.zero_count_accumulator(direction);
+        let trivial_one = self.key.create_trivial(1);
+        let counts = blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let all_zero_before = if i == 0 {
+                    &trivial_one
+                } else {
+                    &all_zero[i - 1]
+                };
+                let mut packed = self.pack_zero_count_input(all_zero_before, block);
+                self.key
+                    .apply_lookup_table_assign(&mut packed, &zero_count_acc);
+                packed
+            })
+            .collect();
+
+        self.sum_blocks_parallelized(counts, num_blocks)
+    }
+
+    /// Computes homomorphically the number of bits set to one in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_count_ones_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_ones()));
+    /// ```
+    pub fn unchecked_count_ones_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_bits_parallelized(ct, |bit| bit == 1)
+    }
+
+    /// Computes homomorphically the number of bits set to zero in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_count_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_zeros()));
+    /// ```
+    pub fn unchecked_count_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_bits_parallelized(ct, |bit| bit == 0)
+    }
+
+    /// Computes homomorphically the number of leading zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 12u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_leading_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.leading_zeros()));
+    /// ```
+    pub fn unchecked_leading_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_zeros_from_parallelized(ct, ZeroCountDirection::Leading)
+    }
+
+    /// Computes homomorphically the number of trailing zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 96u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_trailing_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.trailing_zeros()));
+    /// ```
+    pub fn unchecked_trailing_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_zeros_from_parallelized(ct, ZeroCountDirection::Trailing)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down.
+    ///
+    /// The result has the same number of blocks as the input.
+    /// If the ciphertext encrypts 0, the result wraps around to the maximum value.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 100u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_ilog2_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn unchecked_ilog2_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let num_bits = self.message_bits_per_block() as u64 * num_blocks as u64;
+
+        let mut leading_zeros = self.unchecked_leading_zeros_parallelized(ct);
+        let mut result = self.create_trivial_radix(num_bits - 1, num_blocks);
+        let mut result = self.smart_sub_parallelized(&mut result, &mut leading_zeros);
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Computes homomorphically the number of bits set to one in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_count_ones_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_ones()));
+    /// ```
+    pub fn smart_count_ones_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_count_ones_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of bits set to zero in the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 179u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_count_zeros_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.count_zeros()));
+    /// ```
+    pub fn smart_count_zeros_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_count_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of leading zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 12u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_leading_zeros_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.leading_zeros()));
+    /// ```
+    pub fn smart_leading_zeros_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_leading_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of trailing zeros of the ciphertext.
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 96u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_trailing_zeros_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u64::from(msg.trailing_zeros()));
+    /// ```
+    pub fn smart_trailing_zeros_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_trailing_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down.
+    ///
+    /// The result has the same number of blocks as the input.
+    /// If the ciphertext encrypts 0, the result wraps around to the maximum value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 100u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_ilog2_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn smart_ilog2_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_ilog2_parallelized(ct)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Selects block-wise between two integer ciphertexts of the same shape.
+    pub(crate) fn unchecked_if_then_else_impl_parallelized<T: IntegerCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T {
+        let accs = self.cmux_accumulators(true_ct.blocks().iter().chain(false_ct.blocks()));
+        let blocks = true_ct
+            .blocks()
+            .par_iter()
+            .zip(false_ct.blocks().par_iter())
+            .map(|(true_block, false_block)| {
+                self.unchecked_block_cmux(condition, true_block, false_block, &accs)
+            })
+            .collect();
+        T::from_blocks(blocks)
+    }
+
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false),
+    /// for example the first block of the result of a comparison.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 97;
+    /// let msg2 = 14;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let is_lower = sks.unchecked_lt_parallelized(&ct1, &ct2);
+    /// let ct_res = sks.unchecked_if_then_else_parallelized(&is_lower.blocks()[0], &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg1.min(msg2), dec);
+    /// ```
+    pub fn unchecked_if_then_else_parallelized<T: IntegerRadixCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T {
+        self.unchecked_if_then_else_impl_parallelized(condition, true_ct, false_ct)
+    }
+
+    /// Homomorphically selects `true_ct` if the condition is true and `false_ct` otherwise.
+    ///
+    /// The condition is a single block encrypting 1 (true) or 0 (false).
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 21;
+    /// let msg2 = 140;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    ///
+    /// let is_equal = sks.smart_eq_parallelized(&mut ct1, &mut ct2);
+    /// let ct_res = sks.smart_if_then_else_parallelized(&is_equal.blocks()[0], &mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg2, dec);
+    /// ```
+    pub fn smart_if_then_else_parallelized<T: IntegerRadixCiphertext>(
+        &self,
+        condition: &CiphertextBig,
+        true_ct: &mut T,
+        false_ct: &mut T,
+    ) -> T {
+        rayon::join(
+            || {
+                if has_non_zero_carries(true_ct) {
+                    self.full_propagate_parallelized(true_ct);
+                }
+            },
+            || {
+                if has_non_zero_carries(false_ct) {
+                    self.full_propagate_parallelized(false_ct);
+                }
+            },
+        );
+        self.unchecked_if_then_else_parallelized(condition, true_ct, false_ct)
+    }
+}
@@ -1,7 +1,8 @@
 use super::ServerKey;
 
 use crate::integer::server_key::comparator::Comparator;
-use crate::integer::RadixCiphertext;
+use crate::integer::{RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::CiphertextBig;
 
 impl ServerKey {
     pub fn unchecked_eq_parallelized(
@@ -44,6 +45,14 @@ impl ServerKey {
         Comparator::new(self).unchecked_le_parallelized(lhs, rhs)
     }
 
+    pub fn unchecked_ne_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_ne_parallelized(lhs, rhs)
+    }
+
     pub fn unchecked_max_parallelized(
         &self,
         lhs: &RadixCiphertext,
@@ -100,6 +109,14 @@ impl ServerKey {
         Comparator::new(self).smart_le_parallelized(lhs, rhs)
     }
 
+    pub fn smart_ne_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_ne_parallelized(lhs, rhs)
+    }
+
     pub fn smart_max_parallelized(
         &self,
         lhs: &mut RadixCiphertext,
@@ -115,4 +132,260 @@ impl ServerKey {
     ) -> RadixCiphertext {
         Comparator::new(self).smart_min_parallelized(lhs, rhs)
     }
+
+    pub fn unchecked_signed_gt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_gt_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_ge_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_ge_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_lt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_lt_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_le_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).unchecked_signed_le_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_max_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).unchecked_signed_max_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_min_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).unchecked_signed_min_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_gt_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_gt_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_ge_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_lt_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_le_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).smart_signed_le_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_max_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).smart_signed_max_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_min_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        Comparator::new(self).smart_signed_min_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_eq_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_eq_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_ne_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_ne_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_gt_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_gt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_ge_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_ge_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_lt_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_lt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_le_as_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_le_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_eq_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_eq_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_ne_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_ne_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_gt_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_gt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_ge_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_ge_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_lt_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_lt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_le_as_block_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_le_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_gt_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_gt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_ge_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_ge_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_lt_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_lt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_signed_le_as_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).unchecked_signed_le_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_gt_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_gt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_ge_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_ge_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_lt_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_lt_as_block_parallelized(lhs, rhs)
+    }
+
+    pub fn smart_signed_le_as_block_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> CiphertextBig {
+        Comparator::new(self).smart_signed_le_as_block_parallelized(lhs, rhs)
+    }
 }
@@ -0,0 +1,165 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::{has_non_zero_carries, Comparator};
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// encrypting integer values.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg_1 = 97;
+    /// let msg_2 = 14;
+    ///
+    /// let ct_1 = cks.encrypt(msg_1);
+    /// let ct_2 = cks.encrypt(msg_2);
+    ///
+    /// // Compute homomorphically a division:
+    /// let (q, r) = sks.unchecked_div_rem_parallelized(&ct_1, &ct_2);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg_1 / msg_2);
+    /// assert_eq!(r, msg_1 % msg_2);
+    /// ```
+    pub fn unchecked_div_rem_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        assert_eq!(numerator.blocks.len(), divisor.blocks.len());
+        let num_blocks = numerator.blocks.len();
+
+        let comparator = Comparator::new(self);
+
+        //number of bits of message
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let bit_extractors = (0..nb_bits)
+            .into_par_iter()
+            .map(|i| self.key.generate_accumulator(|x| (x >> i) & 1))
+            .collect::<Vec<_>>();
+
+        // The numerator bits do not depend on the remainder, so they are all extracted upfront
+        let numerator_bits = (0..num_blocks * nb_bits)
+            .into_par_iter()
+            .map(|i| {
+                self.key.apply_lookup_table(
+                    &numerator.blocks[i / nb_bits],
+                    &bit_extractors[i % nb_bits],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The remainder has one more block than the inputs,
+        // so that shifting it to the left never loses its msb
+        let mut remainder = self.create_trivial_zero_radix(num_blocks + 1);
+        let mut extended_divisor = divisor.clone();
+        extended_divisor.blocks.push(self.key.create_trivial(0));
+
+        let mut quotient = self.create_trivial_zero_radix(num_blocks);
+
+        for (i, numerator_bit) in numerator_bits.iter().enumerate().rev() {
+            let block_index = i / nb_bits;
+            let pos_in_block = i % nb_bits;
+
+            // remainder = (remainder << 1) | numerator_bit_i
+            self.unchecked_small_scalar_mul_assign(&mut remainder, 2);
+            self.key
+                .unchecked_add_assign(&mut remainder.blocks[0], numerator_bit);
+            self.full_propagate_parallelized(&mut remainder);
+
+            // 1 if the divisor fits in the remainder, 0 otherwise
+            let mut quotient_bit = comparator
+                .unchecked_ge_parallelized(&remainder, &extended_divisor)
+                .blocks
+                .swap_remove(0);
+
+            // Only the blocks of the original divisor have to be masked,
+            // the last one is a trivial zero
+            let mut subtrahend = extended_divisor.clone();
+            subtrahend.blocks[..num_blocks]
+                .par_iter_mut()
+                .for_each(|block| self.key.unchecked_mul_lsb_assign(block, &quotient_bit));
+            self.smart_sub_assign_parallelized(&mut remainder, &mut subtrahend);
+            self.full_propagate_parallelized(&mut remainder);
+
+            self.key
+                .unchecked_scalar_mul_assign(&mut quotient_bit, 1 << pos_in_block);
+            self.key
+                .unchecked_add_assign(&mut quotient.blocks[block_index], &quotient_bit);
+        }
+
+        // The remainder is smaller than the divisor, so its last block is empty
+        remainder.blocks.pop();
+
+        (quotient, remainder)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// encrypting integer values.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg_1 = 200;
+    /// let msg_2 = 7;
+    ///
+    /// let mut ct_1 = cks.encrypt(msg_1);
+    /// let mut ct_2 = cks.encrypt(msg_2);
+    ///
+    /// // Compute homomorphically a division:
+    /// let (q, r) = sks.smart_div_rem_parallelized(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg_1 / msg_2);
+    /// assert_eq!(r, msg_1 % msg_2);
+    /// ```
+    pub fn smart_div_rem_parallelized(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: &mut RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        rayon::join(
+            || {
+                if has_non_zero_carries(numerator) {
+                    self.full_propagate_parallelized(numerator);
+                }
+            },
+            || {
+                if has_non_zero_carries(divisor) {
+                    self.full_propagate_parallelized(divisor);
+                }
+            },
+        );
+        self.unchecked_div_rem_parallelized(numerator, divisor)
+    }
+}
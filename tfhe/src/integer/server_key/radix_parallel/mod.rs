@@ -1,19 +1,54 @@
 mod add;
+mod bit_count;
 mod bitwise_op;
+mod cmux;
 mod comparison;
+mod div_mod;
 mod mul;
 mod neg;
+mod oblivious;
+mod overflowing;
+mod pow;
 mod scalar_add;
+mod scalar_comparison;
+mod scalar_div_mod;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
+mod signed;
+mod sort;
+mod sqrt;
 mod sub;
+mod sum;
+mod univariate;
 
 #[cfg(test)]
 mod tests;
 
 use super::ServerKey;
-use crate::integer::RadixCiphertext;
+use crate::integer::IntegerRadixCiphertext;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+/// State of the carry going out of a block, as computed by the parallel prefix propagation.
+#[repr(u64)]
+enum OutputCarry {
+    /// The block does not output a carry
+    None = 0,
+    /// The block outputs a carry, whatever its input carry is
+    Generated = 1,
+    /// The block outputs a carry only if it receives one
+    Propagated = 2,
+}
+
+/// Combines the carry state of a block with the state of the blocks below it.
+fn prefix_sum_carry_propagation(block_state: u64, previous_state: u64) -> u64 {
+    if block_state == OutputCarry::Propagated as u64 {
+        previous_state
+    } else {
+        block_state
+    }
+}
 
 // parallelized versions
 impl ServerKey {
@@ -42,17 +77,17 @@ impl ServerKey {
     /// let res = cks.decrypt_one_block(&ct_res.blocks()[1]);
     /// assert_eq!(3, res);
     /// ```
-    pub fn propagate_parallelized(&self, ctxt: &mut RadixCiphertext, index: usize) {
+    pub fn propagate_parallelized<T: IntegerRadixCiphertext>(&self, ctxt: &mut T, index: usize) {
         let (carry, message) = rayon::join(
-            || self.key.carry_extract(&ctxt.blocks[index]),
-            || self.key.message_extract(&ctxt.blocks[index]),
+            || self.key.carry_extract(&ctxt.blocks()[index]),
+            || self.key.message_extract(&ctxt.blocks()[index]),
         );
-        ctxt.blocks[index] = message;
+        ctxt.blocks_mut()[index] = message;
 
         //add the carry to the next block
-        if index < ctxt.blocks.len() - 1 {
+        if index < ctxt.blocks().len() - 1 {
             self.key
-                .unchecked_add_assign(&mut ctxt.blocks[index + 1], &carry);
+                .unchecked_add_assign(&mut ctxt.blocks_mut()[index + 1], &carry);
         }
     }
 
@@ -81,10 +116,178 @@ impl ServerKey {
     /// let res = cks.decrypt(&ct_res);
     /// assert_eq!(msg + msg, res);
     /// ```
-    pub fn full_propagate_parallelized(&self, ctxt: &mut RadixCiphertext) {
-        let len = ctxt.blocks.len();
+    pub fn full_propagate_parallelized<T: IntegerRadixCiphertext>(&self, ctxt: &mut T) {
+        let len = ctxt.blocks().len();
         for i in 0..len {
             self.propagate_parallelized(ctxt, i);
         }
     }
+
+    /// Returns whether the parameters of the server key allow to propagate carries
+    /// with [`Self::full_propagate_parallelized_low_latency`].
+    ///
+    /// The carry states of two blocks are combined with a bivariate PBS, so the message and
+    /// carry spaces of a block each have to be able to hold the three possible states.
+    pub fn is_eligible_for_low_latency_propagation(&self) -> bool {
+        self.key.message_modulus.0 >= 3 && self.key.carry_modulus.0 >= 3
+    }
+
+    /// Propagate all the carries, in a depth that is logarithmic in the number of blocks.
+    ///
+    /// Each block computes whether it generates or propagates a carry, then these states are
+    /// combined with a parallel prefix (Hillis-Steele) scan, so that all the input carries are
+    /// known after `log2(num_blocks)` bivariate PBS.
+    ///
+    /// The parallel prefix can only resolve carries of at most 1 per block, if some blocks hold
+    /// bigger carries, a first round extracting the carries of all the blocks in parallel is
+    /// done. If the parameters are not eligible, this falls back to
+    /// [`Self::full_propagate_parallelized`].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 127;
+    ///
+    /// let mut ct1 = cks.encrypt(msg);
+    /// let mut ct2 = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let mut ct_res = sks.unchecked_add(&mut ct1, &mut ct2);
+    /// sks.full_propagate_parallelized_low_latency(&mut ct_res);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg + msg, res);
+    /// ```
+    pub fn full_propagate_parallelized_low_latency<T: IntegerRadixCiphertext>(&self, ctxt: &mut T) {
+        if !self.is_eligible_for_low_latency_propagation() {
+            self.full_propagate_parallelized(ctxt);
+            return;
+        }
+
+        if !self.has_at_most_single_carries(ctxt) {
+            self.extract_all_carries_parallelized(ctxt);
+            if !self.has_at_most_single_carries(ctxt) {
+                self.full_propagate_parallelized(ctxt);
+                return;
+            }
+        }
+
+        let input_carries = self.compute_input_carries_parallelized(ctxt.blocks());
+        ctxt.blocks_mut()
+            .par_iter_mut()
+            .zip(input_carries.par_iter())
+            .for_each(|(block, input_carry)| {
+                self.key.unchecked_add_assign(block, input_carry);
+                self.key.message_extract_assign(block);
+            });
+    }
+
+    /// Returns whether adding a carry of 1 to any block makes it output a carry of at most 1.
+    fn has_at_most_single_carries<T: IntegerRadixCiphertext>(&self, ctxt: &T) -> bool {
+        let max_value = 2 * self.key.message_modulus.0 - 2;
+        ctxt.blocks()
+            .iter()
+            .all(|block| block.degree.0 <= max_value)
+    }
+
+    /// Splits every block in its message and its carry, and adds each carry to the next block.
+    ///
+    /// After this, each block holds at most `message_modulus - 1 + carry_modulus - 1`.
+    fn extract_all_carries_parallelized<T: IntegerRadixCiphertext>(&self, ctxt: &mut T) {
+        let num_blocks = ctxt.blocks().len();
+        let carries = ctxt
+            .blocks_mut()
+            .par_iter_mut()
+            .map(|block| {
+                let carry = self.key.carry_extract(block);
+                self.key.message_extract_assign(block);
+                carry
+            })
+            .collect::<Vec<_>>();
+
+        // The carry of the last block is dropped
+        for (i, carry) in carries.iter().enumerate().take(num_blocks - 1) {
+            self.key
+                .unchecked_add_assign(&mut ctxt.blocks_mut()[i + 1], carry);
+        }
+    }
+
+    /// Computes the carry going into each block, the blocks having carries of at most 1.
+    fn compute_input_carries_parallelized(&self, blocks: &[CiphertextBig]) -> Vec<CiphertextBig> {
+        let modulus = self.key.message_modulus.0 as u64;
+
+        // The first block has no input carry, so it never propagates
+        let acc_generates = self.key.generate_accumulator(|x| {
+            if x >= modulus {
+                OutputCarry::Generated as u64
+            } else {
+                OutputCarry::None as u64
+            }
+        });
+        let acc_generates_or_propagates = self.key.generate_accumulator(|x| {
+            if x >= modulus {
+                OutputCarry::Generated as u64
+            } else if x == modulus - 1 {
+                OutputCarry::Propagated as u64
+            } else {
+                OutputCarry::None as u64
+            }
+        });
+
+        let mut states = blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                if i == 0 {
+                    self.key.apply_lookup_table(block, &acc_generates)
+                } else {
+                    self.key
+                        .apply_lookup_table(block, &acc_generates_or_propagates)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Hillis-Steele scan: after the step with offset `space`, the state of each block
+        // accounts for the `2 * space` blocks below it
+        let acc_prefix_sum = self
+            .key
+            .generate_accumulator_bivariate(prefix_sum_carry_propagation);
+        let num_blocks = states.len();
+        let mut space = 1;
+        while space < num_blocks {
+            let new_states = states[space..]
+                .par_iter()
+                .zip(states[..num_blocks - space].par_iter())
+                .map(|(state, previous_state)| {
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        state,
+                        previous_state,
+                        &acc_prefix_sum,
+                    )
+                })
+                .collect::<Vec<_>>();
+            for (state, new_state) in states[space..].iter_mut().zip(new_states) {
+                *state = new_state;
+            }
+            space *= 2;
+        }
+
+        // As the first block never propagates, all states are now either None or Generated,
+        // which is the carry going out of the block, and into the next one
+        states.pop();
+        states.insert(0, self.key.create_trivial(0));
+        states
+    }
 }
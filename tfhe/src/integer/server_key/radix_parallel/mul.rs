@@ -1,6 +1,7 @@
 use std::sync::Mutex;
 
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
 use crate::integer::ServerKey;
 use rayon::prelude::*;
 
@@ -142,7 +143,7 @@ impl ServerKey {
         index: usize,
     ) -> RadixCiphertext {
         //Makes sure we can do the multiplications
-        self.full_propagate_parallelized(ct1);
+        self.full_propagate_parallelized_low_latency(ct1);
 
         let shifted_ct = self.blockshift(ct1, index);
 
@@ -317,8 +318,8 @@ impl ServerKey {
         ct2: &mut RadixCiphertext,
     ) -> RadixCiphertext {
         rayon::join(
-            || self.full_propagate_parallelized(ct1),
-            || self.full_propagate_parallelized(ct2),
+            || self.full_propagate_parallelized_low_latency(ct1),
+            || self.full_propagate_parallelized_low_latency(ct2),
         );
 
         let terms = Mutex::new(Vec::new());
@@ -326,9 +327,201 @@ impl ServerKey {
             let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
             terms.lock().unwrap().push(term);
         });
-        let mut terms = terms.into_inner().unwrap();
+        let terms = terms.into_inner().unwrap();
 
-        self.smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
+        self.sum_ciphertexts_parallelized(&terms)
             .unwrap_or_else(|| self.create_trivial_zero_radix(ct1.blocks.len()))
     }
+
+    /// Computes the partial products of `ct1` with one block, as two ciphertexts of
+    /// `num_blocks` blocks: the lsb and the msb parts of the block products, shifted
+    /// to the right position.
+    fn unchecked_block_widening_mul_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &crate::shortint::CiphertextBig,
+        index: usize,
+        num_blocks: usize,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let (lsb_blocks, msb_blocks): (Vec<_>, Vec<_>) = ct1
+            .blocks
+            .par_iter()
+            .map(|ct1_j| {
+                rayon::join(
+                    || self.key.unchecked_mul_lsb(ct1_j, ct2),
+                    || self.key.unchecked_mul_msb(ct1_j, ct2),
+                )
+            })
+            .unzip();
+
+        let mut result_lsb = self.create_trivial_zero_radix(num_blocks);
+        let mut result_msb = self.create_trivial_zero_radix(num_blocks);
+        for (j, (lsb, msb)) in lsb_blocks.into_iter().zip(msb_blocks).enumerate() {
+            result_lsb.blocks[index + j] = lsb;
+            result_msb.blocks[index + j + 1] = msb;
+        }
+        (result_lsb, result_msb)
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting integer values.
+    ///
+    /// Unlike [Self::unchecked_mul_parallelized], no part of the product is lost: the result has
+    /// as many blocks as the two inputs together.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clear_1 = 255u64;
+    /// let clear_2 = 143u64;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let ct_res = sks.unchecked_widening_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// assert_eq!(ct_res.blocks().len(), 2 * num_blocks);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 * clear_2, res);
+    /// ```
+    pub fn unchecked_widening_mul_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = ct1.blocks.len() + ct2.blocks.len();
+
+        let terms = ct2
+            .blocks
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, ct2_i)| {
+                let (lsb, msb) =
+                    self.unchecked_block_widening_mul_parallelized(ct1, ct2_i, i, num_blocks);
+                [lsb, msb]
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_ciphertexts_parallelized(&terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting integer values.
+    ///
+    /// The result has as many blocks as the two inputs together.
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn smart_widening_mul_parallelized(
+        &self,
+        ct1: &mut RadixCiphertext,
+        ct2: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        rayon::join(
+            || {
+                if has_non_zero_carries(ct1) {
+                    self.full_propagate_parallelized(ct1);
+                }
+            },
+            || {
+                if has_non_zero_carries(ct2) {
+                    self.full_propagate_parallelized(ct2);
+                }
+            },
+        );
+        self.unchecked_widening_mul_parallelized(ct1, ct2)
+    }
+
+    /// Computes homomorphically the upper half of the full product of two ciphertexts
+    /// encrypting integer values.
+    ///
+    /// This is the product divided by `message_modulus^num_blocks`, which is what fixed-point
+    /// multiplications need.
+    ///
+    /// Requires carry bits to be empty, and the two ciphertexts to have the same number of
+    /// blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clear_1 = 255u64;
+    /// let clear_2 = 143u64;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let ct_res = sks.unchecked_mul_high_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 * clear_2) >> 8, res);
+    /// ```
+    pub fn unchecked_mul_high_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            ct1.blocks.len(),
+            ct2.blocks.len(),
+            "the ciphertexts must have the same number of blocks"
+        );
+        let mut result = self.unchecked_widening_mul_parallelized(ct1, ct2);
+        self.trim_radix_blocks_lsb_assign(&mut result, ct1.blocks.len());
+        result
+    }
+
+    /// Computes homomorphically the upper half of the full product of two ciphertexts
+    /// encrypting integer values.
+    ///
+    /// The two ciphertexts must have the same number of blocks.
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn smart_mul_high_parallelized(
+        &self,
+        ct1: &mut RadixCiphertext,
+        ct2: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            ct1.blocks.len(),
+            ct2.blocks.len(),
+            "the ciphertexts must have the same number of blocks"
+        );
+        let mut result = self.smart_widening_mul_parallelized(ct1, ct2);
+        self.trim_radix_blocks_lsb_assign(&mut result, ct1.blocks.len());
+        result
+    }
 }
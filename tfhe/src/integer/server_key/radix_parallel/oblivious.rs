@@ -0,0 +1,256 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::{has_non_zero_carries, Comparator};
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Sums in parallel blocks of which at most one is non zero, using chunks as big as the
+    /// carry space allows.
+    pub(crate) fn unchecked_sum_one_hot_blocks_parallelized(
+        &self,
+        blocks: &[CiphertextBig],
+    ) -> CiphertextBig {
+        if blocks.is_empty() {
+            return self.key.create_trivial(0);
+        }
+        let chunk_size = self.one_hot_sum_chunk_size();
+        let mut sums = blocks.to_vec();
+        while sums.len() > 1 {
+            sums = sums
+                .par_chunks(chunk_size)
+                .map(|chunk| self.unchecked_sum_one_hot_chunk(chunk))
+                .collect();
+        }
+        sums.pop().unwrap()
+    }
+
+    /// Compares in parallel each index of the table with the encrypted index.
+    fn table_selection_conditions_parallelized(
+        &self,
+        index: &RadixCiphertext,
+        table_len: usize,
+    ) -> Vec<CiphertextBig> {
+        let comparator = Comparator::new(self);
+        (0..table_len)
+            .into_par_iter()
+            .map(|i| comparator.unchecked_scalar_eq_parallelized(index, i as u64))
+            .collect()
+    }
+
+    /// Reads homomorphically the entry of the table at the encrypted index.
+    ///
+    /// Each entry is compared to the index, and only the selected one is kept.
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let clear_table = [17u64, 250, 3, 42];
+    /// let table = clear_table
+    ///     .iter()
+    ///     .map(|&value| cks.encrypt(value))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(2u64);
+    ///
+    /// let ct_res = sks.unchecked_oblivious_read_parallelized(&table, &index);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, clear_table[2]);
+    /// ```
+    pub fn unchecked_oblivious_read_parallelized(
+        &self,
+        table: &[RadixCiphertext],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.assert_table_is_valid(table);
+        let conditions = self.table_selection_conditions_parallelized(index, table.len());
+        let accs = self.cmux_accumulators(table.iter().flat_map(|entry| entry.blocks.iter()));
+
+        let blocks = (0..table[0].blocks.len())
+            .into_par_iter()
+            .map(|block_index| {
+                let masked = table
+                    .par_iter()
+                    .zip(conditions.par_iter())
+                    .map(|(entry, condition)| {
+                        self.unchecked_block_keep_if(condition, &entry.blocks[block_index], &accs)
+                    })
+                    .collect::<Vec<_>>();
+                self.unchecked_sum_one_hot_blocks_parallelized(&masked)
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Reads homomorphically the entry of the table at the encrypted index.
+    ///
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_oblivious_read_parallelized(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        table
+            .par_iter_mut()
+            .chain(rayon::iter::once(&mut *index))
+            .for_each(|ct| {
+                if has_non_zero_carries(ct) {
+                    self.full_propagate_parallelized(ct);
+                }
+            });
+        self.unchecked_oblivious_read_parallelized(table, index)
+    }
+
+    /// Writes homomorphically the value in the entry of the table at the encrypted index.
+    ///
+    /// Every entry is updated with a cmux, so that the index is not revealed.
+    /// If the index is out of the table, the table is left unchanged.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let clear_table = [17u64, 250, 3, 42];
+    /// let mut table = clear_table
+    ///     .iter()
+    ///     .map(|&value| cks.encrypt(value))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(1u64);
+    /// let value = cks.encrypt(99u64);
+    ///
+    /// sks.unchecked_oblivious_write_parallelized(&mut table, &index, &value);
+    ///
+    /// // Decrypt:
+    /// let dec = table.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec, [17, 99, 3, 42]);
+    /// ```
+    pub fn unchecked_oblivious_write_parallelized(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &RadixCiphertext,
+        value: &RadixCiphertext,
+    ) {
+        self.assert_table_is_valid(table);
+        let comparator = Comparator::new(self);
+        table.par_iter_mut().enumerate().for_each(|(i, entry)| {
+            let is_selected = comparator.unchecked_scalar_eq_parallelized(index, i as u64);
+            *entry = self.unchecked_if_then_else_parallelized(&is_selected, value, entry);
+        });
+    }
+
+    /// Writes homomorphically the value in the entry of the table at the encrypted index.
+    ///
+    /// If the index is out of the table, the table is left unchanged.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_oblivious_write_parallelized(
+        &self,
+        table: &mut [RadixCiphertext],
+        index: &mut RadixCiphertext,
+        value: &mut RadixCiphertext,
+    ) {
+        table
+            .par_iter_mut()
+            .chain([&mut *index, &mut *value])
+            .for_each(|ct| {
+                if has_non_zero_carries(ct) {
+                    self.full_propagate_parallelized(ct);
+                }
+            });
+        self.unchecked_oblivious_write_parallelized(table, index, value)
+    }
+
+    /// Returns homomorphically the value of the clear table at the encrypted index,
+    /// as a ciphertext of `num_blocks` blocks.
+    ///
+    /// As the table is clear, the selected value is built from the comparison results
+    /// with scalar multiplications instead of cmuxes.
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 2 * 2 = 4 bits of index
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let table = [1000u64, 2000, 3000, 4000];
+    /// let index = cks.encrypt(3u64);
+    ///
+    /// // The values need 12 bits, so 6 blocks
+    /// let ct_res = sks.unchecked_match_value_parallelized(&index, &table, 6);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[3]);
+    /// ```
+    pub fn unchecked_match_value_parallelized(
+        &self,
+        index: &RadixCiphertext,
+        table: &[u64],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let conditions = self.table_selection_conditions_parallelized(index, table.len());
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let terms = self
+                    .clear_table_digits(table, block_index)
+                    .into_iter()
+                    .zip(conditions.iter())
+                    .filter(|(digit, _)| *digit != 0)
+                    .map(|(digit, condition)| self.key.unchecked_scalar_mul(condition, digit as u8))
+                    .collect::<Vec<_>>();
+                self.unchecked_sum_one_hot_blocks_parallelized(&terms)
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Returns homomorphically the value of the clear table at the encrypted index,
+    /// as a ciphertext of `num_blocks` blocks.
+    ///
+    /// If the index is out of the table, the result is 0.
+    ///
+    /// The carries of the index are propagated if needed.
+    pub fn smart_match_value_parallelized(
+        &self,
+        index: &mut RadixCiphertext,
+        table: &[u64],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(index) {
+            self.full_propagate_parallelized(index);
+        }
+        self.unchecked_match_value_parallelized(index, table, num_blocks)
+    }
+}
@@ -0,0 +1,322 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Propagates all the carries, and returns the carry going out of the last block.
+    fn full_propagate_with_output_carry_parallelized(
+        &self,
+        ctxt: &mut RadixCiphertext,
+    ) -> CiphertextBig {
+        self.partial_propagate_parallelized(ctxt);
+        let last_block = ctxt.blocks.last_mut().unwrap();
+        let (carry, message) = rayon::join(
+            || self.key.carry_extract(last_block),
+            || self.key.message_extract(last_block),
+        );
+        *last_block = message;
+        carry
+    }
+
+    fn propagate_overflowing_inputs_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) {
+        rayon::join(
+            || {
+                if has_non_zero_carries(lhs) {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if has_non_zero_carries(rhs) {
+                    self.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// and whether the addition overflowed.
+    ///
+    /// Returns the result, wrapped around the number of blocks, and a block encrypting 1 if
+    /// the addition overflowed, 0 otherwise.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let (ct_res, overflowed) = sks.unchecked_overflowing_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// let overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(msg1.overflowing_add(msg2), (dec as u8, overflowed == 1));
+    /// ```
+    pub fn unchecked_overflowing_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        let mut result = self.unchecked_add(lhs, rhs);
+        let overflowed = self.full_propagate_with_output_carry_parallelized(&mut result);
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// and whether the addition overflowed.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_overflowing_add_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        self.propagate_overflowing_inputs_parallelized(lhs, rhs);
+        self.unchecked_overflowing_add_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically a subtraction between two ciphertexts encrypting integer values,
+    /// and whether the subtraction overflowed, that is if `rhs` is greater than `lhs`.
+    ///
+    /// Returns the result, wrapped around the number of blocks, and a block encrypting 1 if
+    /// the subtraction overflowed, 0 otherwise.
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_overflowing_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        // lhs - rhs = lhs + !rhs + 1, the subtraction overflows if this sum has no output carry
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let not_acc = self
+            .key
+            .generate_accumulator(|x| message_modulus - 1 - (x % message_modulus));
+        let blocks = lhs
+            .blocks
+            .par_iter()
+            .zip(rhs.blocks.par_iter())
+            .map(|(lhs_block, rhs_block)| {
+                let mut block = self.key.apply_lookup_table(rhs_block, &not_acc);
+                self.key.unchecked_add_assign(&mut block, lhs_block);
+                block
+            })
+            .collect::<Vec<_>>();
+        let mut result = RadixCiphertext::from(blocks);
+        self.key
+            .unchecked_scalar_add_assign(&mut result.blocks[0], 1);
+
+        self.partial_propagate_parallelized(&mut result);
+        let last_block = result.blocks.last_mut().unwrap();
+        let borrow_acc = self
+            .key
+            .generate_accumulator(|x| u64::from(x < message_modulus));
+        let (overflowed, message) = rayon::join(
+            || self.key.apply_lookup_table(last_block, &borrow_acc),
+            || self.key.message_extract(last_block),
+        );
+        *last_block = message;
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically a subtraction between two ciphertexts encrypting integer values,
+    /// and whether the subtraction overflowed.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 97u8;
+    /// let msg2 = 200u8;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1 as u64);
+    /// let mut ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// // Compute homomorphically a subtraction:
+    /// let (ct_res, overflowed) = sks.smart_overflowing_sub_parallelized(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// let overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(msg1.overflowing_sub(msg2), (dec as u8, overflowed == 1));
+    /// ```
+    pub fn smart_overflowing_sub_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        self.propagate_overflowing_inputs_parallelized(lhs, rhs);
+        self.unchecked_overflowing_sub_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer
+    /// values, and whether the multiplication overflowed.
+    ///
+    /// Returns the result, wrapped around the number of blocks, and a block encrypting 1 if
+    /// the multiplication overflowed, 0 otherwise.
+    ///
+    /// The full product is computed on twice the number of blocks,
+    /// so this is about four times as expensive as a multiplication.
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_overflowing_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        let num_blocks = lhs.blocks.len();
+        let extended_lhs = self.extend_with_trivial_zero_blocks(lhs, 2 * num_blocks);
+
+        // Only the blocks of rhs are needed, as the blocks of extended_lhs
+        // shifted by num_blocks or more fall outside of the product.
+        let terms = rhs
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, rhs_i)| self.unchecked_block_mul_parallelized(&extended_lhs, rhs_i, i))
+            .collect::<Vec<_>>();
+        let mut product = self
+            .sum_ciphertexts_parallelized(&terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(2 * num_blocks));
+
+        let high_blocks = product.blocks.split_off(num_blocks);
+        let acc = self.key.generate_accumulator(|x| u64::from(x != 0));
+        let is_non_zero = high_blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &acc))
+            .collect::<Vec<_>>();
+        let overflowed = self.unchecked_any_bit_set(&is_non_zero);
+        (product, overflowed)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer
+    /// values, and whether the multiplication overflowed.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 23u8;
+    /// let msg2 = 12u8;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1 as u64);
+    /// let mut ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// // Compute homomorphically a multiplication:
+    /// let (ct_res, overflowed) = sks.smart_overflowing_mul_parallelized(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// let overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(msg1.overflowing_mul(msg2), (dec as u8, overflowed == 1));
+    /// ```
+    pub fn smart_overflowing_mul_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> (RadixCiphertext, CiphertextBig) {
+        self.propagate_overflowing_inputs_parallelized(lhs, rhs);
+        self.unchecked_overflowing_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// saturating at the maximum value instead of overflowing.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_saturating_add_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (result, overflowed) = self.smart_overflowing_add_parallelized(lhs, rhs);
+        let max = self.create_trivial_max_radix(result.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &max, &result)
+    }
+
+    /// Computes homomorphically a subtraction between two ciphertexts encrypting integer values,
+    /// saturating at zero instead of overflowing.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_saturating_sub_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (result, overflowed) = self.smart_overflowing_sub_parallelized(lhs, rhs);
+        let zero = self.create_trivial_zero_radix(result.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &zero, &result)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer
+    /// values, saturating at the maximum value instead of overflowing.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 23u8;
+    /// let msg2 = 12u8;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1 as u64);
+    /// let mut ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// // Compute homomorphically a saturating multiplication:
+    /// let ct_res = sks.smart_saturating_mul_parallelized(&mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg1.saturating_mul(msg2) as u64, dec);
+    /// ```
+    pub fn smart_saturating_mul_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (result, overflowed) = self.smart_overflowing_mul_parallelized(lhs, rhs);
+        let max = self.create_trivial_max_radix(result.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &max, &result)
+    }
+}
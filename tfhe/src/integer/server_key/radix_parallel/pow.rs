@@ -0,0 +1,225 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically `ct` raised to the power of a clear exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u32;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.unchecked_scalar_pow_parallelized(&ct, exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent) as u64);
+    /// ```
+    pub fn unchecked_scalar_pow_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        exponent: u32,
+    ) -> RadixCiphertext {
+        let mut result: Option<RadixCiphertext> = None;
+        let mut base = ct.clone();
+        let mut exponent = exponent;
+
+        // Square and multiply, from the least significant bit of the exponent
+        while exponent != 0 {
+            let is_bit_set = exponent & 1 == 1;
+            exponent >>= 1;
+            let needs_square = exponent != 0;
+
+            let (new_result, new_base) = rayon::join(
+                || match (is_bit_set, result.take()) {
+                    (false, result) => result,
+                    (true, None) => Some(base.clone()),
+                    (true, Some(mut result)) => {
+                        Some(self.smart_mul_parallelized(&mut result, &mut base.clone()))
+                    }
+                },
+                || {
+                    needs_square
+                        .then(|| self.smart_mul_parallelized(&mut base.clone(), &mut base.clone()))
+                },
+            );
+            result = new_result;
+            if let Some(new_base) = new_base {
+                base = new_base;
+            }
+        }
+
+        let mut result = result.unwrap_or_else(|| self.create_trivial_radix(1u64, ct.blocks.len()));
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Computes homomorphically `ct` raised to the power of a clear exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u32;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.smart_scalar_pow_parallelized(&mut ct, exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent) as u64);
+    /// ```
+    pub fn smart_scalar_pow_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        exponent: u32,
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_scalar_pow_parallelized(ct, exponent)
+    }
+
+    /// Computes homomorphically `ct` raised to the power of an encrypted exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    /// The exponent may have a different number of blocks than `ct`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// let ct_exponent = cks.encrypt(exponent as u64);
+    ///
+    /// let ct_res = sks.unchecked_pow_parallelized(&ct, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent as u32) as u64);
+    /// ```
+    pub fn unchecked_pow_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let exponent_bits = self.unchecked_radix_to_bits_parallelized(exponent);
+        let num_bits = exponent_bits.len();
+
+        let mut result = self.create_trivial_radix(1u64, ct.blocks.len());
+        let mut base = ct.clone();
+
+        // Square and multiply, from the least significant bit of the exponent,
+        // the multiplication is only kept if the bit is set
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let (product, square) = rayon::join(
+                || {
+                    if i == 0 {
+                        return base.clone();
+                    }
+                    let mut product =
+                        self.smart_mul_parallelized(&mut result.clone(), &mut base.clone());
+                    self.full_propagate_parallelized(&mut product);
+                    product
+                },
+                || {
+                    (i != num_bits - 1).then(|| {
+                        let mut square =
+                            self.smart_mul_parallelized(&mut base.clone(), &mut base.clone());
+                        self.full_propagate_parallelized(&mut square);
+                        square
+                    })
+                },
+            );
+            result = self.unchecked_if_then_else_parallelized(bit, &product, &result);
+            if let Some(square) = square {
+                base = square;
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `ct` raised to the power of an encrypted exponent.
+    ///
+    /// The result wraps around the number of blocks of `ct`.
+    /// The exponent may have a different number of blocks than `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let mut ct = cks.encrypt(msg as u64);
+    /// let mut ct_exponent = cks.encrypt(exponent as u64);
+    ///
+    /// let ct_res = sks.smart_pow_parallelized(&mut ct, &mut ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent as u32) as u64);
+    /// ```
+    pub fn smart_pow_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        exponent: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        rayon::join(
+            || {
+                if has_non_zero_carries(ct) {
+                    self.full_propagate_parallelized(ct);
+                }
+            },
+            || {
+                if has_non_zero_carries(exponent) {
+                    self.full_propagate_parallelized(exponent);
+                }
+            },
+        );
+        self.unchecked_pow_parallelized(ct, exponent)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::ServerKey;
 
 impl ServerKey {
@@ -28,15 +29,22 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add_parallelized(
+    pub fn smart_scalar_add_parallelized<T>(
         &self,
         ct: &mut RadixCiphertext,
-        scalar: u64,
-    ) -> RadixCiphertext {
-        if !self.is_scalar_add_possible(ct, scalar) {
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate_parallelized(ct);
         }
-        self.unchecked_scalar_add(ct, scalar)
+
+        let mut result = ct.clone();
+        self.unchecked_scalar_decomposition_add_assign(&mut result, &decomposition);
+        result
     }
 
     /// Computes homomorphically the addition of ciphertext with a scalar.
@@ -65,10 +73,14 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add_assign_parallelized(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        if !self.is_scalar_add_possible(ct, scalar) {
+    pub fn smart_scalar_add_assign_parallelized<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate_parallelized(ct);
         }
-        self.unchecked_scalar_add_assign(ct, scalar);
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
     }
 }
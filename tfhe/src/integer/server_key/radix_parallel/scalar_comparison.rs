@@ -0,0 +1,184 @@
+use super::ServerKey;
+
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::RadixCiphertext;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    pub fn unchecked_scalar_eq_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_eq_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ne_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_ne_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_gt_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_gt_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ge_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_ge_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_lt_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_lt_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_le_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).unchecked_scalar_le_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_max_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        Comparator::new(self).unchecked_scalar_max_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_min_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        Comparator::new(self).unchecked_scalar_min_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_eq_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_eq_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ne_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_ne_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_gt_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_gt_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_ge_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_ge_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_lt_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_lt_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_le_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> CiphertextBig
+    where
+        T: AsLittleEndianWords,
+    {
+        Comparator::new(self).smart_scalar_le_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_max_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        Comparator::new(self).smart_scalar_max_parallelized(lhs, scalar)
+    }
+
+    pub fn smart_scalar_min_parallelized<T>(
+        &self,
+        lhs: &mut RadixCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords + Sync,
+    {
+        Comparator::new(self).smart_scalar_min_parallelized(lhs, scalar)
+    }
+}
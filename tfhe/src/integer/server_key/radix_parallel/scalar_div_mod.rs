@@ -0,0 +1,410 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::server_key::radix::{modular_inverse, ScalarDivisor};
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes `numerator / divisor` with a multiplication by a magic number,
+    /// see [ScalarDivisor::Multiplier].
+    fn unchecked_scalar_div_by_multiplier_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        multiplier: u64,
+        shift: usize,
+    ) -> RadixCiphertext {
+        let num_blocks = numerator.blocks.len();
+
+        // t = (m * n) >> num_bits, the product is computed on twice as many blocks
+        // so that its msb are not lost
+        let mut extended_numerator = numerator.clone();
+        extended_numerator
+            .blocks
+            .extend((0..num_blocks).map(|_| self.key.create_trivial(0)));
+        let mut product = self.smart_scalar_mul_parallelized(&mut extended_numerator, multiplier);
+        self.full_propagate_parallelized(&mut product);
+        let mut t = RadixCiphertext::from(product.blocks.split_off(num_blocks));
+
+        // q = (t + ((n - t) >> 1)) >> (l - 1)
+        let mut numerator = numerator.clone();
+        // The shifts require empty carries
+        let mut difference = self.smart_sub_parallelized(&mut numerator, &mut t);
+        self.full_propagate_parallelized(&mut difference);
+        let mut half_difference = self.unchecked_scalar_right_shift_parallelized(&difference, 1);
+        let mut quotient = self.smart_add_parallelized(&mut t, &mut half_difference);
+        self.full_propagate_parallelized(&mut quotient);
+        self.unchecked_scalar_right_shift_parallelized(&quotient, shift - 1)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// Divisions by a power of two are shifts, other divisors use a multiplication by a
+    /// precomputed reciprocal when the ciphertext holds at most 64 bits, and a long division
+    /// otherwise.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 7u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let (q, r) = sks.unchecked_scalar_div_rem_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg / scalar);
+    /// assert_eq!(r, msg % scalar);
+    /// ```
+    pub fn unchecked_scalar_div_rem_parallelized<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> (RadixCiphertext, RadixCiphertext)
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = numerator.blocks.len();
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero => (self.create_trivial_max_radix(num_blocks), numerator.clone()),
+            ScalarDivisor::TooLarge => (
+                self.create_trivial_zero_radix(num_blocks),
+                numerator.clone(),
+            ),
+            ScalarDivisor::PowerOfTwo(power) => rayon::join(
+                || self.unchecked_scalar_right_shift_parallelized(numerator, power),
+                || self.unchecked_scalar_low_bits(numerator, power),
+            ),
+            ScalarDivisor::Multiplier {
+                divisor,
+                multiplier,
+                shift,
+            } => {
+                let mut quotient = self
+                    .unchecked_scalar_div_by_multiplier_parallelized(numerator, multiplier, shift);
+                let mut product = self.smart_scalar_mul_parallelized(&mut quotient, divisor);
+                let mut numerator = numerator.clone();
+                let mut remainder = self.smart_sub_parallelized(&mut numerator, &mut product);
+                self.full_propagate_parallelized(&mut remainder);
+                (quotient, remainder)
+            }
+            ScalarDivisor::LongDivision => {
+                let divisor = self.create_trivial_radix(divisor, num_blocks);
+                self.unchecked_div_rem_parallelized(numerator, &divisor)
+            }
+        }
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar.
+    ///
+    /// See [Self::unchecked_scalar_div_rem_parallelized] for the algorithms used.
+    ///
+    /// If the divisor is zero, the result is the maximum value that can be represented
+    /// (all bits set to 1).
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 16u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let ct_res = sks.unchecked_scalar_div_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn unchecked_scalar_div_parallelized<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_blocks = numerator.blocks.len();
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero => self.create_trivial_max_radix(num_blocks),
+            ScalarDivisor::TooLarge => self.create_trivial_zero_radix(num_blocks),
+            ScalarDivisor::PowerOfTwo(power) => {
+                self.unchecked_scalar_right_shift_parallelized(numerator, power)
+            }
+            ScalarDivisor::Multiplier {
+                multiplier, shift, ..
+            } => self.unchecked_scalar_div_by_multiplier_parallelized(numerator, multiplier, shift),
+            ScalarDivisor::LongDivision => {
+                let divisor = self.create_trivial_radix(divisor, num_blocks);
+                self.unchecked_div_rem_parallelized(numerator, &divisor).0
+            }
+        }
+    }
+
+    /// Computes homomorphically the remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the numerator.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 32u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar remainder:
+    /// let ct_res = sks.unchecked_scalar_rem_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg % scalar);
+    /// ```
+    pub fn unchecked_scalar_rem_parallelized<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        match ScalarDivisor::new(&divisor, self.radix_message_bits(numerator)) {
+            ScalarDivisor::Zero | ScalarDivisor::TooLarge => numerator.clone(),
+            ScalarDivisor::PowerOfTwo(power) => self.unchecked_scalar_low_bits(numerator, power),
+            _ => {
+                self.unchecked_scalar_div_rem_parallelized(numerator, divisor)
+                    .1
+            }
+        }
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar,
+    /// knowing that the division is exact (the remainder is zero).
+    ///
+    /// If the division is not exact, the result is meaningless.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 228;
+    /// let scalar = 12u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an exact scalar division:
+    /// let ct_res = sks.unchecked_scalar_div_exact_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn unchecked_scalar_div_exact_parallelized<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let num_bits = self.radix_message_bits(numerator);
+        match ScalarDivisor::new(&divisor, num_bits) {
+            ScalarDivisor::Zero => panic!("attempt to divide by zero"),
+            ScalarDivisor::Multiplier { divisor, .. } => {
+                let power = divisor.trailing_zeros() as usize;
+                let odd_divisor = divisor >> power;
+                let mut shifted = self.unchecked_scalar_right_shift_parallelized(numerator, power);
+                if odd_divisor == 1 {
+                    return shifted;
+                }
+                let mut result = self.smart_scalar_mul_parallelized(
+                    &mut shifted,
+                    modular_inverse(odd_divisor, num_bits),
+                );
+                self.full_propagate_parallelized(&mut result);
+                result
+            }
+            _ => self.unchecked_scalar_div_parallelized(numerator, divisor),
+        }
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the quotient is the maximum value that can be represented
+    /// (all bits set to 1) and the remainder is the numerator.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let (q, r) = sks.smart_scalar_div_rem_parallelized(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let q = cks.decrypt(&q);
+    /// let r = cks.decrypt(&r);
+    /// assert_eq!(q, msg / scalar);
+    /// assert_eq!(r, msg % scalar);
+    /// ```
+    pub fn smart_scalar_div_rem_parallelized<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> (RadixCiphertext, RadixCiphertext)
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate_parallelized(numerator);
+        }
+        self.unchecked_scalar_div_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient of the division of a ciphertext by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the maximum value that can be represented
+    /// (all bits set to 1).
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar division:
+    /// let ct_res = sks.smart_scalar_div_parallelized(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / scalar);
+    /// ```
+    pub fn smart_scalar_div_parallelized<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate_parallelized(numerator);
+        }
+        self.unchecked_scalar_div_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the remainder of the division of a ciphertext
+    /// by a clear scalar.
+    ///
+    /// If the divisor is zero, the result is the numerator.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230;
+    /// let scalar = 9u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a scalar remainder:
+    /// let ct_res = sks.smart_scalar_rem_parallelized(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg % scalar);
+    /// ```
+    pub fn smart_scalar_rem_parallelized<T>(
+        &self,
+        numerator: &mut RadixCiphertext,
+        divisor: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        if has_non_zero_carries(numerator) {
+            self.full_propagate_parallelized(numerator);
+        }
+        self.unchecked_scalar_rem_parallelized(numerator, divisor)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
@@ -250,17 +251,20 @@ impl ServerKey {
     /// let clear = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
-    pub fn smart_scalar_mul_parallelized(
+    pub fn smart_scalar_mul_parallelized<T>(
         &self,
         ct: &mut RadixCiphertext,
-        scalar: u64,
-    ) -> RadixCiphertext {
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
         let zero = self.create_trivial_zero_radix(ct.blocks.len());
-        if scalar == 0 || ct.blocks.is_empty() {
+        let decomposition = self.scalar_block_decomposition(&scalar, ct.blocks.len());
+        if decomposition.iter().all(|&u_i| u_i == 0) {
             return zero;
         }
 
-        let b = self.key.message_modulus.0 as u64;
         let n = ct.blocks.len();
 
         //Propagate the carries before doing the multiplications
@@ -270,12 +274,8 @@ impl ServerKey {
         // key is the small scalar we multiply by
         // value is the vector of blockshifts
         let mut task_map = HashMap::<u64, Vec<usize>>::new();
-
-        let mut b_i = 1_u64;
-        for i in 0..n {
-            let u_i = (scalar / b_i) % b;
+        for (i, u_i) in decomposition.into_iter().enumerate() {
             task_map.entry(u_i).or_insert_with(Vec::new).push(i);
-            b_i *= b;
         }
 
         let terms = Mutex::new(Vec::<RadixCiphertext>::new());
@@ -305,7 +305,10 @@ impl ServerKey {
             .unwrap_or(zero)
     }
 
-    pub fn smart_scalar_mul_assign_parallelized(&self, ctxt: &mut RadixCiphertext, scalar: u64) {
+    pub fn smart_scalar_mul_assign_parallelized<T>(&self, ctxt: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
         *ctxt = self.smart_scalar_mul_parallelized(ctxt, scalar);
     }
 }
@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::ServerKey;
 
 impl ServerKey {
@@ -26,21 +27,32 @@ impl ServerKey {
     /// let dec = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn smart_scalar_sub_parallelized(
+    pub fn smart_scalar_sub_parallelized<T>(
         &self,
         ct: &mut RadixCiphertext,
-        scalar: u64,
-    ) -> RadixCiphertext {
-        if !self.is_scalar_sub_possible(ct, scalar) {
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate_parallelized(ct);
         }
-        self.unchecked_scalar_sub(ct, scalar)
+
+        let mut result = ct.clone();
+        self.unchecked_scalar_decomposition_add_assign(&mut result, &decomposition);
+        result
     }
 
-    pub fn smart_scalar_sub_assign_parallelized(&self, ct: &mut RadixCiphertext, scalar: u64) {
-        if !self.is_scalar_sub_possible(ct, scalar) {
+    pub fn smart_scalar_sub_assign_parallelized<T>(&self, ct: &mut RadixCiphertext, scalar: T)
+    where
+        T: AsLittleEndianWords,
+    {
+        let decomposition = self.scalar_negated_block_decomposition(&scalar, ct.blocks.len());
+        if !self.is_scalar_decomposition_add_possible(ct, &decomposition) {
             self.full_propagate_parallelized(ct);
         }
-        self.unchecked_scalar_sub_assign(ct, scalar);
+        self.unchecked_scalar_decomposition_add_assign(ct, &decomposition);
     }
 }
@@ -1,5 +1,9 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::server_key::radix::BarrelShiftKind;
 use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
 
 impl ServerKey {
     /// Computes homomorphically a right shift.
@@ -100,7 +104,7 @@ impl ServerKey {
 
     /// Propagates all carries except the last one.
     /// For development purpose only.
-    fn partial_propagate_parallelized(&self, ctxt: &mut RadixCiphertext) {
+    pub(crate) fn partial_propagate_parallelized(&self, ctxt: &mut RadixCiphertext) {
         let len = ctxt.blocks.len() - 1;
         for i in 0..len {
             self.propagate_parallelized(ctxt, i);
@@ -177,4 +181,381 @@ impl ServerKey {
         let tmp = 1_u64 << shift;
         self.smart_scalar_mul_assign_parallelized(ct, tmp);
     }
+
+    /// Computes homomorphically a left rotation by a scalar.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a left rotation:
+    /// let ct_res = sks.unchecked_scalar_rotate_left_parallelized(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_left(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_scalar_rotate_left_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        n: usize,
+    ) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_left_assign_parallelized(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a left rotation by a scalar.
+    ///
+    /// The result is assigned in the input ciphertext
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_scalar_rotate_left_assign_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: usize,
+    ) {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        //number of bits of message
+        let nb_bits = (message_modulus as f64).log2() as usize;
+        let num_blocks = ct.blocks.len();
+        let n = n % (nb_bits * num_blocks);
+
+        ct.blocks.rotate_right(n / nb_bits);
+        let shift_within_block = n % nb_bits;
+        if shift_within_block == 0 {
+            return;
+        }
+
+        let acc = self
+            .key
+            .generate_accumulator_bivariate(|current, previous| {
+                ((current << shift_within_block) | (previous >> (nb_bits - shift_within_block)))
+                    % message_modulus
+            });
+        ct.blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|i| {
+                let previous = &ct.blocks[(i + num_blocks - 1) % num_blocks];
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[i], previous, &acc)
+            })
+            .collect();
+    }
+
+    /// Computes homomorphically a right rotation by a scalar.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a right rotation:
+    /// let ct_res = sks.unchecked_scalar_rotate_right_parallelized(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_scalar_rotate_right_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        n: usize,
+    ) -> RadixCiphertext {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_right_assign_parallelized(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a right rotation by a scalar.
+    ///
+    /// The result is assigned in the input ciphertext
+    ///
+    /// Requires carry bits to be empty.
+    pub fn unchecked_scalar_rotate_right_assign_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: usize,
+    ) {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_bits = nb_bits * ct.blocks.len();
+        self.unchecked_scalar_rotate_left_assign_parallelized(ct, num_bits - (n % num_bits));
+    }
+
+    /// Computes homomorphically a left shift by an encrypted amount.
+    ///
+    /// The bits shifted out are lost, if the amount is greater or equal to the number of bits
+    /// of the ciphertext the result is zero.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 21;
+    /// let shift = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_shift = cks.encrypt(shift);
+    ///
+    /// // Compute homomorphically a left shift:
+    /// let ct_res = sks.unchecked_left_shift_parallelized(&ct, &ct_shift);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!((msg << shift) % 256, dec);
+    /// ```
+    pub fn unchecked_left_shift_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift_parallelized(ct, shift, BarrelShiftKind::LeftShift)
+    }
+
+    /// Computes homomorphically a right shift by an encrypted amount.
+    ///
+    /// If the amount is greater or equal to the number of bits of the ciphertext
+    /// the result is zero.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    pub fn unchecked_right_shift_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift_parallelized(ct, shift, BarrelShiftKind::RightShift)
+    }
+
+    /// Computes homomorphically a left rotation by an encrypted amount.
+    ///
+    /// The amount is taken modulo the number of bits of the ciphertext.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    pub fn unchecked_rotate_left_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        n: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift_parallelized(ct, n, BarrelShiftKind::LeftRotation)
+    }
+
+    /// Computes homomorphically a right rotation by an encrypted amount.
+    ///
+    /// The amount is taken modulo the number of bits of the ciphertext.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message space + carry space is inferior to 3 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0b1011_0001u8;
+    /// let n = 5;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// let ct_n = cks.encrypt(n);
+    ///
+    /// // Compute homomorphically a right rotation:
+    /// let ct_res = sks.unchecked_rotate_right_parallelized(&ct, &ct_n);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(n as u32) as u64, dec);
+    /// ```
+    pub fn unchecked_rotate_right_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        n: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.unchecked_barrel_shift_parallelized(ct, n, BarrelShiftKind::RightRotation)
+    }
+
+    /// Computes homomorphically a left shift by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_left_shift_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        shift: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs_parallelized(ct, shift);
+        self.unchecked_left_shift_parallelized(ct, shift)
+    }
+
+    /// Computes homomorphically a right shift by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_right_shift_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        shift: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs_parallelized(ct, shift);
+        self.unchecked_right_shift_parallelized(ct, shift)
+    }
+
+    /// Computes homomorphically a left rotation by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_rotate_left_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs_parallelized(ct, n);
+        self.unchecked_rotate_left_parallelized(ct, n)
+    }
+
+    /// Computes homomorphically a right rotation by an encrypted amount.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    pub fn smart_rotate_right_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        n: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.propagate_barrel_shift_inputs_parallelized(ct, n);
+        self.unchecked_rotate_right_parallelized(ct, n)
+    }
+
+    fn propagate_barrel_shift_inputs_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        amount: &mut RadixCiphertext,
+    ) {
+        rayon::join(
+            || {
+                if has_non_zero_carries(ct) {
+                    self.full_propagate_parallelized(ct);
+                }
+            },
+            || {
+                if has_non_zero_carries(amount) {
+                    self.full_propagate_parallelized(amount);
+                }
+            },
+        );
+    }
+
+    fn unchecked_barrel_shift_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        amount: &RadixCiphertext,
+        kind: BarrelShiftKind,
+    ) -> RadixCiphertext {
+        let (mut bits, amount_bits) = rayon::join(
+            || self.unchecked_radix_to_bits_parallelized(ct),
+            || self.unchecked_radix_to_bits_parallelized(amount),
+        );
+        let num_bits = bits.len();
+
+        let acc = self.bit_mux_accumulator();
+        let zero = self.key.create_trivial(0);
+
+        let mut shifted_out_selectors = Vec::new();
+        for (stage, selector) in amount_bits.iter().enumerate() {
+            let Some(stage_amount) = kind.stage_amount(stage, num_bits) else {
+                shifted_out_selectors.push(selector.clone());
+                continue;
+            };
+            if stage_amount == 0 {
+                continue;
+            }
+
+            bits = (0..num_bits)
+                .into_par_iter()
+                .map(|i| {
+                    let moved_bit = kind
+                        .source_bit(i, stage_amount, num_bits)
+                        .map_or(&zero, |source| &bits[source]);
+                    self.unchecked_bit_mux(selector, moved_bit, &bits[i], &acc)
+                })
+                .collect();
+        }
+
+        if !shifted_out_selectors.is_empty() {
+            let is_shifted_out = self.unchecked_any_bit_set(&shifted_out_selectors);
+            bits.par_iter_mut().for_each(|bit| {
+                *bit = self.unchecked_bit_mux(&is_shifted_out, &zero, bit, &acc);
+            });
+        }
+
+        self.unchecked_bits_to_radix(&bits)
+    }
+
+    /// Extracts each bit of the ciphertext in its own block, from the least significant one.
+    ///
+    /// Requires carry bits to be empty.
+    pub(crate) fn unchecked_radix_to_bits_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+    ) -> Vec<CiphertextBig> {
+        let nb_bits = (self.key.message_modulus.0 as f64).log2() as usize;
+        let accs = (0..nb_bits)
+            .map(|i| self.key.generate_accumulator(|x| (x >> i) & 1))
+            .collect::<Vec<_>>();
+
+        (0..ct.blocks.len() * nb_bits)
+            .into_par_iter()
+            .map(|i| {
+                self.key
+                    .apply_lookup_table(&ct.blocks[i / nb_bits], &accs[i % nb_bits])
+            })
+            .collect()
+    }
 }
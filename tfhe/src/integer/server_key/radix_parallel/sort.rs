@@ -0,0 +1,323 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::{has_non_zero_carries, Comparator};
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+/// Returns the layers of Batcher's odd-even merge sorting network for `n` elements.
+///
+/// The pairs of a layer are disjoint, so they can be compare-exchanged in parallel.
+/// When `n` is not a power of two, the comparators involving missing elements are dropped,
+/// which is the same as padding the input with elements greater than all the others.
+fn odd_even_merge_sort_layers(n: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut layers = Vec::new();
+    let mut p = 1;
+    while p < n {
+        let mut k = p;
+        while k >= 1 {
+            let mut layer = Vec::new();
+            let mut j = k % p;
+            while j + k < n {
+                for i in 0..k.min(n - j - k) {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        layer.push((i + j, i + j + k));
+                    }
+                }
+                j += 2 * k;
+            }
+            if !layer.is_empty() {
+                layers.push(layer);
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+    layers
+}
+
+impl ServerKey {
+    /// Propagates in parallel the carries of the ciphertexts that have some.
+    fn propagate_all_parallelized(&self, ciphertexts: &mut [RadixCiphertext]) {
+        assert!(
+            ciphertexts
+                .windows(2)
+                .all(|pair| pair[0].blocks.len() == pair[1].blocks.len()),
+            "all the ciphertexts must have the same number of blocks"
+        );
+        ciphertexts.par_iter_mut().for_each(|ct| {
+            if has_non_zero_carries(ct) {
+                self.full_propagate_parallelized(ct);
+            }
+        });
+    }
+
+    /// Sorts the ciphertexts in increasing order with a sorting network.
+    ///
+    /// Each compare-exchange computes the comparison once, and uses it to select both
+    /// the min and the max.
+    fn unchecked_sort_with_comparator_parallelized(
+        &self,
+        comparator: &Comparator,
+        ciphertexts: &mut [RadixCiphertext],
+    ) {
+        for layer in odd_even_merge_sort_layers(ciphertexts.len()) {
+            let sorted_pairs = layer
+                .par_iter()
+                .map(|&(i, j)| {
+                    comparator.unchecked_min_max_parallelized(&ciphertexts[i], &ciphertexts[j])
+                })
+                .collect::<Vec<_>>();
+            for (&(i, j), (min, max)) in layer.iter().zip(sorted_pairs) {
+                ciphertexts[i] = min;
+                ciphertexts[j] = max;
+            }
+        }
+    }
+
+    /// Sorts homomorphically the ciphertexts in increasing order.
+    ///
+    /// The ciphertexts are sorted with Batcher's odd-even merge sorting network,
+    /// the compare-exchanges of a layer of the network being computed in parallel.
+    /// The carries of the ciphertexts are propagated if needed.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [97u64, 14, 201, 3, 56];
+    ///
+    /// let mut cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// sks.sort_parallelized(&mut cts);
+    ///
+    /// // Decrypt:
+    /// let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec, [3, 14, 56, 97, 201]);
+    /// ```
+    pub fn sort_parallelized(&self, ciphertexts: &mut [RadixCiphertext]) {
+        self.propagate_all_parallelized(ciphertexts);
+        let comparator = Comparator::new(self);
+        self.unchecked_sort_with_comparator_parallelized(&comparator, ciphertexts);
+    }
+
+    /// Returns the index of the ciphertext selected by a tournament.
+    ///
+    /// `keep_lhs` returns a block encrypting 1 if the left candidate wins the match.
+    /// As the left candidate always has the lowest index, returning 1 on ties selects
+    /// the first of the equal candidates.
+    fn unchecked_arg_select_parallelized<F>(
+        &self,
+        ciphertexts: &[RadixCiphertext],
+        keep_lhs: F,
+    ) -> Option<RadixCiphertext>
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> CiphertextBig + Sync,
+    {
+        if ciphertexts.is_empty() {
+            return None;
+        }
+
+        // Enough blocks to encrypt the greatest index
+        let message_modulus = self.key.message_modulus.0;
+        let mut num_index_blocks = 1;
+        let mut capacity = message_modulus;
+        while capacity < ciphertexts.len() {
+            capacity *= message_modulus;
+            num_index_blocks += 1;
+        }
+
+        let mut candidates = ciphertexts
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                (
+                    ct.clone(),
+                    self.create_trivial_radix(i as u64, num_index_blocks),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        while candidates.len() > 1 {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [(lhs, lhs_index), (rhs, rhs_index)] => {
+                        let condition = keep_lhs(lhs, rhs);
+                        rayon::join(
+                            || self.unchecked_if_then_else_parallelized(&condition, lhs, rhs),
+                            || {
+                                self.unchecked_if_then_else_parallelized(
+                                    &condition, lhs_index, rhs_index,
+                                )
+                            },
+                        )
+                    }
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        candidates.pop().map(|(_, index)| index)
+    }
+
+    /// Computes homomorphically the index of the greatest ciphertext.
+    ///
+    /// If several ciphertexts are equal to the max, the lowest index is returned.
+    /// The carries of the ciphertexts are propagated if needed.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [97u64, 14, 201, 3, 201];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmax_parallelized(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn argmax_parallelized(&self, ciphertexts: &[RadixCiphertext]) -> Option<RadixCiphertext> {
+        let mut ciphertexts = ciphertexts.to_vec();
+        self.propagate_all_parallelized(&mut ciphertexts);
+        let comparator = Comparator::new(self);
+        self.unchecked_arg_select_parallelized(&ciphertexts, |lhs, rhs| {
+            comparator.unchecked_ge_as_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the index of the smallest ciphertext.
+    ///
+    /// If several ciphertexts are equal to the min, the lowest index is returned.
+    /// The carries of the ciphertexts are propagated if needed.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [97u64, 14, 201, 3, 56];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmin_parallelized(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 3);
+    /// ```
+    pub fn argmin_parallelized(&self, ciphertexts: &[RadixCiphertext]) -> Option<RadixCiphertext> {
+        let mut ciphertexts = ciphertexts.to_vec();
+        self.propagate_all_parallelized(&mut ciphertexts);
+        let comparator = Comparator::new(self);
+        self.unchecked_arg_select_parallelized(&ciphertexts, |lhs, rhs| {
+            comparator.unchecked_le_as_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the `k` greatest ciphertexts, in decreasing order.
+    ///
+    /// The ciphertexts are processed by chunks of `k`: each chunk is sorted and merged
+    /// with the current best candidates, so that only sorting networks of `k` elements
+    /// are needed.
+    /// The carries of the ciphertexts are propagated if needed.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    /// If there are less than `k` ciphertexts, all of them are returned.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [97u64, 14, 201, 3, 56];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let top = sks.top_k_parallelized(&cts, 2);
+    ///
+    /// // Decrypt:
+    /// let dec = top.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec, [201, 97]);
+    /// ```
+    pub fn top_k_parallelized(
+        &self,
+        ciphertexts: &[RadixCiphertext],
+        k: usize,
+    ) -> Vec<RadixCiphertext> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut best = ciphertexts.to_vec();
+        self.propagate_all_parallelized(&mut best);
+        let comparator = Comparator::new(self);
+
+        let mut others = best.split_off(k.min(best.len()));
+        self.unchecked_sort_with_comparator_parallelized(&comparator, &mut best);
+
+        for chunk in others.chunks_mut(k) {
+            self.unchecked_sort_with_comparator_parallelized(&comparator, chunk);
+            // Both lists being sorted, the pairwise max of the first one and of the reversed
+            // second one are the greatest elements of their union
+            best[..chunk.len()]
+                .par_iter_mut()
+                .zip(chunk.par_iter().rev())
+                .for_each(|(candidate, challenger)| {
+                    *candidate = comparator.unchecked_max_parallelized(candidate, challenger);
+                });
+            self.unchecked_sort_with_comparator_parallelized(&comparator, &mut best);
+        }
+
+        best.reverse();
+        best
+    }
+}
@@ -0,0 +1,100 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+
+impl ServerKey {
+    /// Computes homomorphically the square root of the ciphertext, rounded down.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_sqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14);
+    /// ```
+    pub fn unchecked_sqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let work_blocks = self.sqrt_work_blocks(num_blocks);
+        let bits = self.unchecked_radix_to_bits_parallelized(ct);
+        let num_steps = (bits.len() + 1) / 2;
+
+        // Digit by digit algorithm, in base 2:
+        // the root is built one bit at a time, from the most significant one
+        let mut root_bits = Vec::<CiphertextBig>::with_capacity(num_steps);
+        let mut remainder = self.create_trivial_zero_radix(work_blocks);
+        for step in (0..num_steps).rev() {
+            // remainder = 4 * remainder + next two bits of the input
+            let mut shifted = self.unchecked_scalar_left_shift_parallelized(&remainder, 2);
+            let next_bits = self.sqrt_next_bits(&bits, step);
+            let mut next_bits = self.block_to_radix(next_bits, work_blocks);
+            remainder = self.smart_add_parallelized(&mut shifted, &mut next_bits);
+            self.full_propagate_parallelized(&mut remainder);
+
+            // trial = 4 * root + 1
+            let mut trial_bits = vec![self.key.create_trivial(1), self.key.create_trivial(0)];
+            trial_bits.extend(root_bits.iter().cloned());
+            let trial = self.bits_to_padded_radix(&trial_bits, work_blocks);
+
+            let (is_root_bit_set, difference) = rayon::join(
+                || self.unchecked_ge_as_block_parallelized(&remainder, &trial),
+                || {
+                    let mut difference =
+                        self.smart_sub_parallelized(&mut remainder.clone(), &mut trial.clone());
+                    self.full_propagate_parallelized(&mut difference);
+                    difference
+                },
+            );
+            remainder =
+                self.unchecked_if_then_else_parallelized(&is_root_bit_set, &difference, &remainder);
+
+            root_bits.insert(0, is_root_bit_set);
+        }
+
+        self.bits_to_padded_radix(&root_bits, num_blocks)
+    }
+
+    /// Computes homomorphically the square root of the ciphertext, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_sqrt_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14);
+    /// ```
+    pub fn smart_sqrt_parallelized(&self, ct: &mut RadixCiphertext) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_sqrt_parallelized(ct)
+    }
+}
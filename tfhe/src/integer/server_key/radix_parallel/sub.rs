@@ -35,14 +35,14 @@ impl ServerKey {
     ) -> RadixCiphertext {
         // If the ciphertext cannot be negated without exceeding the capacity of a ciphertext
         if !self.is_neg_possible(ctxt_right) {
-            self.full_propagate_parallelized(ctxt_right);
+            self.full_propagate_parallelized_low_latency(ctxt_right);
         }
 
         // If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !self.is_sub_possible(ctxt_left, ctxt_right) {
             rayon::join(
-                || self.full_propagate_parallelized(ctxt_left),
-                || self.full_propagate_parallelized(ctxt_right),
+                || self.full_propagate_parallelized_low_latency(ctxt_left),
+                || self.full_propagate_parallelized_low_latency(ctxt_right),
             );
         }
 
@@ -85,14 +85,14 @@ impl ServerKey {
     ) {
         // If the ciphertext cannot be negated without exceeding the capacity of a ciphertext
         if !self.is_neg_possible(ctxt_right) {
-            self.full_propagate_parallelized(ctxt_right);
+            self.full_propagate_parallelized_low_latency(ctxt_right);
         }
 
         // If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !self.is_sub_possible(ctxt_left, ctxt_right) {
             rayon::join(
-                || self.full_propagate_parallelized(ctxt_left),
-                || self.full_propagate_parallelized(ctxt_right),
+                || self.full_propagate_parallelized_low_latency(ctxt_left),
+                || self.full_propagate_parallelized_low_latency(ctxt_right),
             );
         }
 
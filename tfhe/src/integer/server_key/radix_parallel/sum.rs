@@ -0,0 +1,170 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically the sum of all the ciphertexts.
+    ///
+    /// The ciphertexts are added with unchecked additions as long as the carry space allows,
+    /// and when it is full, each partial sum is reduced by splitting the message and the carry
+    /// of its blocks. The carries are only propagated once, at the end.
+    ///
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [14u64, 97, 201, 3, 56];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.sum_ciphertexts_parallelized(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msgs.iter().sum::<u64>() % 256);
+    /// ```
+    pub fn sum_ciphertexts_parallelized(
+        &self,
+        ciphertexts: &[RadixCiphertext],
+    ) -> Option<RadixCiphertext> {
+        let num_blocks = ciphertexts.first()?.blocks.len();
+        assert!(
+            ciphertexts.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "all the ciphertexts must have the same number of blocks"
+        );
+
+        let mut terms = ciphertexts.to_vec();
+        let mut result = loop {
+            let mut sums = self
+                .group_terms_for_sum(terms)
+                .into_par_iter()
+                .map(|group| {
+                    let mut group = group.into_iter();
+                    let mut sum = group.next().unwrap();
+                    for term in group {
+                        self.unchecked_add_assign(&mut sum, &term);
+                    }
+                    sum
+                })
+                .collect::<Vec<_>>();
+
+            if sums.len() == 1 {
+                break sums.pop().unwrap();
+            }
+
+            terms = sums
+                .par_iter()
+                .map(|sum| self.carry_save_reduce_parallelized(sum))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|(message, carries)| std::iter::once(message).chain(carries))
+                .collect();
+        };
+
+        self.full_propagate_parallelized_low_latency(&mut result);
+        Some(result)
+    }
+
+    /// Splits each block of the ciphertext in its message and its carry.
+    fn carry_save_reduce_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+    ) -> (RadixCiphertext, Option<RadixCiphertext>) {
+        let (messages, carries) = ct
+            .blocks
+            .par_iter()
+            .map(|block| {
+                if block.degree.0 < self.key.message_modulus.0 {
+                    (block.clone(), None)
+                } else {
+                    let (message, carry) = rayon::join(
+                        || self.key.message_extract(block),
+                        || self.key.carry_extract(block),
+                    );
+                    (message, Some(carry))
+                }
+            })
+            .unzip();
+        self.carry_save_terms(ct, messages, carries)
+    }
+
+    /// Computes homomorphically the sum of the ciphertexts, each multiplied by its clear weight.
+    ///
+    /// The result wraps around the number of blocks of the ciphertexts.
+    /// All the ciphertexts must have the same number of blocks.
+    /// Returns `None` if there are no ciphertexts.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msgs = [14u64, 97, 201];
+    /// let weights = [3u64, 0, 17];
+    ///
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks
+    ///     .dot_product_with_clear_parallelized(&cts, &weights)
+    ///     .unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// let expected: u64 = msgs.iter().zip(weights.iter()).map(|(m, w)| m * w).sum();
+    /// assert_eq!(dec, expected % 256);
+    /// ```
+    pub fn dot_product_with_clear_parallelized(
+        &self,
+        ciphertexts: &[RadixCiphertext],
+        weights: &[u64],
+    ) -> Option<RadixCiphertext> {
+        assert_eq!(
+            ciphertexts.len(),
+            weights.len(),
+            "there must be as many weights as ciphertexts"
+        );
+        let num_blocks = ciphertexts.first()?.blocks.len();
+
+        let terms = ciphertexts
+            .par_iter()
+            .zip(weights.par_iter())
+            .flat_map(|(ct, &weight)| {
+                if has_non_zero_carries(ct) {
+                    let mut ct = ct.clone();
+                    self.full_propagate_parallelized_low_latency(&mut ct);
+                    self.weighted_terms(&ct, weight)
+                } else {
+                    self.weighted_terms(ct, weight)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if terms.is_empty() {
+            return Some(self.create_trivial_zero_radix(num_blocks));
+        }
+        self.sum_ciphertexts_parallelized(&terms)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{RadixClientKey, ServerKey};
+use crate::integer::{RadixClientKey, ServerKey, U256};
 use crate::shortint::parameters::*;
 use crate::shortint::Parameters;
 use paste::paste;
@@ -12,6 +12,9 @@ const NB_TEST: usize = 30;
 /// meant for test where the function tested is more expensive
 const NB_TEST_SMALLER: usize = 10;
 const NB_CTXT: usize = 4;
+/// Number of loop iteration within the univariate function tests,
+/// which need in the order of message_modulus^(NB_CTXT - 2) PBS per block
+const NB_TEST_UNIVARIATE: usize = 2;
 
 macro_rules! create_parametrized_test{
     ($name:ident { $($param:ident),* }) => {
@@ -36,7 +39,16 @@ macro_rules! create_parametrized_test{
 }
 
 create_parametrized_test!(integer_smart_add);
+create_parametrized_test!(integer_full_propagate_low_latency {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 create_parametrized_test!(integer_smart_add_sequence_multi_thread);
+create_parametrized_test!(integer_sum_dot_product {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 create_parametrized_test!(integer_smart_add_sequence_single_thread);
 create_parametrized_test!(integer_smart_bitand);
 create_parametrized_test!(integer_smart_bitor);
@@ -46,13 +58,92 @@ create_parametrized_test!(integer_smart_small_scalar_mul);
 create_parametrized_test!(integer_smart_scalar_mul);
 create_parametrized_test!(integer_unchecked_scalar_left_shift);
 create_parametrized_test!(integer_unchecked_scalar_right_shift);
+create_parametrized_test!(integer_unchecked_scalar_rotate {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_shift_rotate {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_if_then_else {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_overflowing_saturating {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 create_parametrized_test!(integer_smart_neg);
 create_parametrized_test!(integer_smart_sub);
 create_parametrized_test!(integer_unchecked_block_mul);
 create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_smart_mul);
+create_parametrized_test!(integer_smart_widening_mul {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_scalar_div_rem {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_signed_overflowing_add_sub {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_signed_abs_shift_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_pow_sqrt {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_bit_count {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_comparisons_as_block {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_scalar_comparisons {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_apply_univariate_function {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_apply_univariate_function {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_unchecked_apply_lookup_table_radix {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_smart_apply_lookup_table_radix {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_oblivious_read_write {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+create_parametrized_test!(integer_sort_argmax_top_k {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
+create_parametrized_test!(integer_smart_scalar_ops_u256 {
+    PARAM_MESSAGE_2_CARRY_2
+});
 
 fn integer_smart_add(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -98,6 +189,88 @@ fn integer_smart_add(param: Parameters) {
     }
 }
 
+fn integer_full_propagate_low_latency(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // Carries of at most 1 per block
+        let mut ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let mut clear = (clear_0 + clear_1) % modulus;
+        sks.full_propagate_parallelized_low_latency(&mut ct_res);
+        assert_eq!(clear, cks.decrypt(&ct_res));
+        assert!(ct_res
+            .blocks
+            .iter()
+            .all(|block| block.degree.0 < param.message_modulus.0));
+
+        // Fill the carries so that blocks hold carries bigger than 1
+        let mut ct_res = ctxt_0.clone();
+        clear = clear_0;
+        while sks.is_add_possible(&ct_res, &ctxt_1) {
+            sks.unchecked_add_assign(&mut ct_res, &ctxt_1);
+            clear = (clear + clear_1) % modulus;
+        }
+        sks.full_propagate_parallelized_low_latency(&mut ct_res);
+        assert_eq!(clear, cks.decrypt(&ct_res));
+    }
+}
+
+fn integer_sum_dot_product(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let num_ct = rng.gen_range(1..16);
+        let clears = (0..num_ct)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let weights = (0..num_ct)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let mut cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+        // Fill the carries of the first ciphertext
+        let ct_0 = cts[0].clone();
+        sks.unchecked_add_assign(&mut cts[0], &ct_0);
+        let mut clears = clears;
+        clears[0] = (2 * clears[0]) % modulus;
+
+        let ct_res = sks.sum_ciphertexts_parallelized(&cts).unwrap();
+        assert_eq!(clears.iter().sum::<u64>() % modulus, cks.decrypt(&ct_res));
+
+        let ct_res = sks
+            .dot_product_with_clear_parallelized(&cts, &weights)
+            .unwrap();
+        let expected = clears
+            .iter()
+            .zip(weights.iter())
+            .fold(0, |acc, (clear, weight)| (acc + clear * weight) % modulus);
+        assert_eq!(expected, cks.decrypt(&ct_res));
+    }
+}
+
 fn integer_smart_add_sequence_multi_thread(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -476,6 +649,365 @@ fn integer_unchecked_scalar_right_shift(param: Parameters) {
     }
 }
 
+/// Rotates the `nb_bits` lsb of `value` to the left
+fn clear_rotate_left(value: u64, n: usize, nb_bits: usize) -> u64 {
+    let n = n % nb_bits;
+    let mask = (1u64 << nb_bits) - 1;
+    ((value << n) | (value >> (nb_bits - n))) & mask
+}
+
+fn integer_unchecked_scalar_rotate(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let nb_bits = (param.message_modulus.0 as f64).log2() as usize * NB_CTXT;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let scalar = rng.gen::<usize>() % (2 * nb_bits);
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.unchecked_scalar_rotate_left_parallelized(&ct, scalar);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(clear_rotate_left(clear, scalar, nb_bits), dec_res);
+
+        let ct_res = sks.unchecked_scalar_rotate_right_parallelized(&ct, scalar);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(
+            clear_rotate_left(clear, nb_bits - scalar % nb_bits, nb_bits),
+            dec_res
+        );
+    }
+}
+
+fn integer_smart_shift_rotate(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let nb_bits = (param.message_modulus.0 as f64).log2() as usize * NB_CTXT;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        // Amounts greater than the number of bits are also tested
+        let n = rng.gen::<u64>() % (2 * nb_bits as u64);
+
+        let mut ct = cks.encrypt(clear);
+        let mut ct_n = cks.encrypt(n);
+
+        let ct_res = sks.smart_left_shift_parallelized(&mut ct, &mut ct_n);
+        let dec_res = cks.decrypt(&ct_res);
+        let expected = if n < nb_bits as u64 {
+            (clear << n) % modulus
+        } else {
+            0
+        };
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.smart_right_shift_parallelized(&mut ct, &mut ct_n);
+        let dec_res = cks.decrypt(&ct_res);
+        let expected = if n < nb_bits as u64 { clear >> n } else { 0 };
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.smart_rotate_left_parallelized(&mut ct, &mut ct_n);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(clear_rotate_left(clear, n as usize, nb_bits), dec_res);
+
+        let ct_res = sks.smart_rotate_right_parallelized(&mut ct, &mut ct_n);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(
+            clear_rotate_left(clear, nb_bits - n as usize % nb_bits, nb_bits),
+            dec_res
+        );
+    }
+}
+
+fn integer_smart_if_then_else(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = signed_wrap(rng.gen::<i64>(), num_bits);
+        let clear_1 = signed_wrap(rng.gen::<i64>(), num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        let mut ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let condition = sks.smart_signed_lt_parallelized(&mut ctxt_0, &mut ctxt_1);
+
+        let ct_res =
+            sks.smart_if_then_else_parallelized(&condition.blocks[0], &mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec);
+    }
+}
+
+fn integer_pow_sqrt(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % 16;
+
+        let mut ct = cks.encrypt(clear);
+        // The exponent is encrypted on fewer blocks to keep the test short
+        let mut ct_exponent = sks.create_trivial_zero_radix(1);
+        ct_exponent.blocks[0] = cks.encrypt_one_block(exponent % 4);
+
+        let ct_res = sks.smart_scalar_pow_parallelized(&mut ct, exponent as u32);
+        assert_eq!(
+            clear.wrapping_pow(exponent as u32) % modulus,
+            cks.decrypt(&ct_res)
+        );
+
+        let ct_res = sks.smart_pow_parallelized(&mut ct, &mut ct_exponent);
+        assert_eq!(
+            clear.wrapping_pow((exponent % 4) as u32) % modulus,
+            cks.decrypt(&ct_res)
+        );
+
+        let ct_res = sks.smart_sqrt_parallelized(&mut ct);
+        let expected = (0..=clear)
+            .take_while(|root| root * root <= clear)
+            .last()
+            .unwrap();
+        assert_eq!(expected, cks.decrypt(&ct_res));
+    }
+}
+
+fn integer_bit_count(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for i in 0..NB_TEST_SMALLER {
+        // Also check values whose high (or low) blocks are zero
+        let clear = match i % 4 {
+            0 => 0,
+            1 => rng.gen::<u64>() % param.message_modulus.0 as u64,
+            2 => (rng.gen::<u64>() % 4) << (num_bits - 2),
+            _ => rng.gen::<u64>() % (1 << num_bits),
+        };
+        let leading_zeros = u64::from(clear.leading_zeros() - (u64::BITS - num_bits));
+
+        let mut ct = cks.encrypt(clear);
+
+        let ct_res = sks.unchecked_count_ones_parallelized(&ct);
+        assert_eq!(u64::from(clear.count_ones()), cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_count_zeros_parallelized(&mut ct);
+        assert_eq!(
+            u64::from(num_bits - clear.count_ones()),
+            cks.decrypt(&ct_res)
+        );
+
+        let ct_res = sks.unchecked_leading_zeros_parallelized(&ct);
+        assert_eq!(leading_zeros, cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_trailing_zeros_parallelized(&mut ct);
+        assert_eq!(
+            u64::from(clear.trailing_zeros().min(num_bits)),
+            cks.decrypt(&ct_res)
+        );
+
+        let ct_res = sks.smart_ilog2_parallelized(&mut ct);
+        let expected = (u64::from(num_bits) - 1).wrapping_sub(leading_zeros) % (1 << num_bits);
+        assert_eq!(expected, cks.decrypt(&ct_res));
+    }
+}
+
+fn integer_comparisons_as_block(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = if i % 3 == 0 {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ct_0 = cks.encrypt(clear_0);
+        let mut ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.smart_ne_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 != clear_1), cks.decrypt(&ct_res));
+
+        let ct_res = sks.unchecked_eq_as_block_parallelized(&ct_0, &ct_1);
+        assert_eq!(
+            u64::from(clear_0 == clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.smart_ne_as_block_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(
+            u64::from(clear_0 != clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.smart_gt_as_block_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.smart_le_as_block_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(
+            u64::from(clear_0 <= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let clear_0 = signed_wrap(rng.gen::<i64>(), num_bits);
+        let clear_1 = signed_wrap(rng.gen::<i64>(), num_bits);
+
+        let mut ct_0 = cks.encrypt_signed(clear_0);
+        let mut ct_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = sks.smart_signed_lt_as_block_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.unchecked_signed_ge_as_block_parallelized(&ct_0, &ct_1);
+        assert_eq!(
+            u64::from(clear_0 >= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+    }
+}
+
+fn integer_scalar_comparisons(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Some scalars do not fit in the ciphertext, others are equal to the ciphertext
+        let clear_1 = match i % 3 {
+            0 => modulus + rng.gen::<u64>() % modulus,
+            1 => clear_0,
+            _ => rng.gen::<u64>() % modulus,
+        };
+
+        let mut ct_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.unchecked_scalar_eq_parallelized(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 == clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.unchecked_scalar_ge_parallelized(&ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 >= clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.smart_scalar_ne_parallelized(&mut ct_0, clear_1);
+        assert_eq!(
+            u64::from(clear_0 != clear_1),
+            cks.decrypt_one_block(&ct_res)
+        );
+
+        let ct_res = sks.smart_scalar_lt_parallelized(&mut ct_0, clear_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_one_block(&ct_res));
+
+        let ct_res = sks.smart_scalar_max_parallelized(&mut ct_0, clear_1);
+        assert_eq!(clear_0.max(clear_1).min(modulus - 1), cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_scalar_min_parallelized(&mut ct_0, clear_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt(&ct_res));
+    }
+}
+
+fn integer_smart_overflowing_saturating(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt(clear_0);
+        let mut ct_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.smart_overflowing_add_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!((clear_0 + clear_1) % modulus, cks.decrypt(&ct_res));
+        assert_eq!(
+            u64::from(clear_0 + clear_1 >= modulus),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let (ct_res, overflowed) = sks.smart_overflowing_sub_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(
+            clear_0.wrapping_sub(clear_1) % modulus,
+            cks.decrypt(&ct_res)
+        );
+        assert_eq!(
+            u64::from(clear_0 < clear_1),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let (ct_res, overflowed) = sks.smart_overflowing_mul_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!((clear_0 * clear_1) % modulus, cks.decrypt(&ct_res));
+        assert_eq!(
+            u64::from(clear_0 * clear_1 >= modulus),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let ct_res = sks.smart_saturating_add_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!((clear_0 + clear_1).min(modulus - 1), cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_saturating_sub_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0.saturating_sub(clear_1), cks.decrypt(&ct_res));
+    }
+}
+
 fn integer_smart_neg(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -651,6 +1183,174 @@ fn integer_smart_mul(param: Parameters) {
     }
 }
 
+fn integer_smart_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let mut ctxt_2 = cks.encrypt(clear_2);
+
+        // add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let (q, r) = sks.smart_div_rem_parallelized(&mut ctxt_0, &mut ctxt_2);
+        let dec_q = cks.decrypt(&q);
+        let dec_r = cks.decrypt(&r);
+
+        if clear_2 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_2, dec_q);
+            assert_eq!(clear_0 % clear_2, dec_r);
+        }
+    }
+}
+
+fn integer_smart_scalar_div_rem(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Random divisors, powers of two, zero and divisors larger than the modulus
+    let mut divisors = (0..NB_TEST_SMALLER)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    divisors.extend([0, 1, 4, modulus / 2, modulus - 1, modulus + 3]);
+
+    for clear_2 in divisors {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let (q, r) = sks.smart_scalar_div_rem_parallelized(&mut ctxt_0, clear_2);
+        let dec_q = cks.decrypt(&q);
+        let dec_r = cks.decrypt(&r);
+
+        if clear_2 == 0 {
+            assert_eq!(modulus - 1, dec_q);
+            assert_eq!(clear_0, dec_r);
+        } else {
+            assert_eq!(clear_0 / clear_2, dec_q);
+            assert_eq!(clear_0 % clear_2, dec_r);
+
+            let exact = clear_0 - clear_0 % clear_2;
+            let ctxt_exact = cks.encrypt(exact);
+            let ct_res = sks.unchecked_scalar_div_exact_parallelized(&ctxt_exact, clear_2);
+            assert_eq!(exact / clear_2, cks.decrypt(&ct_res));
+        }
+    }
+}
+
+/// Sign-extends the `num_bits` lsb of a value, as the decryption of a signed ciphertext
+fn signed_wrap(value: i64, num_bits: u32) -> i64 {
+    let unused_bits = 64 - num_bits;
+    (value << unused_bits) >> unused_bits
+}
+
+fn integer_smart_signed_overflowing_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = signed_wrap(rng.gen::<i64>(), num_bits);
+        let clear_1 = signed_wrap(rng.gen::<i64>(), num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        let mut ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (ct_res, overflowed) =
+            sks.smart_signed_overflowing_add_parallelized(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        let expected = clear_0 + clear_1;
+        assert_eq!(signed_wrap(expected, num_bits), dec);
+        assert_eq!(
+            u64::from(signed_wrap(expected, num_bits) != expected),
+            cks.decrypt_one_block(&overflowed)
+        );
+
+        let (ct_res, overflowed) =
+            sks.smart_signed_overflowing_sub_parallelized(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        let expected = clear_0 - clear_1;
+        assert_eq!(signed_wrap(expected, num_bits), dec);
+        assert_eq!(
+            u64::from(signed_wrap(expected, num_bits) != expected),
+            cks.decrypt_one_block(&overflowed)
+        );
+    }
+}
+
+fn integer_smart_signed_abs_shift_comparisons(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = (param.message_modulus.0 as f64).log2() as u32 * NB_CTXT as u32;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = signed_wrap(rng.gen::<i64>(), num_bits);
+        let clear_1 = signed_wrap(rng.gen::<i64>(), num_bits);
+        let shift = rng.gen_range(0..=num_bits);
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        let mut ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = sks.smart_signed_abs_parallelized(&mut ctxt_0);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(signed_wrap(clear_0.abs(), num_bits), dec);
+
+        let ct_res = sks.smart_signed_neg_parallelized(&mut ctxt_0);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(signed_wrap(-clear_0, num_bits), dec);
+
+        let ct_res = sks.unchecked_signed_scalar_right_shift_parallelized(&ctxt_0, shift as usize);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear_0 >> shift, dec);
+
+        let ct_res = sks.smart_signed_lt_parallelized(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_signed_ge_parallelized(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(u64::from(clear_0 >= clear_1), cks.decrypt(&ct_res));
+
+        let ct_res = sks.smart_signed_max_parallelized(&mut ctxt_0, &mut ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec);
+    }
+}
+
 fn integer_smart_scalar_add(param: Parameters) {
     // generate the server-client key set
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -734,3 +1434,269 @@ fn integer_smart_scalar_sub(param: Parameters) {
         }
     }
 }
+
+fn integer_unchecked_apply_univariate_function(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // With NB_CTXT blocks, the selection tree has more than one level
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let f = |x: u64| x ^ (x >> 1);
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_apply_univariate_function_parallelized(&ct, f);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(f(clear), dec_res);
+    }
+}
+
+fn integer_smart_apply_univariate_function(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let f = |x: u64| (modulus - 1 - x) * 5 % modulus;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Fill the carries so that the smart version has to propagate them
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_apply_univariate_function_parallelized(&mut ct_0, f);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(f(clear), dec_res);
+    }
+}
+
+fn integer_unchecked_apply_lookup_table_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        let table = (0..modulus)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.unchecked_apply_lookup_table_radix_parallelized(&ct, &table);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(table[clear as usize], dec_res);
+    }
+}
+
+fn integer_smart_apply_lookup_table_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_UNIVARIATE {
+        // The table only covers half of the inputs, the other half must map to 0
+        let table = (0..modulus / 2)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Fill the carries so that the smart version has to propagate them
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let ct_res = sks.smart_apply_lookup_table_radix_parallelized(&mut ct_0, &table);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        let expected = table.get(clear as usize).copied().unwrap_or(0);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_oblivious_read_write(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // The index is on a single block, the table only covers part of its range
+    let index_modulus = param.message_modulus.0 as u64;
+    let table_len = (index_modulus - 1) as usize;
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear_table = (0..table_len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut table = clear_table
+            .iter()
+            .map(|&value| cks.encrypt_radix(value, NB_CTXT))
+            .collect::<Vec<_>>();
+
+        let clear_index = rng.gen::<u64>() % index_modulus;
+        let clear_value = rng.gen::<u64>() % modulus;
+        let mut index = cks.encrypt_radix(clear_index, 1);
+        let mut value = cks.encrypt_radix(clear_value, NB_CTXT);
+
+        let expected = clear_table.get(clear_index as usize).copied().unwrap_or(0);
+
+        let ct_res = sks.smart_oblivious_read_parallelized(&mut table, &mut index);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.smart_match_value_parallelized(&mut index, &clear_table, NB_CTXT);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        sks.smart_oblivious_write_parallelized(&mut table, &mut index, &mut value);
+        if let Some(entry) = clear_table.get_mut(clear_index as usize) {
+            *entry = clear_value;
+        }
+        let dec_table = table
+            .iter()
+            .map(|ct| cks.decrypt_radix(ct))
+            .collect::<Vec<u64>>();
+        assert_eq!(clear_table, dec_table);
+    }
+}
+
+fn integer_sort_argmax_top_k(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let num_cts = 6;
+    let k = 4;
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Duplicate a value to check how ties are handled
+        let mut clears = (0..num_cts - 1)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        clears.push(clears[rng.gen_range(0..num_cts - 1)]);
+
+        let mut cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_radix(clear, NB_CTXT))
+            .collect::<Vec<_>>();
+
+        let max = *clears.iter().max().unwrap();
+        let min = *clears.iter().min().unwrap();
+        let expected_argmax = clears.iter().position(|&clear| clear == max).unwrap() as u64;
+        let expected_argmin = clears.iter().position(|&clear| clear == min).unwrap() as u64;
+
+        let ct_res = sks.argmax_parallelized(&cts).unwrap();
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected_argmax, dec_res);
+
+        let ct_res = sks.argmin_parallelized(&cts).unwrap();
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(expected_argmin, dec_res);
+
+        let mut sorted = clears.clone();
+        sorted.sort_unstable();
+        let expected_top_k = sorted.iter().rev().take(k).copied().collect::<Vec<_>>();
+
+        let top_k = sks.top_k_parallelized(&cts, k);
+        let dec_top_k = top_k
+            .iter()
+            .map(|ct| cks.decrypt_radix(ct))
+            .collect::<Vec<u64>>();
+        assert_eq!(expected_top_k, dec_top_k);
+
+        sks.sort_parallelized(&mut cts);
+        let dec_sorted = cts
+            .iter()
+            .map(|ct| cks.decrypt_radix(ct))
+            .collect::<Vec<u64>>();
+        assert_eq!(sorted, dec_sorted);
+    }
+}
+
+fn integer_smart_scalar_ops_u256(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // Enough blocks to encrypt 256 bits
+    let num_block = (256f64 / (param.message_modulus.0 as f64).log2()).ceil() as usize;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<U256>();
+        let clear_1 = rng.gen::<U256>();
+
+        let mut ctxt_0 = cks.encrypt_radix(clear_0, num_block);
+
+        let mut ct_res = sks.smart_scalar_add_parallelized(&mut ctxt_0, clear_1);
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0 + clear_1, dec_res);
+
+        let ct_res = sks.smart_scalar_sub_parallelized(&mut ct_res, clear_1);
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0, dec_res);
+
+        // A scalar with only a few non zero blocks keeps the number of terms to add small,
+        // while still using the high words
+        let clear_2 = U256::from(rng.gen::<u8>()) << rng.gen_range(64..248u32);
+        let ct_res = sks.smart_scalar_mul_parallelized(&mut ctxt_0, clear_2);
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0 * clear_2, dec_res);
+    }
+}
+
+fn integer_smart_widening_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let mut ctxt_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        // Raise the degree, so that the carries need to be propagated
+        ctxt_0 = sks.unchecked_add(&ctxt_0, &ctxt_0);
+        let clear_0 = (2 * clear_0) % modulus;
+
+        let ct_res = sks.smart_widening_mul_parallelized(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_0 * clear_1, dec_res);
+
+        let ct_res = sks.smart_mul_high_parallelized(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 * clear_1) / modulus, dec_res);
+    }
+}
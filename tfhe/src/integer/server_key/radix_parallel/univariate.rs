@@ -0,0 +1,196 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::comparator::has_non_zero_carries;
+use crate::integer::ServerKey;
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes in parallel the leaves of the tree used to evaluate the `digit`-th output block.
+    fn univariate_tree_leaves_parallelized<F>(
+        &self,
+        ct: &RadixCiphertext,
+        f: &F,
+        digit: usize,
+    ) -> Vec<CiphertextBig>
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        if ct.blocks.len() == 1 {
+            let acc = self
+                .key
+                .generate_accumulator(|x| self.tree_leaf_value(f, digit, x, 0));
+            return vec![self.key.apply_lookup_table(&ct.blocks[0], &acc)];
+        }
+
+        let num_leaves = message_modulus.pow(ct.blocks.len() as u32 - 2);
+        (0..num_leaves)
+            .into_par_iter()
+            .map(|high| {
+                let acc = self.key.generate_accumulator_bivariate(|left, right| {
+                    self.tree_leaf_value(f, digit, right + left * message_modulus, high)
+                });
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[1], &ct.blocks[0], &acc)
+            })
+            .collect()
+    }
+
+    /// Evaluates homomorphically a univariate function over the whole ciphertext.
+    ///
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// This only uses PBS: the output blocks are the roots of trees of block selections,
+    /// whose leaves are bivariate PBS over the two least significant blocks.
+    /// The number of PBS grows exponentially with the number of blocks,
+    /// so this is only practical for small integers (up to ~12 bits).
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 3 * 2 = 6 bits of message
+    /// let size = 3;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 37u64;
+    /// let f = |x: u64| (x * x + 3) % 64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_apply_univariate_function_parallelized(&ct, f);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn unchecked_apply_univariate_function_parallelized<F>(
+        &self,
+        ct: &RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        self.assert_tree_based_lut_is_possible(ct);
+        let message_modulus = self.key.message_modulus.0;
+        let accs = self.tree_selection_accumulators();
+
+        let blocks = (0..ct.blocks.len())
+            .into_par_iter()
+            .map(|digit| {
+                let mut nodes = self.univariate_tree_leaves_parallelized(ct, &f, digit);
+                for selector in ct.blocks.iter().skip(2) {
+                    nodes = nodes
+                        .par_chunks(message_modulus)
+                        .map(|candidates| self.unchecked_tree_select(candidates, selector, &accs))
+                        .collect();
+                }
+                nodes.pop().unwrap()
+            })
+            .collect();
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Evaluates homomorphically a univariate function over the whole ciphertext.
+    ///
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// The carries of the input are propagated if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 3 * 2 = 6 bits of message
+    /// let size = 3;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 37u64;
+    /// let f = |x: u64| x / 3;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_apply_univariate_function_parallelized(&mut ct, f);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn smart_apply_univariate_function_parallelized<F>(
+        &self,
+        ct: &mut RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_apply_univariate_function_parallelized(ct, f)
+    }
+
+    /// Looks up homomorphically the value encrypted in the ciphertext in a clear table.
+    ///
+    /// The i-th element of the table is the output for the input `i`,
+    /// inputs outside of the table are mapped to 0.
+    /// The result is computed modulo `message_modulus^num_blocks`.
+    ///
+    /// Requires carry bits to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 2 * 2 = 4 bits of message
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let table = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+    /// let msg = 10u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_apply_lookup_table_radix_parallelized(&ct, &table);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[msg as usize]);
+    /// ```
+    pub fn unchecked_apply_lookup_table_radix_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        self.unchecked_apply_univariate_function_parallelized(ct, |x| {
+            table.get(x as usize).copied().unwrap_or(0)
+        })
+    }
+
+    /// Looks up homomorphically the value encrypted in the ciphertext in a clear table.
+    ///
+    /// The i-th element of the table is the output for the input `i`,
+    /// inputs outside of the table are mapped to 0.
+    ///
+    /// The carries of the input are propagated if needed.
+    pub fn smart_apply_lookup_table_radix_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        if has_non_zero_carries(ct) {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_apply_lookup_table_radix_parallelized(ct, table)
+    }
+}
@@ -0,0 +1,493 @@
+//! Unsigned integers with a fixed number of 64 bits words, used as clear values
+//! for the radix ciphertexts wider than the native integers.
+use super::u256::adc;
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// Unsigned integer of `N` 64 bits words, stored in little endian order.
+///
+/// The arithmetic operations wrap around on overflow, like the radix ciphertexts.
+/// Divisions panic if the divisor is zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StaticUnsignedBigInt<const N: usize>(pub [u64; N]);
+
+/// Error returned when parsing a [StaticUnsignedBigInt] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit,
+    Overflow,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Cannot parse an integer from an empty string"),
+            Self::InvalidDigit => write!(f, "Invalid digit found in string"),
+            Self::Overflow => write!(f, "Number too large to fit in the target type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl<const N: usize> StaticUnsignedBigInt<N> {
+    pub const BITS: u32 = 64 * N as u32;
+    pub const ZERO: Self = Self([0; N]);
+    pub const MAX: Self = Self([u64::MAX; N]);
+    pub const ONE: Self = {
+        let mut words = [0; N];
+        if N > 0 {
+            words[0] = 1;
+        }
+        Self(words)
+    };
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the number of bits needed to represent the value.
+    pub fn bit_len(&self) -> u32 {
+        self.0
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |i| 64 * i as u32 + (64 - self.0[i].leading_zeros()))
+    }
+
+    pub fn bit(&self, index: u32) -> bool {
+        (self.0[(index / 64) as usize] >> (index % 64)) & 1 == 1
+    }
+
+    fn set_bit(&mut self, index: u32) {
+        self.0[(index / 64) as usize] |= 1 << (index % 64);
+    }
+
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut result = Self::ZERO;
+        let mut carry = false;
+        for i in 0..N {
+            (result.0[i], carry) = adc(self.0[i], rhs.0[i], carry);
+        }
+        (result, carry)
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut result = Self::ZERO;
+        let mut borrow = false;
+        for i in 0..N {
+            let (diff, o0) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (diff, o1) = diff.overflowing_sub(borrow as u64);
+            result.0[i] = diff;
+            borrow = o0 | o1;
+        }
+        (result, borrow)
+    }
+
+    /// Computes `self * mul + add`, returning the wrapped result and the word that overflowed.
+    fn overflowing_mul_add_small(self, mul: u64, add: u64) -> (Self, u64) {
+        let mut result = Self::ZERO;
+        let mut carry = add;
+        for i in 0..N {
+            let product = self.0[i] as u128 * mul as u128 + carry as u128;
+            result.0[i] = product as u64;
+            carry = (product >> 64) as u64;
+        }
+        (result, carry)
+    }
+
+    /// Divides by a single word divisor, returning the quotient and the remainder.
+    fn div_rem_small(self, divisor: u64) -> (Self, u64) {
+        assert_ne!(divisor, 0, "attempt to divide by zero");
+        let mut quotient = Self::ZERO;
+        let mut remainder = 0u64;
+        for i in (0..N).rev() {
+            let dividend = ((remainder as u128) << 64) | self.0[i] as u128;
+            quotient.0[i] = (dividend / divisor as u128) as u64;
+            remainder = (dividend % divisor as u128) as u64;
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns the quotient and the remainder of the division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    pub fn div_rem(self, divisor: Self) -> (Self, Self) {
+        if divisor.bit_len() <= 64 {
+            let (quotient, remainder) = self.div_rem_small(divisor.0[0]);
+            return (quotient, Self::from(remainder));
+        }
+
+        // Binary long division
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for i in (0..self.bit_len()).rev() {
+            // The divisor has more than 64 bits so N > 1, and the shift can only lose
+            // the top bit of the remainder
+            let overflowed = remainder.bit(Self::BITS - 1);
+            remainder <<= 1u32;
+            if self.bit(i) {
+                remainder.0[0] |= 1;
+            }
+            if overflowed || remainder >= divisor {
+                remainder = remainder.overflowing_sub(divisor).0;
+                quotient.set_bit(i);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Parses an integer written in the given radix (between 2 and 36).
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be between 2 and 36, got {radix}"
+        );
+        if src.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+
+        src.chars().try_fold(Self::ZERO, |value, c| {
+            let digit = c.to_digit(radix).ok_or(ParseBigIntError::InvalidDigit)?;
+            match value.overflowing_mul_add_small(radix as u64, digit as u64) {
+                (result, 0) => Ok(result),
+                _ => Err(ParseBigIntError::Overflow),
+            }
+        })
+    }
+}
+
+impl<const N: usize> Default for StaticUnsignedBigInt<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Parses a decimal integer, or an hexadecimal one if prefixed by `0x`.
+impl<const N: usize> FromStr for StaticUnsignedBigInt<N> {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => Self::from_str_radix(hex, 16),
+            None => Self::from_str_radix(s, 10),
+        }
+    }
+}
+
+impl<const N: usize> Display for StaticUnsignedBigInt<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Extract the digits by chunks of 19, the biggest power of 10 fitting in a word
+        const CHUNK_DIVISOR: u64 = 10u64.pow(19);
+        let mut chunks = Vec::new();
+        let mut value = *self;
+        loop {
+            let (quotient, chunk) = value.div_rem_small(CHUNK_DIVISOR);
+            chunks.push(chunk);
+            if quotient.is_zero() {
+                break;
+            }
+            value = quotient;
+        }
+
+        let mut digits = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl<const N: usize> std::fmt::LowerHex for StaticUnsignedBigInt<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut words = self.0.iter().rev().skip_while(|&&word| word == 0);
+        let mut digits = format!("{:x}", words.next().copied().unwrap_or(0));
+        for word in words {
+            digits.push_str(&format!("{word:016x}"));
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+// Since we store as [low, high], deriving ord
+// would produces bad ordering
+impl<const N: usize> Ord for StaticUnsignedBigInt<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<const N: usize> PartialOrd for StaticUnsignedBigInt<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Add<Self> for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.overflowing_add(rhs).0
+    }
+}
+
+impl<const N: usize> Sub<Self> for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const N: usize> Mul<Self> for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut result = Self::ZERO;
+        for i in 0..N {
+            let mut carry = 0u64;
+            for j in 0..N - i {
+                let product =
+                    self.0[i] as u128 * rhs.0[j] as u128 + result.0[i + j] as u128 + carry as u128;
+                result.0[i + j] = product as u64;
+                carry = (product >> 64) as u64;
+            }
+        }
+        result
+    }
+}
+
+impl<const N: usize> Div<Self> for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<const N: usize> Rem<Self> for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<const N: usize> Not for StaticUnsignedBigInt<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(self.0.map(|word| !word))
+    }
+}
+
+macro_rules! impl_word_wise_op {
+    ($trait_name:ident($trait_method:ident), $assign_trait_name:ident($assign_trait_method:ident), $op:tt) => {
+        impl<const N: usize> $trait_name<Self> for StaticUnsignedBigInt<N> {
+            type Output = Self;
+
+            fn $trait_method(mut self, rhs: Self) -> Self::Output {
+                self.$assign_trait_method(rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> $assign_trait_name<Self> for StaticUnsignedBigInt<N> {
+            fn $assign_trait_method(&mut self, rhs: Self) {
+                for (lhs_word, rhs_word) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *lhs_word $op *rhs_word;
+                }
+            }
+        }
+    };
+}
+
+impl_word_wise_op!(BitAnd(bitand), BitAndAssign(bitand_assign), &=);
+impl_word_wise_op!(BitOr(bitor), BitOrAssign(bitor_assign), |=);
+impl_word_wise_op!(BitXor(bitxor), BitXorAssign(bitxor_assign), ^=);
+
+macro_rules! impl_assign_op {
+    ($($assign_trait_name:ident($assign_trait_method:ident) => $op:tt),*) => {
+        $(
+            impl<const N: usize> $assign_trait_name<Self> for StaticUnsignedBigInt<N> {
+                fn $assign_trait_method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_op!(
+    AddAssign(add_assign) => +,
+    SubAssign(sub_assign) => -,
+    MulAssign(mul_assign) => *,
+    DivAssign(div_assign) => /,
+    RemAssign(rem_assign) => %
+);
+
+// Shifting by at least the number of bits gives 0
+macro_rules! impl_shifts {
+    ($($shift_type:ty),*) => {
+        $(
+            impl<const N: usize> Shl<$shift_type> for StaticUnsignedBigInt<N> {
+                type Output = Self;
+
+                fn shl(self, shift: $shift_type) -> Self::Output {
+                    let word_shift = (shift / 64) as usize;
+                    let bit_shift = (shift % 64) as u32;
+                    let mut result = Self::ZERO;
+                    for i in word_shift..N {
+                        let src = i - word_shift;
+                        result.0[i] = self.0[src] << bit_shift;
+                        if bit_shift != 0 && src > 0 {
+                            result.0[i] |= self.0[src - 1] >> (64 - bit_shift);
+                        }
+                    }
+                    result
+                }
+            }
+
+            impl<const N: usize> Shr<$shift_type> for StaticUnsignedBigInt<N> {
+                type Output = Self;
+
+                fn shr(self, shift: $shift_type) -> Self::Output {
+                    let word_shift = (shift / 64) as usize;
+                    let bit_shift = (shift % 64) as u32;
+                    let mut result = Self::ZERO;
+                    for i in 0..N.saturating_sub(word_shift) {
+                        let src = i + word_shift;
+                        result.0[i] = self.0[src] >> bit_shift;
+                        if bit_shift != 0 && src + 1 < N {
+                            result.0[i] |= self.0[src + 1] << (64 - bit_shift);
+                        }
+                    }
+                    result
+                }
+            }
+
+            impl<const N: usize> ShlAssign<$shift_type> for StaticUnsignedBigInt<N> {
+                fn shl_assign(&mut self, shift: $shift_type) {
+                    *self = *self << shift;
+                }
+            }
+
+            impl<const N: usize> ShrAssign<$shift_type> for StaticUnsignedBigInt<N> {
+                fn shr_assign(&mut self, shift: $shift_type) {
+                    *self = *self >> shift;
+                }
+            }
+        )*
+    };
+}
+
+impl_shifts!(u32, usize);
+
+macro_rules! impl_from_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl<const N: usize> From<$type> for StaticUnsignedBigInt<N> {
+                fn from(value: $type) -> Self {
+                    Self::from(value as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64);
+
+/// The value is truncated if `N` is 1.
+impl<const N: usize> From<u128> for StaticUnsignedBigInt<N> {
+    fn from(value: u128) -> Self {
+        let mut result = Self::ZERO;
+        for (word, value_word) in result
+            .0
+            .iter_mut()
+            .zip([value as u64, (value >> 64) as u64])
+        {
+            *word = value_word;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+impl<const N: usize> rand::distributions::Distribution<StaticUnsignedBigInt<N>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> StaticUnsignedBigInt<N> {
+        StaticUnsignedBigInt(std::array::from_fn(|_| rng.gen()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    type U128 = StaticUnsignedBigInt<2>;
+
+    fn to_u128(value: U128) -> u128 {
+        value.0[0] as u128 | ((value.0[1] as u128) << 64)
+    }
+
+    #[test]
+    fn test_arithmetic_matches_u128() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let a = rng.gen::<u128>();
+            // Also test divisors that fit in a single word
+            let b = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let shift = rng.gen_range(0..128u32);
+
+            let (big_a, big_b) = (U128::from(a), U128::from(b));
+
+            assert_eq!(to_u128(big_a + big_b), a.wrapping_add(b));
+            assert_eq!(to_u128(big_a - big_b), a.wrapping_sub(b));
+            assert_eq!(to_u128(big_a * big_b), a.wrapping_mul(b));
+            assert_eq!(to_u128(big_a & big_b), a & b);
+            assert_eq!(to_u128(big_a | big_b), a | b);
+            assert_eq!(to_u128(big_a ^ big_b), a ^ b);
+            assert_eq!(to_u128(!big_a), !a);
+            assert_eq!(to_u128(big_a << shift), a << shift);
+            assert_eq!(to_u128(big_a >> shift), a >> shift);
+            assert_eq!(big_a.cmp(&big_b), a.cmp(&b));
+            if b != 0 {
+                assert_eq!(to_u128(big_a / big_b), a / b);
+                assert_eq!(to_u128(big_a % big_b), a % b);
+            }
+        }
+        assert_eq!(U128::from(1u64) << 128u32, U128::ZERO);
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let value = rng.gen::<u128>();
+            let big_value = U128::from(value);
+
+            assert_eq!(big_value.to_string(), value.to_string());
+            assert_eq!(format!("{big_value:x}"), format!("{value:x}"));
+            assert_eq!(value.to_string().parse::<U128>(), Ok(big_value));
+            assert_eq!(format!("{value:#x}").parse::<U128>(), Ok(big_value));
+        }
+
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(max.parse(), Ok(StaticUnsignedBigInt::<4>::MAX));
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+                .parse::<StaticUnsignedBigInt<4>>(),
+            Err(ParseBigIntError::Overflow)
+        );
+        assert_eq!("".parse::<U128>(), Err(ParseBigIntError::Empty));
+        assert_eq!("12a".parse::<U128>(), Err(ParseBigIntError::InvalidDigit));
+    }
+}
@@ -10,6 +10,7 @@ use crate::core_crypto::prelude::*;
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::{ClientKey, CrtCiphertext, IntegerCiphertext, RadixCiphertext, ServerKey};
 use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use rayon::prelude::*;
 
 use crate::shortint::Parameters;
@@ -205,50 +206,7 @@ impl WopbsKey {
     where
         T: IntegerCiphertext,
     {
-        let total_bits_extracted = ct_in.blocks().iter().fold(0usize, |acc, block| {
-            acc + f64::log2((block.degree.0 + 1) as f64).ceil() as usize
-        });
-
-        let extract_bits_output_lwe_size = self
-            .wopbs_key
-            .wopbs_server_key
-            .key_switching_key
-            .output_key_lwe_dimension()
-            .to_lwe_size();
-
-        let mut extracted_bits_blocks = LweCiphertextList::new(
-            0u64,
-            extract_bits_output_lwe_size,
-            LweCiphertextCount(total_bits_extracted),
-        );
-
-        let mut bits_extracted_so_far = 0;
-
-        // Extraction of each bit for each block
-        for block in ct_in.blocks().iter().rev() {
-            let delta = (1_usize << 63)
-                / (self.wopbs_key.param.message_modulus.0 * self.wopbs_key.param.carry_modulus.0);
-            let delta_log = DeltaLog(f64::log2(delta as f64) as usize);
-            let nb_bit_to_extract = f64::log2((block.degree.0 + 1) as f64).ceil() as usize;
-
-            let extract_from_bit = bits_extracted_so_far;
-            let extract_to_bit = extract_from_bit + nb_bit_to_extract;
-            bits_extracted_so_far += nb_bit_to_extract;
-
-            let mut lwe_sub_list =
-                extracted_bits_blocks.get_sub_mut(extract_from_bit..extract_to_bit);
-
-            self.wopbs_key.extract_bits_assign(
-                delta_log,
-                block,
-                nb_bit_to_extract,
-                &mut lwe_sub_list,
-            );
-        }
-
-        let vec_ct_out = self
-            .wopbs_key
-            .circuit_bootstrapping_vertical_packing(lut, &extracted_bits_blocks);
+        let vec_ct_out = self.extract_bits_and_evaluate_luts(ct_in, lut);
 
         let mut ct_vec_out = vec![];
         for (block, block_out) in ct_in.blocks().iter().zip(vec_ct_out.into_iter()) {
@@ -969,6 +927,489 @@ impl WopbsKey {
         vec_ct_in: &[T],
         lut: &[Vec<u64>],
     ) -> T
+    where
+        T: IntegerCiphertext,
+    {
+        let vec_ct_out = self.extract_bits_native_crt_and_evaluate_luts(vec_ct_in, lut);
+
+        let mut ct_vec_out: Vec<crate::shortint::CiphertextBig> = vec![];
+        for (block, block_out) in vec_ct_in[0].blocks().iter().zip(vec_ct_out.into_iter()) {
+            ct_vec_out.push(crate::shortint::CiphertextBig {
+                ct: block_out,
+                degree: Degree(block.message_modulus.0 - 1),
+                message_modulus: block.message_modulus,
+                carry_modulus: block.carry_modulus,
+                _order_marker: Default::default(),
+            });
+        }
+        T::from_blocks(ct_vec_out)
+    }
+
+    /// Converts homomorphically a radix ciphertext into a CRT ciphertext with the given moduli.
+    ///
+    /// The encrypted value is reduced modulo the product of the moduli.
+    /// The ciphertext must have been keyswitched to the WoPBS parameters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let nb_block = 3;
+    /// let basis: Vec<u64> = vec![2, 3, 5];
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear = 42;
+    /// let ct = cks.encrypt_radix(clear, nb_block);
+    /// let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+    /// let ct_res = wopbs_key.radix_to_crt(&ct, &basis);
+    /// let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear % 30);
+    /// ```
+    pub fn radix_to_crt(&self, ct: &RadixCiphertext, moduli: &[u64]) -> CrtCiphertext {
+        let total_modulus =
+            self.wopbs_key.param.message_modulus.0 * self.wopbs_key.param.carry_modulus.0;
+        let delta: u64 = (1 << 63) / total_modulus as u64;
+
+        let lut = self.generate_lut_radix_to_crt(ct, moduli, |value, _| value * delta);
+        let vec_ct_out = self.extract_bits_and_evaluate_luts(ct, &lut);
+
+        let blocks = vec_ct_out
+            .into_iter()
+            .zip(moduli.iter())
+            .map(|(block_out, &modulus)| crate::shortint::CiphertextBig {
+                ct: block_out,
+                degree: Degree(modulus as usize - 1),
+                message_modulus: MessageModulus(modulus as usize),
+                carry_modulus: CarryModulus(total_modulus / modulus as usize),
+                _order_marker: Default::default(),
+            })
+            .collect();
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    /// Converts homomorphically a radix ciphertext into a native CRT ciphertext (i.e., without
+    /// padding) with the given moduli.
+    ///
+    /// The encrypted value is reduced modulo the product of the moduli.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::parameters::PARAM_4_BITS_5_BLOCKS;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    ///
+    /// let nb_block = 2;
+    /// let basis: Vec<u64> = vec![9, 11];
+    ///
+    /// let param = PARAM_4_BITS_5_BLOCKS;
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&param);
+    /// let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+    ///
+    /// let clear = 42;
+    /// let ct = cks.encrypt_radix(clear, nb_block);
+    /// let ct_res = wopbs_key.radix_to_native_crt(&ct, &basis);
+    /// let res = cks.decrypt_native_crt(&ct_res);
+    /// assert_eq!(res, clear % 99);
+    /// ```
+    pub fn radix_to_native_crt(&self, ct: &RadixCiphertext, moduli: &[u64]) -> CrtCiphertext {
+        let lut = self.generate_lut_radix_to_crt(ct, moduli, |value, modulus| {
+            ((value as u128 * (1 << 64)) / modulus as u128) as u64
+        });
+        let vec_ct_out = self.extract_bits_and_evaluate_luts(ct, &lut);
+
+        let blocks = vec_ct_out
+            .into_iter()
+            .zip(moduli.iter())
+            .map(|(block_out, &modulus)| crate::shortint::CiphertextBig {
+                ct: block_out,
+                degree: Degree(modulus as usize - 1),
+                message_modulus: MessageModulus(modulus as usize),
+                carry_modulus: CarryModulus(1),
+                _order_marker: Default::default(),
+            })
+            .collect();
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    /// Generates one LUT per modulus, mapping the bits extracted from the radix ciphertext to
+    /// the encoding of its value modulo this modulus.
+    fn generate_lut_radix_to_crt<F>(
+        &self,
+        ct: &RadixCiphertext,
+        moduli: &[u64],
+        encode: F,
+    ) -> Vec<Vec<u64>>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let mut total_bit = 0;
+        let mut modulus = 1;
+
+        //This contains the basis of each block depending on the degree
+        let mut vec_deg_basis = vec![];
+
+        for (i, deg) in ct.moduli().iter().zip(ct.blocks().iter()) {
+            modulus *= i;
+            let b = f64::log2((deg.degree.0 + 1) as f64).ceil() as u64;
+            vec_deg_basis.push(b);
+            total_bit += b;
+        }
+
+        let mut lut_size = 1 << total_bit;
+        if 1 << total_bit < self.wopbs_key.param.polynomial_size.0 as u64 {
+            lut_size = self.wopbs_key.param.polynomial_size.0;
+        }
+        let mut vec_lut = vec![vec![0; lut_size]; moduli.len()];
+
+        let basis = ct.moduli()[0];
+        for lut_index_val in 0..(1 << total_bit) {
+            let encoded_with_deg_val = encode_mix_radix(lut_index_val, &vec_deg_basis, basis);
+            let value = decode_radix(encoded_with_deg_val, basis) % modulus;
+            for (lut, &crt_modulus) in vec_lut.iter_mut().zip(moduli.iter()) {
+                lut[lut_index_val as usize] = encode(value % crt_modulus, crt_modulus);
+            }
+        }
+        vec_lut
+    }
+
+    /// Converts homomorphically a CRT ciphertext into a radix ciphertext with the given number of
+    /// blocks.
+    ///
+    /// The encrypted value is reduced modulo `message_modulus^num_blocks`.
+    /// The ciphertext must have been keyswitched to the WoPBS parameters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let nb_block = 3;
+    /// let basis: Vec<u64> = vec![2, 3, 5];
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear = 23;
+    /// let ct = cks.encrypt_crt(clear, basis);
+    /// let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+    /// let ct_res = wopbs_key.crt_to_radix(&ct, nb_block);
+    /// let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn crt_to_radix(&self, ct: &CrtCiphertext, num_blocks: usize) -> RadixCiphertext {
+        let mut bit = vec![];
+        let mut total_bit = 0;
+        for block in ct.blocks.iter() {
+            let b = f64::log2((block.degree.0 + 1) as f64).ceil() as u64;
+            total_bit += b;
+            bit.push(b);
+        }
+
+        let mut lut_size = 1 << total_bit;
+        if 1 << total_bit < self.wopbs_key.param.polynomial_size.0 as u64 {
+            lut_size = self.wopbs_key.param.polynomial_size.0;
+        }
+        let mut vec_lut = vec![vec![0; lut_size]; num_blocks];
+
+        let mut residues = vec![0; ct.blocks.len()];
+        for i in 0..(1 << total_bit) {
+            let mut value = i;
+            for ((residue, block), deg) in residues.iter_mut().zip(ct.blocks.iter()).zip(bit.iter())
+            {
+                *residue = (value % (1 << deg)) % block.message_modulus.0 as u64;
+                value >>= deg;
+            }
+            let value = i_crt(&ct.moduli, &residues);
+            self.fill_lut_radix_digits(&mut vec_lut, i as usize, value);
+        }
+
+        let vec_ct_out = self.extract_bits_and_evaluate_luts(ct, &vec_lut);
+        self.radix_from_lut_outputs(vec_ct_out)
+    }
+
+    /// Converts homomorphically a native CRT ciphertext (i.e., without padding) into a radix
+    /// ciphertext with the given number of blocks.
+    ///
+    /// The encrypted value is reduced modulo `message_modulus^num_blocks`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::parameters::PARAM_4_BITS_5_BLOCKS;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    ///
+    /// let nb_block = 2;
+    /// let basis: Vec<u64> = vec![9, 11];
+    ///
+    /// let param = PARAM_4_BITS_5_BLOCKS;
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&param);
+    /// let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+    ///
+    /// let clear = 42;
+    /// let ct = cks.encrypt_native_crt(clear, basis);
+    /// let ct_res = wopbs_key.native_crt_to_radix(&ct, nb_block);
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn native_crt_to_radix(&self, ct: &CrtCiphertext, num_blocks: usize) -> RadixCiphertext {
+        let mut bit = vec![];
+        let mut total_bit = 0;
+        let mut modulus = 1;
+        let basis = ct.moduli();
+
+        for i in basis.iter() {
+            modulus *= i;
+            let b = f64::log2(*i as f64).ceil() as u64;
+            total_bit += b;
+            bit.push(b);
+        }
+        let mut lut_size = 1 << total_bit;
+        if 1 << total_bit < self.wopbs_key.param.polynomial_size.0 as u64 {
+            lut_size = self.wopbs_key.param.polynomial_size.0;
+        }
+        let mut vec_lut = vec![vec![0; lut_size]; num_blocks];
+
+        for value in 0..modulus {
+            let mut index_lut = 0;
+            let mut tmp = 1;
+            for (base, bit) in basis.iter().zip(bit.iter()) {
+                index_lut += (((value % base) << bit) / base) * tmp;
+                tmp <<= bit;
+            }
+            self.fill_lut_radix_digits(&mut vec_lut, index_lut as usize, value);
+        }
+
+        let vec_ct_out =
+            self.extract_bits_native_crt_and_evaluate_luts(std::slice::from_ref(ct), &vec_lut);
+        self.radix_from_lut_outputs(vec_ct_out)
+    }
+
+    /// Computes homomorphically the bitwise AND between two CRT ciphertexts.
+    ///
+    /// The CRT ciphertexts are converted to radix ciphertexts, on which the operation is done
+    /// using the server key, and the result is converted back to the CRT basis of `ct_left`.
+    ///
+    /// The bits of a value depend on all its residues at once, and converting between the two
+    /// representations needs a function of several blocks, which is what the WoPBS computes:
+    /// this is why, contrary to the other CRT operations, there is no variant
+    /// of the bitwise operations on [ServerKey] alone.
+    ///
+    /// The ciphertexts are expected in the PBS parameters, as is the result.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let basis: Vec<u64> = vec![2, 3, 5];
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 23;
+    /// let clear_2 = 14;
+    /// let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ct_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = wopbs_key.crt_bitand(&sks, &ct_1, &ct_2);
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear_1 & clear_2);
+    /// ```
+    pub fn crt_bitand(
+        &self,
+        sks: &ServerKey,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_bitwise_op(sks, ct_left, ct_right, ServerKey::unchecked_bitand)
+    }
+
+    /// Computes homomorphically the bitwise OR between two CRT ciphertexts.
+    ///
+    /// The result may be greater than the product of the moduli, in which case it is reduced
+    /// modulo this product to fit the CRT basis: with the basis `[2, 3, 5]`, `17 | 14 = 31` wraps around to `1`.
+    ///
+    /// See [Self::crt_bitand] for the details.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let basis: Vec<u64> = vec![2, 3, 5];
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 17;
+    /// let clear_2 = 14;
+    /// let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ct_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = wopbs_key.crt_bitor(&sks, &ct_1, &ct_2);
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, (clear_1 | clear_2) % 30);
+    /// assert_eq!(res, 1);
+    /// ```
+    pub fn crt_bitor(
+        &self,
+        sks: &ServerKey,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_bitwise_op(sks, ct_left, ct_right, ServerKey::unchecked_bitor)
+    }
+
+    /// Computes homomorphically the bitwise XOR between two CRT ciphertexts.
+    ///
+    /// The result may be greater than the product of the moduli, in which case it is reduced
+    /// modulo this product to fit the CRT basis: with the basis `[2, 3, 5]`, `17 ^ 14 = 31` wraps around to `1`.
+    ///
+    /// See [Self::crt_bitand] for the details.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let basis: Vec<u64> = vec![2, 3, 5];
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 17;
+    /// let clear_2 = 14;
+    /// let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ct_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = wopbs_key.crt_bitxor(&sks, &ct_1, &ct_2);
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, (clear_1 ^ clear_2) % 30);
+    /// assert_eq!(res, 1);
+    /// ```
+    pub fn crt_bitxor(
+        &self,
+        sks: &ServerKey,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_bitwise_op(sks, ct_left, ct_right, ServerKey::unchecked_bitxor)
+    }
+
+    /// Does the round trip CRT -> radix -> CRT around the given radix bitwise operation.
+    fn crt_bitwise_op<F>(
+        &self,
+        sks: &ServerKey,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+        op: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(&ServerKey, &RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        assert_eq!(
+            ct_left.moduli, ct_right.moduli,
+            "Both ciphertexts must use the same basis"
+        );
+
+        // Enough radix blocks to represent any value of the CRT basis
+        let modulus: u64 = ct_left.moduli.iter().product();
+        let message_modulus = sks.key.message_modulus.0 as u64;
+        let mut num_blocks = 1;
+        let mut msg_space = message_modulus;
+        while msg_space < modulus {
+            msg_space *= message_modulus;
+            num_blocks += 1;
+        }
+
+        let (radix_left, radix_right) = rayon::join(
+            || self.crt_to_radix_with_pbs_params(sks, ct_left, num_blocks),
+            || self.crt_to_radix_with_pbs_params(sks, ct_right, num_blocks),
+        );
+
+        let radix_result = op(sks, &radix_left, &radix_right);
+
+        let radix_result = self.keyswitch_to_wopbs_params(sks, &radix_result);
+        let result = self.radix_to_crt(&radix_result, &ct_left.moduli);
+        self.keyswitch_to_pbs_params(&result)
+    }
+
+    fn crt_to_radix_with_pbs_params(
+        &self,
+        sks: &ServerKey,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let ct = self.keyswitch_to_wopbs_params(sks, ct);
+        let radix = self.crt_to_radix(&ct, num_blocks);
+        self.keyswitch_to_pbs_params(&radix)
+    }
+
+    /// Writes at the given index of each LUT the encoding of the matching radix digit of the
+    /// value.
+    fn fill_lut_radix_digits(&self, vec_lut: &mut [Vec<u64>], index: usize, value: u64) {
+        let basis = self.wopbs_key.param.message_modulus.0 as u64;
+        let delta: u64 = (1 << 63)
+            / (self.wopbs_key.param.message_modulus.0 * self.wopbs_key.param.carry_modulus.0)
+                as u64;
+
+        let mut value = value;
+        for lut in vec_lut.iter_mut() {
+            lut[index] = (value % basis) * delta;
+            value /= basis;
+        }
+    }
+
+    fn radix_from_lut_outputs(&self, vec_ct_out: Vec<LweCiphertextOwned<u64>>) -> RadixCiphertext {
+        let blocks = vec_ct_out
+            .into_iter()
+            .map(|block_out| crate::shortint::CiphertextBig {
+                ct: block_out,
+                degree: Degree(self.wopbs_key.param.message_modulus.0 - 1),
+                message_modulus: self.wopbs_key.param.message_modulus,
+                carry_modulus: self.wopbs_key.param.carry_modulus,
+                _order_marker: Default::default(),
+            })
+            .collect::<Vec<_>>();
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Extracts all the bits of the native CRT blocks and evaluates each LUT on them, returning
+    /// one LWE ciphertext per LUT.
+    fn extract_bits_native_crt_and_evaluate_luts<T>(
+        &self,
+        vec_ct_in: &[T],
+        lut: &[Vec<u64>],
+    ) -> Vec<LweCiphertextOwned<u64>>
     where
         T: IntegerCiphertext,
     {
@@ -1027,21 +1468,63 @@ impl WopbsKey {
             }
         }
 
-        let vec_ct_out = self
+        self.wopbs_key
+            .circuit_bootstrapping_vertical_packing(lut, &extracted_bits_blocks)
+    }
+
+    /// Extracts the bits of all the blocks, as many as their degree requires,
+    /// and evaluates each LUT on them, returning one LWE ciphertext per LUT.
+    fn extract_bits_and_evaluate_luts<T>(
+        &self,
+        ct_in: &T,
+        lut: &[Vec<u64>],
+    ) -> Vec<LweCiphertextOwned<u64>>
+    where
+        T: IntegerCiphertext,
+    {
+        let total_bits_extracted = ct_in.blocks().iter().fold(0usize, |acc, block| {
+            acc + f64::log2((block.degree.0 + 1) as f64).ceil() as usize
+        });
+
+        let extract_bits_output_lwe_size = self
             .wopbs_key
-            .circuit_bootstrapping_vertical_packing(lut, &extracted_bits_blocks);
+            .wopbs_server_key
+            .key_switching_key
+            .output_key_lwe_dimension()
+            .to_lwe_size();
 
-        let mut ct_vec_out: Vec<crate::shortint::CiphertextBig> = vec![];
-        for (block, block_out) in vec_ct_in[0].blocks().iter().zip(vec_ct_out.into_iter()) {
-            ct_vec_out.push(crate::shortint::CiphertextBig {
-                ct: block_out,
-                degree: Degree(block.message_modulus.0 - 1),
-                message_modulus: block.message_modulus,
-                carry_modulus: block.carry_modulus,
-                _order_marker: Default::default(),
-            });
+        let mut extracted_bits_blocks = LweCiphertextList::new(
+            0u64,
+            extract_bits_output_lwe_size,
+            LweCiphertextCount(total_bits_extracted),
+        );
+
+        let mut bits_extracted_so_far = 0;
+
+        // Extraction of each bit for each block
+        for block in ct_in.blocks().iter().rev() {
+            let delta = (1_usize << 63)
+                / (self.wopbs_key.param.message_modulus.0 * self.wopbs_key.param.carry_modulus.0);
+            let delta_log = DeltaLog(f64::log2(delta as f64) as usize);
+            let nb_bit_to_extract = f64::log2((block.degree.0 + 1) as f64).ceil() as usize;
+
+            let extract_from_bit = bits_extracted_so_far;
+            let extract_to_bit = extract_from_bit + nb_bit_to_extract;
+            bits_extracted_so_far += nb_bit_to_extract;
+
+            let mut lwe_sub_list =
+                extracted_bits_blocks.get_sub_mut(extract_from_bit..extract_to_bit);
+
+            self.wopbs_key.extract_bits_assign(
+                delta_log,
+                block,
+                nb_bit_to_extract,
+                &mut lwe_sub_list,
+            );
         }
-        T::from_blocks(ct_vec_out)
+
+        self.wopbs_key
+            .circuit_bootstrapping_vertical_packing(lut, &extracted_bits_blocks)
     }
 
     pub fn keyswitch_to_wopbs_params<T>(&self, sks: &ServerKey, ct_in: &T) -> T
@@ -5,6 +5,7 @@ use crate::integer::parameters::*;
 use crate::integer::wopbs::{encode_radix, WopbsKey};
 use crate::shortint::parameters::parameters_wopbs::*;
 use crate::shortint::parameters::parameters_wopbs_message_carry::*;
+use crate::shortint::parameters::parameters_wopbs_prime_moduli::*;
 use crate::shortint::parameters::{Parameters, *};
 use rand::Rng;
 use std::cmp::max;
@@ -289,3 +290,146 @@ pub fn wopbs_bivariate_crt(params: (Parameters, Parameters)) {
         assert_eq!(res, ((clear1 * clear2) + clear2) % msg_space);
     }
 }
+
+create_parametrized_test!(wopbs_radix_to_crt {
+    (PARAM_MESSAGE_2_CARRY_2, WOPBS_PARAM_MESSAGE_2_CARRY_2),
+    (PARAM_MESSAGE_3_CARRY_3, WOPBS_PARAM_MESSAGE_3_CARRY_3),
+    (PARAM_MESSAGE_4_CARRY_4, WOPBS_PARAM_MESSAGE_4_CARRY_4),
+    (WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2)
+});
+create_parametrized_test!(wopbs_crt_to_radix {
+    (PARAM_MESSAGE_2_CARRY_2, WOPBS_PARAM_MESSAGE_2_CARRY_2),
+    (PARAM_MESSAGE_3_CARRY_3, WOPBS_PARAM_MESSAGE_3_CARRY_3),
+    (PARAM_MESSAGE_4_CARRY_4, WOPBS_PARAM_MESSAGE_4_CARRY_4),
+    (WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2)
+});
+create_parametrized_test!(wopbs_crt_bitwise_op);
+create_parametrized_test!(wopbs_native_crt_radix_conversion {
+    (WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2),
+    (WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2, WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2)
+});
+
+fn make_native_basis(message_modulus: usize) -> Vec<u64> {
+    match message_modulus {
+        n if n < 8 => vec![2, 3],
+        n if n < 16 => vec![5, 7],
+        _ => vec![11, 13],
+    }
+}
+
+/// Returns the number of radix blocks needed to hold any value modulo the given modulus.
+fn num_blocks_for_modulus(message_modulus: usize, modulus: u64) -> usize {
+    let mut num_blocks = 1;
+    let mut msg_space = message_modulus as u64;
+    while msg_space < modulus {
+        msg_space *= message_modulus as u64;
+        num_blocks += 1;
+    }
+    num_blocks
+}
+
+pub fn wopbs_radix_to_crt(params: (Parameters, Parameters)) {
+    let mut rng = rand::thread_rng();
+
+    let basis = make_basis(params.1.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+    let nb_block = 3;
+
+    let (cks, sks) = gen_keys(&params.0);
+    let wopbs_key = KEY_CACHE_WOPBS.get_from_params(params);
+
+    let msg_space = (params.0.message_modulus.0 as u64).pow(nb_block as u32);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % msg_space;
+        let ct = cks.encrypt_radix(clear, nb_block);
+
+        let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+        let ct_res = wopbs_key.radix_to_crt(&ct, &basis);
+        let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+
+        let res = cks.decrypt_crt(&ct_res);
+        assert_eq!(res, clear % modulus);
+    }
+}
+
+pub fn wopbs_crt_to_radix(params: (Parameters, Parameters)) {
+    let mut rng = rand::thread_rng();
+
+    let basis = make_basis(params.1.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+    let nb_block = num_blocks_for_modulus(params.0.message_modulus.0, modulus);
+
+    let (cks, sks) = gen_keys(&params.0);
+    let wopbs_key = KEY_CACHE_WOPBS.get_from_params(params);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_crt(clear, basis.clone());
+
+        let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+        let ct_res = wopbs_key.crt_to_radix(&ct, nb_block);
+        let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+
+        let res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(res, clear);
+    }
+}
+
+pub fn wopbs_crt_bitwise_op(params: (Parameters, Parameters)) {
+    let mut rng = rand::thread_rng();
+
+    let basis = make_basis(params.1.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = gen_keys(&params.0);
+    let wopbs_key = KEY_CACHE_WOPBS.get_from_params(params);
+
+    for _ in 0..NB_TEST {
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+        let ct_2 = cks.encrypt_crt(clear_2, basis.clone());
+
+        let ct_res = wopbs_key.crt_bitand(&sks, &ct_1, &ct_2);
+        let res = cks.decrypt_crt(&ct_res);
+        assert_eq!(res, clear_1 & clear_2);
+
+        let ct_res = wopbs_key.crt_bitor(&sks, &ct_1, &ct_2);
+        let res = cks.decrypt_crt(&ct_res);
+        assert_eq!(res, (clear_1 | clear_2) % modulus);
+
+        let ct_res = wopbs_key.crt_bitxor(&sks, &ct_1, &ct_2);
+        let res = cks.decrypt_crt(&ct_res);
+        assert_eq!(res, (clear_1 ^ clear_2) % modulus);
+    }
+}
+
+pub fn wopbs_native_crt_radix_conversion(params: (Parameters, Parameters)) {
+    let mut rng = rand::thread_rng();
+
+    let basis = make_native_basis(params.1.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+    let nb_block = num_blocks_for_modulus(params.1.message_modulus.0, modulus);
+
+    let (cks, sks) = gen_keys(&params.1);
+    let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_native_crt(clear, basis.clone());
+
+        let ct_radix = wopbs_key.native_crt_to_radix(&ct, nb_block);
+        let res: u64 = cks.decrypt_radix(&ct_radix);
+        assert_eq!(res, clear);
+
+        let ct_crt = wopbs_key.radix_to_native_crt(&ct_radix, &basis);
+        let res = cks.decrypt_native_crt(&ct_crt);
+        assert_eq!(res, clear);
+    }
+}
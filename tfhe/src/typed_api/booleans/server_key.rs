@@ -1,7 +1,7 @@
 use super::client_key::GenericBoolClientKey;
 use super::parameters::BooleanParameterSet;
 use super::types::GenericBool;
-use crate::boolean::server_key::{BinaryBooleanGates, ServerKey};
+use crate::boolean::server_key::{BinaryBooleanGates, CompressedServerKey, ServerKey};
 
 use serde::{Deserialize, Serialize};
 
@@ -91,3 +91,37 @@ where
         GenericBool::<P>::new(ciphertext, condition.id)
     }
 }
+
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "boolean"))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenericBoolCompressedServerKey<P>
+where
+    P: BooleanParameterSet,
+{
+    pub(in crate::typed_api::booleans) key: CompressedServerKey,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<P> GenericBoolCompressedServerKey<P>
+where
+    P: BooleanParameterSet,
+{
+    pub(crate) fn new(key: &GenericBoolClientKey<P>) -> Self {
+        Self {
+            key: CompressedServerKey::new(&key.key),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<P> From<GenericBoolCompressedServerKey<P>> for GenericBoolServerKey<P>
+where
+    P: BooleanParameterSet,
+{
+    fn from(compressed: GenericBoolCompressedServerKey<P>) -> Self {
+        Self {
+            key: compressed.key.into(),
+            _marker: Default::default(),
+        }
+    }
+}
@@ -98,6 +98,10 @@ where
         self.id
             .with_unwrapped_global(|key| key.xnor(self, other.borrow()))
     }
+
+    fn ne(&self, other: B) -> Self {
+        self.neq(other.borrow())
+    }
 }
 
 #[allow(dead_code)]
@@ -5,7 +5,9 @@ use crate::typed_api::booleans::client_key::GenericBoolClientKey;
 use crate::typed_api::booleans::parameters::BooleanParameterSet;
 pub use crate::typed_api::booleans::parameters::FheBoolParameters;
 use crate::typed_api::booleans::public_key::GenericBoolPublicKey;
-use crate::typed_api::booleans::server_key::GenericBoolServerKey;
+use crate::typed_api::booleans::server_key::{
+    GenericBoolCompressedServerKey, GenericBoolServerKey,
+};
 use crate::typed_api::booleans::types::CompressedBool;
 use crate::typed_api::errors::Type;
 
@@ -55,6 +57,8 @@ pub(in crate::typed_api::booleans) type FheBoolClientKey =
     GenericBoolClientKey<StaticBoolParameters>;
 pub(in crate::typed_api::booleans) type FheBoolServerKey =
     GenericBoolServerKey<StaticBoolParameters>;
+pub(in crate::typed_api::booleans) type FheBoolCompressedServerKey =
+    GenericBoolCompressedServerKey<StaticBoolParameters>;
 pub(in crate::typed_api::booleans) type FheBoolPublicKey =
     GenericBoolPublicKey<StaticBoolParameters>;
 
@@ -6,7 +6,7 @@ use crate::typed_api::integers::IntegerConfig;
 use crate::typed_api::shortints::ShortIntConfig;
 
 /// The config type
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Config {
     #[cfg(feature = "boolean")]
     pub(crate) boolean_config: BooleanConfig,
@@ -44,13 +44,16 @@ pub struct ConfigBuilder {
 
 impl ConfigBuilder {
     /// Create a new builder with all the data types activated with their default parameters
+    ///
+    /// `FheUint32`, `FheUint64` and `FheUint128` are the exception, they have to be
+    /// enabled explicitly (eg with [enable_default_uint32](Self::enable_default_uint32)).
     pub fn all_enabled() -> Self {
         Self {
             config: Config {
                 #[cfg(feature = "boolean")]
                 boolean_config: BooleanConfig::all_default(),
                 #[cfg(feature = "integer")]
-                integer_config: IntegerConfig::all_default(),
+                integer_config: IntegerConfig::all_default_without_wide_types(),
                 #[cfg(feature = "shortint")]
                 shortint_config: ShortIntConfig::all_default(),
             },
@@ -143,6 +146,42 @@ impl ConfigBuilder {
         self
     }
 
+    #[cfg(feature = "integer")]
+    pub fn enable_default_uint32(mut self) -> Self {
+        self.config.integer_config.uint32_params = Some(Default::default());
+        self
+    }
+
+    #[cfg(feature = "integer")]
+    pub fn disable_uint32(mut self) -> Self {
+        self.config.integer_config.uint32_params = None;
+        self
+    }
+
+    #[cfg(feature = "integer")]
+    pub fn enable_default_uint64(mut self) -> Self {
+        self.config.integer_config.uint64_params = Some(Default::default());
+        self
+    }
+
+    #[cfg(feature = "integer")]
+    pub fn disable_uint64(mut self) -> Self {
+        self.config.integer_config.uint64_params = None;
+        self
+    }
+
+    #[cfg(feature = "integer")]
+    pub fn enable_default_uint128(mut self) -> Self {
+        self.config.integer_config.uint128_params = Some(Default::default());
+        self
+    }
+
+    #[cfg(feature = "integer")]
+    pub fn disable_uint128(mut self) -> Self {
+        self.config.integer_config.uint128_params = None;
+        self
+    }
+
     #[cfg(feature = "integer")]
     pub fn enable_default_uint256(mut self) -> Self {
         self.config.integer_config.uint256_params = Some(Default::default());
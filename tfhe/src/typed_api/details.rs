@@ -15,14 +15,15 @@ macro_rules! define_key_structs {
                     [<$base_ty_name Parameters>],
                     [<$base_ty_name ClientKey>],
                     [<$base_ty_name PublicKey>],
-                    [<$base_ty_name ServerKey>]
+                    [<$base_ty_name ServerKey>],
+                    [<$base_ty_name CompressedServerKey>]
                 };
             )*
 
             ///////////////////////
             /// Config
             ///////////////////////
-            #[derive(Clone, Debug)]
+            #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
             pub(crate) struct [<$base_struct_name Config>] {
                 $(
                     pub(crate) [<$name _params>]: Option<[<$base_ty_name Parameters>]>,
@@ -119,6 +120,36 @@ macro_rules! define_key_structs {
                     }
                 }
             }
+
+            ///////////////////////
+            /// Compressed Server Key
+            ///////////////////////
+            #[derive(Clone, serde::Deserialize, serde::Serialize)]
+            pub(crate) struct [<$base_struct_name CompressedServerKey>] {
+                $(
+                    pub(super) [<$name _key>]: Option<[<$base_ty_name CompressedServerKey>]>,
+                )*
+            }
+
+            impl [<$base_struct_name CompressedServerKey>] {
+                pub(crate) fn new(client_key: &[<$base_struct_name ClientKey>]) -> Self {
+                    Self {
+                        $(
+                            [<$name _key>]: client_key.[<$name _key>].as_ref().map(<[<$base_ty_name CompressedServerKey>]>::new),
+                        )*
+                    }
+                }
+            }
+
+            impl From<[<$base_struct_name CompressedServerKey>]> for [<$base_struct_name ServerKey>] {
+                fn from(compressed: [<$base_struct_name CompressedServerKey>]) -> Self {
+                    Self {
+                        $(
+                            [<$name _key>]: compressed.[<$name _key>].map(<[<$base_ty_name ServerKey>]>::from),
+                        )*
+                    }
+                }
+            }
         }
     }
 }
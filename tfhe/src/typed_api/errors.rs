@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::typed_api::keys::KeyHeader;
+
 /// Unwrap 'Extension' trait
 ///
 /// The goal of this trait is to add a method similar to `unwrap` to `Result<T, E>`
@@ -46,6 +48,12 @@ pub enum Type {
     #[cfg(feature = "integer")]
     FheUint16,
     #[cfg(feature = "integer")]
+    FheUint32,
+    #[cfg(feature = "integer")]
+    FheUint64,
+    #[cfg(feature = "integer")]
+    FheUint128,
+    #[cfg(feature = "integer")]
     FheUint256,
 }
 
@@ -58,8 +66,8 @@ impl Display for UninitializedServerKey {
         write!(
             f,
             "The server key for the type '{:?}' was not properly initialized\n\
-             Did you forget to call `set_server_key` in this thread or forget to
-             enable the type in the config ?
+             Did you forget to call `set_server_key` in this thread (or `set_global_server_key`)\n\
+             or forget to enable the type in the config ?
             ",
             self.0
         )
@@ -116,6 +124,27 @@ impl Display for OutOfRangeError {
 
 impl std::error::Error for OutOfRangeError {}
 
+/// A serialized key was produced by a build of the crate with a different version,
+/// key format or set of data type kinds (cargo features) than the current one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyHeaderMismatch {
+    pub expected: KeyHeader,
+    pub found: KeyHeader,
+}
+
+impl Display for KeyHeaderMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The key was serialized by an incompatible build of the crate \
+             (expected {:?}, found {:?})",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for KeyHeaderMismatch {}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -123,6 +152,9 @@ pub enum Error {
     UninitializedClientKey(Type),
     UninitializedPublicKey(Type),
     UninitializedServerKey(Type),
+    KeyHeaderMismatch(KeyHeaderMismatch),
+    /// The serialized key could not be read, the message comes from the deserializer
+    Deserialization(String),
 }
 
 impl From<OutOfRangeError> for Error {
@@ -149,6 +181,12 @@ impl From<UninitializedServerKey> for Error {
     }
 }
 
+impl From<KeyHeaderMismatch> for Error {
+    fn from(value: KeyHeaderMismatch) -> Self {
+        Self::KeyHeaderMismatch(value)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -164,6 +202,12 @@ impl Display for Error {
             Error::UninitializedServerKey(ty) => {
                 write!(f, "{}", UninitializedServerKey(*ty))
             }
+            Error::KeyHeaderMismatch(mismatch) => {
+                write!(f, "{mismatch}")
+            }
+            Error::Deserialization(message) => {
+                write!(f, "Failed to deserialize the key: {message}")
+            }
         }
     }
 }
@@ -2,6 +2,7 @@
 //! perform operations.
 use crate::typed_api::errors::{UninitializedServerKey, UnwrapResultExt};
 use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
 
 use crate::typed_api::keys::ServerKey;
 
@@ -14,6 +15,13 @@ thread_local! {
     static INTERNAL_KEYS: RefCell<ServerKey> = RefCell::new(ServerKey::default());
 }
 
+/// The process-wide keys, shared by all the threads.
+///
+/// They are only used for the types whose key is not set in the thread local keys,
+/// which allows threads created by libraries (eg rayon workers, tokio tasks)
+/// to do computations without having to initialize their own keys.
+static GLOBAL_KEYS: RwLock<Option<Arc<ServerKey>>> = RwLock::new(None);
+
 /// The function used to initialize internal keys.
 ///
 /// As each thread has its own set of keys,
@@ -77,18 +85,135 @@ where
     (result, keys)
 }
 
+/// Runs the function using the given keys for the computations done in the current thread.
+///
+/// Contrary to [with_server_key_as_context], the keys are borrowed, and the keys
+/// the thread had before the call are restored once the function returns (or panics).
+///
+/// This allows to write functions that take the server key as a parameter
+/// and do not depend on, nor modify, the keys of the thread calling them.
+///
+/// # Warning
+///
+/// - The keys are only set in the thread calling this function: the computations `f`
+///   sends to other threads (eg rayon workers, tokio tasks) do not use them, they use the
+///   process-wide keys set by [set_global_server_key] if any, or fail.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "boolean")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, with_server_key, ConfigBuilder, FheBool, ServerKey};
+///
+/// fn and(lhs: &FheBool, rhs: &FheBool, server_key: &ServerKey) -> FheBool {
+///     with_server_key(server_key, || lhs & rhs)
+/// }
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// let a = FheBool::encrypt(true, &client_key);
+/// let b = FheBool::encrypt(false, &client_key);
+///
+/// let c = and(&a, &b, &server_key);
+/// let decrypted = c.decrypt(&client_key);
+/// assert!(!decrypted);
+/// # }
+/// ```
+pub fn with_server_key<T, F>(keys: &ServerKey, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    // Restores the previous keys when dropped, even if `f` panics
+    struct RestoreOnDrop(ServerKey);
+
+    impl Drop for RestoreOnDrop {
+        fn drop(&mut self) {
+            set_server_key(std::mem::take(&mut self.0));
+        }
+    }
+
+    let previous_keys = INTERNAL_KEYS.with(|internal_keys| internal_keys.replace(keys.clone()));
+    let _guard = RestoreOnDrop(previous_keys);
+    f()
+}
+
+/// Sets the keys shared by all the threads of the process.
+///
+/// These keys are used by the threads that do not have their own keys
+/// set via [set_server_key] (or do not have the key for the type being used),
+/// which removes the need to initialize the keys in every thread.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "boolean")]
+/// # {
+/// use std::thread;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_global_server_key, ConfigBuilder, FheBool};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// set_global_server_key(server_key);
+///
+/// let a = FheBool::encrypt(true, &client_key);
+/// let b = FheBool::encrypt(true, &client_key);
+///
+/// let th = thread::spawn(move || {
+///     // No need to call set_server_key in this thread
+///     a & b
+/// });
+/// let c = th.join().unwrap();
+///
+/// let decrypted = c.decrypt(&client_key);
+/// assert!(decrypted);
+/// # }
+/// ```
+pub fn set_global_server_key(keys: ServerKey) {
+    *GLOBAL_KEYS.write().unwrap() = Some(Arc::new(keys));
+}
+
+/// Removes the keys shared by all the threads of the process, returning them if they were set.
+///
+/// The keys returned are only shared with the computations still running.
+pub fn unset_global_server_key() -> Option<Arc<ServerKey>> {
+    GLOBAL_KEYS.write().unwrap().take()
+}
+
 /// Convenience function that allows to write functions that needs to access the internal keys.
+///
+/// The `select` function returns the key needed by the caller, it is first looked for
+/// in the thread local keys, then in the process-wide keys.
 #[cfg(any(feature = "integer", feature = "shortint", feature = "boolean"))]
 #[inline]
-pub(crate) fn with_internal_keys<T, F>(func: F) -> T
+pub(crate) fn with_internal_keys<K, T, F>(
+    select: fn(&ServerKey) -> Option<&K>,
+    func: F,
+) -> Option<T>
 where
-    F: FnOnce(&ServerKey) -> T,
+    F: FnOnce(&K) -> T,
 {
+    let mut func = Some(func);
     // Should use `with_borrow` when its stabilized
-    INTERNAL_KEYS.with(|keys| {
-        let key = &*keys.borrow();
-        func(key)
-    })
+    let result = INTERNAL_KEYS.with(|keys| {
+        let keys = &*keys.borrow();
+        select(keys).map(|key| func.take().unwrap()(key))
+    });
+
+    match func {
+        // The key was not in the thread local keys
+        Some(func) => {
+            // The lock is not held during the computation,
+            // so that setting new global keys does not have to wait for it
+            let global_keys = GLOBAL_KEYS.read().unwrap().clone();
+            global_keys.as_deref().and_then(select).map(func)
+        }
+        None => result,
+    }
 }
 
 /// Helper macro to help reduce boiler plate
@@ -117,12 +242,11 @@ macro_rules! impl_with_global_key {
             where
                 F: FnOnce(&Self::Key) -> R,
             {
-                crate::typed_api::global_state::with_internal_keys(|keys| {
-                    keys$(.$member)*
-                        .as_ref()
-                        .map(func)
-                        .ok_or(crate::typed_api::errors::UninitializedServerKey($enum_variant))
-                })
+                crate::typed_api::global_state::with_internal_keys(
+                    |keys| keys$(.$member)*.as_ref(),
+                    func,
+                )
+                .ok_or(crate::typed_api::errors::UninitializedServerKey($enum_variant))
             }
         }
     }
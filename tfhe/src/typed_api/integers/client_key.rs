@@ -28,6 +28,16 @@ impl DecryptionKey<u64> for RadixClientKey {
     }
 }
 
+impl DecryptionKey<u128> for RadixClientKey {
+    type Ciphertext = RadixCiphertext;
+
+    fn decrypt(&self, ciphertext: &Self::Ciphertext) -> u128 {
+        let mut r = 0u128;
+        self.as_ref().decrypt_radix_into(ciphertext, &mut r);
+        r
+    }
+}
+
 impl DecryptionKey<U256> for RadixClientKey {
     type Ciphertext = RadixCiphertext;
 
@@ -5,6 +5,24 @@ define_key_structs! {
         uint12: FheUint12,
         uint14: FheUint14,
         uint16: FheUint16,
+        uint32: FheUint32,
+        uint64: FheUint64,
+        uint128: FheUint128,
         uint256: FheUint256,
     }
 }
+
+impl IntegerConfig {
+    /// The config used by [ConfigBuilder::all_enabled](crate::typed_api::ConfigBuilder::all_enabled)
+    ///
+    /// Each type has its own set of keys, so the 32, 64 and 128 bits types are left out:
+    /// generating their keys would multiply the time and memory needed to enable everything.
+    pub(crate) fn all_default_without_wide_types() -> Self {
+        Self {
+            uint32_params: None,
+            uint64_params: None,
+            uint128_params: None,
+            ..Self::all_default()
+        }
+    }
+}
@@ -1,6 +1,13 @@
-pub(crate) use keys::{IntegerClientKey, IntegerConfig, IntegerPublicKey, IntegerServerKey};
+pub(crate) use keys::{
+    IntegerClientKey, IntegerCompressedServerKey, IntegerConfig, IntegerPublicKey, IntegerServerKey,
+};
 pub use parameters::{CrtParameters, RadixParameters};
-pub use types::{FheUint10, FheUint12, FheUint14, FheUint16, FheUint256, FheUint8, GenericInteger};
+pub use types::{
+    CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
+    CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
+    CompressedFheUint8, CompressedGenericInteger, FheUint10, FheUint12, FheUint128, FheUint14,
+    FheUint16, FheUint256, FheUint32, FheUint64, FheUint8, GenericInteger, GenericIntegerBool,
+};
 
 mod client_key;
 mod keys;
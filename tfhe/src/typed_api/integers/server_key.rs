@@ -10,7 +10,8 @@ use crate::integer::wopbs::WopbsKey;
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct GenericIntegerServerKey<P: IntegerParameter> {
     pub(in crate::typed_api::integers) inner: P::InnerServerKey,
-    pub(in crate::typed_api::integers) wopbs_key: WopbsKey,
+    // None when the key was decompressed from a GenericIntegerCompressedServerKey
+    pub(in crate::typed_api::integers) wopbs_key: Option<WopbsKey>,
     _marker: PhantomData<P>,
 }
 
@@ -28,7 +29,57 @@ where
         );
         Self {
             inner,
-            wopbs_key,
+            wopbs_key: Some(wopbs_key),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<P> GenericIntegerServerKey<P>
+where
+    P: IntegerParameter,
+{
+    #[track_caller]
+    pub(in crate::typed_api::integers) fn wopbs_key(&self) -> &WopbsKey {
+        self.wopbs_key.as_ref().expect(
+            "The WoPBS key is not available, \
+            server keys decompressed from a CompressedServerKey do not support FheBootstrap",
+        )
+    }
+}
+
+/// The compressed version of [GenericIntegerServerKey]
+///
+/// Only the key used for the regular operations is compressed,
+/// the WoPBS key has no compressed form and is not part of it,
+/// so the decompressed key does not support the `FheBootstrap` operations.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericIntegerCompressedServerKey<P: IntegerParameter> {
+    pub(in crate::typed_api::integers) inner: crate::integer::CompressedServerKey,
+    _marker: PhantomData<P>,
+}
+
+impl<P> GenericIntegerCompressedServerKey<P>
+where
+    P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+    P::InnerClientKey: AsRef<crate::integer::ClientKey>,
+{
+    pub(super) fn new(client_key: &GenericIntegerClientKey<P>) -> Self {
+        Self {
+            inner: crate::integer::CompressedServerKey::new(client_key.inner.as_ref()),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<P> From<GenericIntegerCompressedServerKey<P>> for GenericIntegerServerKey<P>
+where
+    P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+{
+    fn from(compressed: GenericIntegerCompressedServerKey<P>) -> Self {
+        Self {
+            inner: compressed.inner.into(),
+            wopbs_key: None,
             _marker: Default::default(),
         }
     }
@@ -39,8 +90,19 @@ pub(super) trait SmartNeg<Ciphertext> {
     fn smart_neg(&self, lhs: Ciphertext) -> Self::Output;
 }
 
+/// The condition is a single shortint block encrypting 1 (true) or 0 (false)
+pub trait SmartIfThenElse<Ciphertext> {
+    type Output;
+    fn smart_if_then_else(
+        &self,
+        condition: &crate::shortint::CiphertextBig,
+        then_ct: Ciphertext,
+        else_ct: Ciphertext,
+    ) -> Self::Output;
+}
+
 macro_rules! define_smart_server_key_op {
-    ($op_name:ident) => {
+    (@without_assign $op_name:ident) => {
         paste::paste! {
             pub trait [< Smart $op_name >]<Lhs, Rhs> {
                 type Output;
@@ -51,7 +113,12 @@ macro_rules! define_smart_server_key_op {
                     rhs: Rhs,
                 ) -> Self::Output;
             }
+        }
+    };
+    ($op_name:ident) => {
+        define_smart_server_key_op!(@without_assign $op_name);
 
+        paste::paste! {
             pub trait [< Smart $op_name Assign >]<Lhs, Rhs> {
                 fn [< smart_ $op_name:lower _assign >](
                     &self,
@@ -71,6 +138,7 @@ macro_rules! define_smart_server_key_op {
 define_smart_server_key_op!(
     Add, Sub, Mul, BitAnd, BitOr, BitXor, Shl, Shr, Eq, Ge, Gt, Le, Lt, Max, Min
 );
+define_smart_server_key_op!(@without_assign Ne);
 
 macro_rules! impl_smart_op_for_tfhe_integer_server_key {
     ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident)) => {
@@ -88,6 +156,23 @@ macro_rules! impl_smart_op_for_tfhe_integer_server_key {
     };
 }
 
+// Comparisons return a single block encrypting 0 or 1
+macro_rules! impl_smart_comparison_op_for_tfhe_integer_server_key {
+    ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident)) => {
+        impl $smart_trait<&mut $ciphertext, &mut $ciphertext> for crate::integer::ServerKey {
+            type Output = crate::shortint::CiphertextBig;
+
+            fn $smart_trait_fn(
+                &self,
+                lhs: &mut $ciphertext,
+                rhs: &mut $ciphertext,
+            ) -> Self::Output {
+                self.$method(lhs, rhs)
+            }
+        }
+    };
+}
+
 macro_rules! impl_smart_assign_op_for_tfhe_integer_server_key {
     ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident)) => {
         impl $smart_trait<$ciphertext, &mut $ciphertext> for crate::integer::ServerKey {
@@ -110,6 +195,33 @@ macro_rules! impl_smart_scalar_op_for_tfhe_integer_server_key {
     };
 }
 
+// For the methods generic over the clear type, the scalar is forwarded as is
+macro_rules! impl_smart_generic_scalar_op_for_tfhe_integer_server_key {
+    ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident, $($scalar_type:ty),*)) => {
+        $(
+            impl $smart_trait<&mut $ciphertext, $scalar_type> for crate::integer::ServerKey {
+                type Output = $ciphertext;
+
+                fn $smart_trait_fn(&self, lhs: &mut $ciphertext, rhs: $scalar_type) -> Self::Output {
+                    self.$method(lhs, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_smart_generic_scalar_assign_op_for_tfhe_integer_server_key {
+    ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident, $($scalar_type:ty),*)) => {
+        $(
+            impl $smart_trait<$ciphertext, $scalar_type> for crate::integer::ServerKey {
+                fn $smart_trait_fn(&self, lhs: &mut $ciphertext, rhs: $scalar_type) {
+                    self.$method(lhs, rhs);
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! impl_smart_scalar_assign_op_for_tfhe_integer_server_key {
     ($smart_trait:ident($smart_trait_fn:ident) => ($ciphertext:ty, $method:ident)) => {
         impl $smart_trait<$ciphertext, u64> for crate::integer::ServerKey {
@@ -127,17 +239,30 @@ impl SmartNeg<&mut crate::integer::RadixCiphertext> for crate::integer::ServerKe
     }
 }
 
+impl SmartIfThenElse<&mut crate::integer::RadixCiphertext> for crate::integer::ServerKey {
+    type Output = crate::integer::RadixCiphertext;
+    fn smart_if_then_else(
+        &self,
+        condition: &crate::shortint::CiphertextBig,
+        then_ct: &mut crate::integer::RadixCiphertext,
+        else_ct: &mut crate::integer::RadixCiphertext,
+    ) -> Self::Output {
+        self.smart_if_then_else_parallelized(condition, then_ct, else_ct)
+    }
+}
+
 impl_smart_op_for_tfhe_integer_server_key!(SmartAdd(smart_add) => (crate::integer::RadixCiphertext, smart_add_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartSub(smart_sub) => (crate::integer::RadixCiphertext, smart_sub_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartMul(smart_mul) => (crate::integer::RadixCiphertext, smart_mul_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartBitAnd(smart_bitand) => (crate::integer::RadixCiphertext, smart_bitand_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartBitOr(smart_bitor) => (crate::integer::RadixCiphertext, smart_bitor_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartBitXor(smart_bitxor) => (crate::integer::RadixCiphertext, smart_bitxor_parallelized));
-impl_smart_op_for_tfhe_integer_server_key!(SmartEq(smart_eq) => (crate::integer::RadixCiphertext, smart_eq_parallelized));
-impl_smart_op_for_tfhe_integer_server_key!(SmartGe(smart_ge) => (crate::integer::RadixCiphertext, smart_ge_parallelized));
-impl_smart_op_for_tfhe_integer_server_key!(SmartGt(smart_gt) => (crate::integer::RadixCiphertext, smart_gt_parallelized));
-impl_smart_op_for_tfhe_integer_server_key!(SmartLe(smart_le) => (crate::integer::RadixCiphertext, smart_le_parallelized));
-impl_smart_op_for_tfhe_integer_server_key!(SmartLt(smart_lt) => (crate::integer::RadixCiphertext, smart_lt_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartEq(smart_eq) => (crate::integer::RadixCiphertext, smart_eq_as_block_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartNe(smart_ne) => (crate::integer::RadixCiphertext, smart_ne_as_block_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartGe(smart_ge) => (crate::integer::RadixCiphertext, smart_ge_as_block_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartGt(smart_gt) => (crate::integer::RadixCiphertext, smart_gt_as_block_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartLe(smart_le) => (crate::integer::RadixCiphertext, smart_le_as_block_parallelized));
+impl_smart_comparison_op_for_tfhe_integer_server_key!(SmartLt(smart_lt) => (crate::integer::RadixCiphertext, smart_lt_as_block_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartMax(smart_max) => (crate::integer::RadixCiphertext, smart_max_parallelized));
 impl_smart_op_for_tfhe_integer_server_key!(SmartMin(smart_min) => (crate::integer::RadixCiphertext, smart_min_parallelized));
 
@@ -148,15 +273,15 @@ impl_smart_assign_op_for_tfhe_integer_server_key!(SmartBitAndAssign(smart_bitand
 impl_smart_assign_op_for_tfhe_integer_server_key!(SmartBitOrAssign(smart_bitor_assign) => (crate::integer::RadixCiphertext, smart_bitor_assign_parallelized));
 impl_smart_assign_op_for_tfhe_integer_server_key!(SmartBitXorAssign(smart_bitxor_assign) => (crate::integer::RadixCiphertext, smart_bitxor_assign_parallelized));
 
-impl_smart_scalar_op_for_tfhe_integer_server_key!(SmartAdd(smart_add) => (crate::integer::RadixCiphertext, smart_scalar_add_parallelized));
-impl_smart_scalar_op_for_tfhe_integer_server_key!(SmartSub(smart_sub) => (crate::integer::RadixCiphertext, smart_scalar_sub_parallelized));
-impl_smart_scalar_op_for_tfhe_integer_server_key!(SmartMul(smart_mul) => (crate::integer::RadixCiphertext, smart_scalar_mul_parallelized));
+impl_smart_generic_scalar_op_for_tfhe_integer_server_key!(SmartAdd(smart_add) => (crate::integer::RadixCiphertext, smart_scalar_add_parallelized, u64, crate::integer::U256));
+impl_smart_generic_scalar_op_for_tfhe_integer_server_key!(SmartSub(smart_sub) => (crate::integer::RadixCiphertext, smart_scalar_sub_parallelized, u64, crate::integer::U256));
+impl_smart_generic_scalar_op_for_tfhe_integer_server_key!(SmartMul(smart_mul) => (crate::integer::RadixCiphertext, smart_scalar_mul_parallelized, u64, crate::integer::U256));
 impl_smart_scalar_op_for_tfhe_integer_server_key!(SmartShl(smart_shl) => (crate::integer::RadixCiphertext, unchecked_scalar_left_shift_parallelized));
 impl_smart_scalar_op_for_tfhe_integer_server_key!(SmartShr(smart_shr) => (crate::integer::RadixCiphertext, unchecked_scalar_right_shift_parallelized));
 
-impl_smart_scalar_assign_op_for_tfhe_integer_server_key!(SmartAddAssign(smart_add_assign) => (crate::integer::RadixCiphertext, smart_scalar_add_assign_parallelized));
-impl_smart_scalar_assign_op_for_tfhe_integer_server_key!(SmartSubAssign(smart_sub_assign) => (crate::integer::RadixCiphertext, smart_scalar_sub_assign_parallelized));
-impl_smart_scalar_assign_op_for_tfhe_integer_server_key!(SmartMulAssign(smart_mul_assign) => (crate::integer::RadixCiphertext, smart_scalar_mul_assign_parallelized));
+impl_smart_generic_scalar_assign_op_for_tfhe_integer_server_key!(SmartAddAssign(smart_add_assign) => (crate::integer::RadixCiphertext, smart_scalar_add_assign_parallelized, u64, crate::integer::U256));
+impl_smart_generic_scalar_assign_op_for_tfhe_integer_server_key!(SmartSubAssign(smart_sub_assign) => (crate::integer::RadixCiphertext, smart_scalar_sub_assign_parallelized, u64, crate::integer::U256));
+impl_smart_generic_scalar_assign_op_for_tfhe_integer_server_key!(SmartMulAssign(smart_mul_assign) => (crate::integer::RadixCiphertext, smart_scalar_mul_assign_parallelized, u64, crate::integer::U256));
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key!(SmartShlAssign(smart_shl_assign) => (crate::integer::RadixCiphertext, unchecked_scalar_left_shift_assign_parallelized));
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key!(SmartShrAssign(smart_shr_assign) => (crate::integer::RadixCiphertext, unchecked_scalar_right_shift_assign_parallelized));
 
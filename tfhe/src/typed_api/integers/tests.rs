@@ -1,5 +1,8 @@
 use crate::typed_api::prelude::*;
-use crate::typed_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+use crate::typed_api::{
+    generate_keys, set_server_key, ClientKey, CompressedFheUint8, CompressedServerKey,
+    ConfigBuilder, FheUint128, FheUint32, FheUint64, FheUint8,
+};
 
 #[test]
 fn test_quickstart_uint8() {
@@ -39,27 +42,154 @@ fn test_uint8_compare() {
     let b = FheUint8::encrypt(clear_b, &client_key);
 
     let result = &a.eq(&b);
-    let decrypted_result: u8 = result.decrypt(&client_key);
-    let clear_result = u8::from(clear_a == clear_b);
-    assert_eq!(decrypted_result, clear_result);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a == clear_b);
+
+    let result = &a.ne(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a != clear_b);
 
     let result = &a.le(&b);
-    let decrypted_result: u8 = result.decrypt(&client_key);
-    let clear_result = u8::from(clear_a <= clear_b);
-    assert_eq!(decrypted_result, clear_result);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a <= clear_b);
 
     let result = &a.lt(&b);
-    let decrypted_result: u8 = result.decrypt(&client_key);
-    let clear_result = u8::from(clear_a < clear_b);
-    assert_eq!(decrypted_result, clear_result);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a < clear_b);
 
     let result = &a.ge(&b);
-    let decrypted_result: u8 = result.decrypt(&client_key);
-    let clear_result = u8::from(clear_a >= clear_b);
-    assert_eq!(decrypted_result, clear_result);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a >= clear_b);
 
     let result = &a.gt(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a > clear_b);
+}
+
+#[test]
+fn test_uint8_compare_result_ops() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+    let clear_c = 200u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+    let c = FheUint8::encrypt(clear_c, &client_key);
+
+    let is_between = a.lt(&b) & b.lt(&c);
+    let decrypted_result: bool = is_between.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a < clear_b && clear_b < clear_c);
+
+    let result = !&is_between | a.eq(&c);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        !(clear_a < clear_b && clear_b < clear_c) || clear_a == clear_c
+    );
+
+    let result = a.gt(&b) ^ b.ne(&c);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, (clear_a > clear_b) ^ (clear_b != clear_c));
+
+    let result = a.gt(&c).select(&a, &c);
     let decrypted_result: u8 = result.decrypt(&client_key);
-    let clear_result = u8::from(clear_a >= clear_b);
-    assert_eq!(decrypted_result, clear_result);
+    assert_eq!(decrypted_result, clear_a.max(clear_c));
+
+    let result = (!a.gt(&c)).select(&a, &c);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.min(clear_c));
+
+    let result = a.max(&b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.max(clear_b));
+
+    let result = a.min(&b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.min(clear_b));
+}
+
+#[test]
+fn test_uint32_uint64_uint128() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_uint32()
+        .enable_default_uint64()
+        .enable_default_uint128()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 3_000_000_000u32;
+    let clear_b = 1_500_000_000u32;
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::try_encrypt(clear_b, &client_key).unwrap();
+    let result = a + b;
+    let decrypted_result: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+
+    let clear_a = u64::MAX - 12;
+    let clear_b = 1u64 << 40;
+    let a = FheUint64::encrypt(clear_a, &client_key);
+    let b = FheUint64::try_encrypt(clear_b, &client_key).unwrap();
+    let result = a - b;
+    let decrypted_result: u64 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a - clear_b);
+
+    let clear_a = u128::MAX - 7;
+    let clear_b = 9u128;
+    let a = FheUint128::encrypt(clear_a, &client_key);
+    let b = FheUint128::try_encrypt(clear_b, &client_key).unwrap();
+    let result = a + b;
+    let decrypted_result: u128 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+}
+
+#[test]
+fn test_all_enabled_leaves_out_wide_types() {
+    let config = ConfigBuilder::all_enabled().build();
+    let integer_config = &config.integer_config;
+
+    assert!(integer_config.uint8_params.is_some());
+    assert!(integer_config.uint256_params.is_some());
+    assert!(integer_config.uint32_params.is_none());
+    assert!(integer_config.uint64_params.is_none());
+    assert!(integer_config.uint128_params.is_none());
+
+    let config = ConfigBuilder::all_enabled().enable_default_uint32().build();
+    assert!(config.integer_config.uint32_params.is_some());
+}
+
+#[test]
+fn test_uint8_compressed() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    let client_key = ClientKey::generate(config);
+
+    let compressed_server_key = CompressedServerKey::new(&client_key);
+    let compressed_server_key: CompressedServerKey =
+        bincode::deserialize(&bincode::serialize(&compressed_server_key).unwrap()).unwrap();
+    set_server_key(compressed_server_key.decompress());
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let compressed_a = CompressedFheUint8::try_encrypt(clear_a, &client_key).unwrap();
+    let compressed_b = CompressedFheUint8::try_encrypt(clear_b, &client_key).unwrap();
+    let compressed_b: CompressedFheUint8 =
+        bincode::deserialize(&bincode::serialize(&compressed_b).unwrap()).unwrap();
+
+    let a = FheUint8::from(compressed_a);
+    let b = FheUint8::from(compressed_b);
+
+    let decrypted: u8 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+
+    let decrypted: u8 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
 }
@@ -7,6 +7,7 @@ use std::ops::{
 
 use crate::integer::wopbs::WopbsKey;
 use crate::integer::{CrtCiphertext, RadixCiphertext, U256};
+use crate::shortint::CiphertextBig;
 use crate::typed_api::global_state::WithGlobalKey;
 use crate::typed_api::integers::client_key::GenericIntegerClientKey;
 use crate::typed_api::integers::parameters::{
@@ -17,9 +18,10 @@ use crate::typed_api::integers::public_key::GenericIntegerPublicKey;
 use crate::typed_api::integers::server_key::{
     GenericIntegerServerKey, SmartAdd, SmartAddAssign, SmartBitAnd, SmartBitAndAssign, SmartBitOr,
     SmartBitOrAssign, SmartBitXor, SmartBitXorAssign, SmartEq, SmartGe, SmartGt, SmartLe, SmartLt,
-    SmartMax, SmartMin, SmartMul, SmartMulAssign, SmartNeg, SmartShl, SmartShlAssign, SmartShr,
-    SmartShrAssign, SmartSub, SmartSubAssign,
+    SmartMax, SmartMin, SmartMul, SmartMulAssign, SmartNe, SmartNeg, SmartShl, SmartShlAssign,
+    SmartShr, SmartShrAssign, SmartSub, SmartSubAssign,
 };
+use crate::typed_api::integers::types::GenericIntegerBool;
 use crate::typed_api::internal_traits::{DecryptionKey, EncryptionKey};
 use crate::typed_api::keys::{RefKeyFromKeyChain, RefKeyFromPublicKeyChain};
 use crate::typed_api::traits::{FheBootstrap, FheDecrypt, FheEq, FheOrd, FheTryEncrypt};
@@ -84,6 +86,18 @@ where
     }
 }
 
+impl<P> FheDecrypt<u128> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = GenericIntegerClientKey<P>>,
+    P::InnerClientKey: DecryptionKey<u128, Ciphertext = P::InnerCiphertext>,
+{
+    fn decrypt(&self, key: &ClientKey) -> u128 {
+        let key = self.id.unwrapped_ref_key(key);
+        key.inner.decrypt(&self.ciphertext.borrow())
+    }
+}
+
 impl<P> FheDecrypt<U256> for GenericInteger<P>
 where
     P: IntegerParameter,
@@ -203,12 +217,16 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
     P::InnerServerKey: for<'a> SmartEq<
-        &'a mut P::InnerCiphertext,
-        &'a mut P::InnerCiphertext,
-        Output = P::InnerCiphertext,
-    >,
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = CiphertextBig,
+        > + for<'a> SmartNe<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = CiphertextBig,
+        >,
 {
-    type Output = Self;
+    type Output = GenericIntegerBool<P>;
 
     fn eq(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|server_key| {
@@ -228,7 +246,28 @@ where
             }
         });
 
-        GenericInteger::new(inner_result, self.id)
+        GenericIntegerBool::new(inner_result, self.id)
+    }
+
+    fn ne(&self, rhs: B) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            let borrowed = rhs.borrow();
+            if std::ptr::eq(self, borrowed) {
+                let cloned = (*borrowed).clone();
+                let r = server_key.inner.smart_ne(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_ne(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut borrowed.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericIntegerBool::new(inner_result, self.id)
     }
 }
 
@@ -241,22 +280,22 @@ where
     P::InnerServerKey: for<'a> SmartGe<
             &'a mut P::InnerCiphertext,
             &'a mut P::InnerCiphertext,
-            Output = P::InnerCiphertext,
+            Output = CiphertextBig,
         > + for<'a> SmartGt<
             &'a mut P::InnerCiphertext,
             &'a mut P::InnerCiphertext,
-            Output = P::InnerCiphertext,
+            Output = CiphertextBig,
         > + for<'a> SmartLe<
             &'a mut P::InnerCiphertext,
             &'a mut P::InnerCiphertext,
-            Output = P::InnerCiphertext,
+            Output = CiphertextBig,
         > + for<'a> SmartLt<
             &'a mut P::InnerCiphertext,
             &'a mut P::InnerCiphertext,
-            Output = P::InnerCiphertext,
+            Output = CiphertextBig,
         >,
 {
-    type Output = Self;
+    type Output = GenericIntegerBool<P>;
 
     fn lt(&self, other: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|server_key| {
@@ -276,7 +315,7 @@ where
             }
         });
 
-        GenericInteger::new(inner_result, self.id)
+        GenericIntegerBool::new(inner_result, self.id)
     }
 
     fn le(&self, other: B) -> Self::Output {
@@ -297,7 +336,7 @@ where
             }
         });
 
-        GenericInteger::new(inner_result, self.id)
+        GenericIntegerBool::new(inner_result, self.id)
     }
 
     fn gt(&self, other: B) -> Self::Output {
@@ -318,7 +357,7 @@ where
             }
         });
 
-        GenericInteger::new(inner_result, self.id)
+        GenericIntegerBool::new(inner_result, self.id)
     }
 
     fn ge(&self, other: B) -> Self::Output {
@@ -339,7 +378,7 @@ where
             }
         });
 
-        GenericInteger::new(inner_result, self.id)
+        GenericIntegerBool::new(inner_result, self.id)
     }
 }
 
@@ -438,7 +477,7 @@ where
         func: F,
     ) -> GenericInteger<P> {
         let ct = ct_in.ciphertext.borrow();
-        let res = wopbs_radix(&self.wopbs_key, &self.inner, &ct, func);
+        let res = wopbs_radix(self.wopbs_key(), &self.inner, &ct, func);
         GenericInteger::<P>::new(res, ct_in.id)
     }
 
@@ -451,7 +490,7 @@ where
         let lhs_ct = lhs.ciphertext.borrow();
         let rhs_ct = rhs.ciphertext.borrow();
 
-        let res_ct = bivariate_wopbs_radix(&self.wopbs_key, &self.inner, &lhs_ct, &rhs_ct, func);
+        let res_ct = bivariate_wopbs_radix(self.wopbs_key(), &self.inner, &lhs_ct, &rhs_ct, func);
 
         GenericInteger::<P>::new(res_ct, lhs.id)
     }
@@ -467,7 +506,7 @@ where
         func: F,
     ) -> GenericInteger<P> {
         let ct = ct_in.ciphertext.borrow();
-        let res = wopbs_crt(&self.wopbs_key, &self.inner, &ct, func);
+        let res = wopbs_crt(self.wopbs_key(), &self.inner, &ct, func);
         GenericInteger::<P>::new(res, ct_in.id)
     }
 
@@ -480,7 +519,7 @@ where
         let lhs_ct = lhs.ciphertext.borrow();
         let rhs_ct = rhs.ciphertext.borrow();
 
-        let res_ct = bivariate_wopbs_crt(&self.wopbs_key, &self.inner, &lhs_ct, &rhs_ct, func);
+        let res_ct = bivariate_wopbs_crt(self.wopbs_key(), &self.inner, &lhs_ct, &rhs_ct, func);
         GenericInteger::<P>::new(res_ct, lhs.id)
     }
 }
@@ -649,7 +688,7 @@ macro_rules! generic_integer_impl_operation_assign (
 );
 
 macro_rules! generic_integer_impl_scalar_operation {
-    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*)) => {
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*) as $inner_scalar_type:ty) => {
         $(
             impl<P> $trait_name<$scalar_type> for GenericInteger<P>
             where
@@ -657,7 +696,7 @@ macro_rules! generic_integer_impl_scalar_operation {
                 P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
                 P::InnerServerKey: for<'a> $smart_trait<
                                             &'a mut P::InnerCiphertext,
-                                            u64,
+                                            $inner_scalar_type,
                                             Output=P::InnerCiphertext>,
             {
                 type Output = GenericInteger<P>;
@@ -673,7 +712,7 @@ macro_rules! generic_integer_impl_scalar_operation {
                 P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
                 P::InnerServerKey: for<'a> $smart_trait<
                                             &'a mut P::InnerCiphertext,
-                                            u64,
+                                            $inner_scalar_type,
                                             Output=P::InnerCiphertext>,
             {
                 type Output = GenericInteger<P>;
@@ -682,7 +721,7 @@ macro_rules! generic_integer_impl_scalar_operation {
                     let ciphertext = self.id.with_unwrapped_global(|key| {
                         key.inner.$key_method(
                             &mut self.ciphertext.borrow_mut(),
-                            u64::from(rhs)
+                            <$inner_scalar_type>::from(rhs)
                         )
                     });
 
@@ -694,19 +733,19 @@ macro_rules! generic_integer_impl_scalar_operation {
 }
 
 macro_rules! generic_integer_impl_scalar_operation_assign {
-    ($trait_name:ident($trait_method:ident,$smart_assign_trait:ident) => $key_method:ident($($scalar_type:ty),*)) => {
+    ($trait_name:ident($trait_method:ident,$smart_assign_trait:ident) => $key_method:ident($($scalar_type:ty),*) as $inner_scalar_type:ty) => {
         $(
             impl<P> $trait_name<$scalar_type> for GenericInteger<P>
                 where
                     P: IntegerParameter,
                     P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
-                    P::InnerServerKey: for<'a> $smart_assign_trait<P::InnerCiphertext, u64>,
+                    P::InnerServerKey: for<'a> $smart_assign_trait<P::InnerCiphertext, $inner_scalar_type>,
             {
                 fn $trait_method(&mut self, rhs: $scalar_type) {
                     self.id.with_unwrapped_global(|key| {
                         key.inner.$key_method(
                             &mut self.ciphertext.borrow_mut(),
-                            u64::from(rhs)
+                            <$inner_scalar_type>::from(rhs)
                         )
                     });
                 }
@@ -729,17 +768,23 @@ generic_integer_impl_operation_assign!(BitAndAssign(bitand_assign,&=, SmartBitAn
 generic_integer_impl_operation_assign!(BitOrAssign(bitor_assign,|=, SmartBitOrAssign) => smart_bitor_assign);
 generic_integer_impl_operation_assign!(BitXorAssign(bitxor_assign,^=, SmartBitXorAssign) => smart_bitxor_assign);
 
-generic_integer_impl_scalar_operation!(Add(add, SmartAdd) => smart_add(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Shl(shl, SmartShl) => smart_shl(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Shr(shr, SmartShr) => smart_shr(u8, u16, u32, u64));
-
-generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign, SmartAddAssign) => smart_add_assign(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign, SmartSubAssign) => smart_sub_assign(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign, SmartMulAssign) => smart_mul_assign(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign, SmartShlAssign) => smart_shl_assign(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign, SmartShrAssign) => smart_shr_assign(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation!(Add(add, SmartAdd) => smart_add(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation!(Add(add, SmartAdd) => smart_add(u128, U256) as U256);
+generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u128, U256) as U256);
+generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u128, U256) as U256);
+generic_integer_impl_scalar_operation!(Shl(shl, SmartShl) => smart_shl(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation!(Shr(shr, SmartShr) => smart_shr(u8, u16, u32, u64) as u64);
+
+generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign, SmartAddAssign) => smart_add_assign(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign, SmartAddAssign) => smart_add_assign(u128, U256) as U256);
+generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign, SmartSubAssign) => smart_sub_assign(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign, SmartSubAssign) => smart_sub_assign(u128, U256) as U256);
+generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign, SmartMulAssign) => smart_mul_assign(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign, SmartMulAssign) => smart_mul_assign(u128, U256) as U256);
+generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign, SmartShlAssign) => smart_shl_assign(u8, u16, u32, u64) as u64);
+generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign, SmartShrAssign) => smart_shr_assign(u8, u16, u32, u64) as u64);
 
 impl<P> Neg for GenericInteger<P>
 where
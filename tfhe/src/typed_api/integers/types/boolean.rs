@@ -0,0 +1,182 @@
+use std::borrow::Borrow;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::shortint::CiphertextBig;
+use crate::typed_api::global_state::WithGlobalKey;
+use crate::typed_api::integers::client_key::GenericIntegerClientKey;
+use crate::typed_api::integers::parameters::IntegerParameter;
+use crate::typed_api::integers::server_key::{GenericIntegerServerKey, SmartIfThenElse};
+use crate::typed_api::keys::RefKeyFromKeyChain;
+use crate::typed_api::traits::FheDecrypt;
+use crate::typed_api::ClientKey;
+
+use super::base::GenericInteger;
+
+/// The encrypted boolean returned by comparisons of [GenericInteger]
+///
+/// It is a single block encrypting either 1 (true) or 0 (false),
+/// and uses the keys of the integer type that produced it.
+///
+/// It supports the `&`, `|`, `^` and `!` operators,
+/// and can be used to [select](Self::select) between two integers.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(128u8, &client_key);
+///
+/// let is_lower = a.lt(&b);
+/// let is_different = a.ne(&b);
+/// let both = is_lower & !is_different;
+/// let decrypted: bool = both.decrypt(&client_key);
+/// assert!(!decrypted);
+///
+/// let max = a.lt(&b).select(&b, &a);
+/// let decrypted: u8 = max.decrypt(&client_key);
+/// assert_eq!(decrypted, 128);
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericIntegerBool<P: IntegerParameter> {
+    pub(in crate::typed_api::integers) ciphertext: CiphertextBig,
+    pub(in crate::typed_api::integers) id: P::Id,
+}
+
+impl<P> GenericIntegerBool<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::typed_api::integers) fn new(ciphertext: CiphertextBig, id: P::Id) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<P> GenericIntegerBool<P>
+where
+    P: IntegerParameter,
+    GenericInteger<P>: Clone,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey:
+        for<'a> SmartIfThenElse<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Returns `then_value` if self encrypts true, `else_value` otherwise
+    pub fn select(
+        &self,
+        then_value: &GenericInteger<P>,
+        else_value: &GenericInteger<P>,
+    ) -> GenericInteger<P> {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(then_value, else_value) {
+                let cloned = else_value.clone();
+                let r = server_key.inner.smart_if_then_else(
+                    &self.ciphertext,
+                    &mut then_value.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_if_then_else(
+                    &self.ciphertext,
+                    &mut then_value.ciphertext.borrow_mut(),
+                    &mut else_value.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> FheDecrypt<bool> for GenericIntegerBool<P>
+where
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = GenericIntegerClientKey<P>>,
+    P::InnerClientKey: AsRef<crate::integer::ClientKey>,
+{
+    fn decrypt(&self, key: &ClientKey) -> bool {
+        let key = self.id.unwrapped_ref_key(key);
+        key.inner.as_ref().decrypt_one_block(&self.ciphertext) != 0
+    }
+}
+
+// The blocks only ever encrypt 0 or 1, so the unchecked shortint
+// operations can be used without any carry cleaning.
+macro_rules! integer_bool_impl_operation (
+    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+        impl<P, B> $trait_name<B> for GenericIntegerBool<P>
+        where
+            B: Borrow<Self>,
+            P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+            P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+        {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $trait_name<B>>::$trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $trait_name<B> for &GenericIntegerBool<P>
+        where
+            B: Borrow<GenericIntegerBool<P>>,
+            P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+            P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+        {
+            type Output = GenericIntegerBool<P>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|server_key| {
+                    server_key
+                        .inner
+                        .key
+                        .$key_method(&self.ciphertext, &rhs.borrow().ciphertext)
+                });
+                GenericIntegerBool::new(ciphertext, self.id)
+            }
+        }
+    }
+);
+
+integer_bool_impl_operation!(BitAnd(bitand) => unchecked_bitand);
+integer_bool_impl_operation!(BitOr(bitor) => unchecked_bitor);
+integer_bool_impl_operation!(BitXor(bitxor) => unchecked_bitxor);
+
+impl<P> Not for GenericIntegerBool<P>
+where
+    P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+impl<P> Not for &GenericIntegerBool<P>
+where
+    P: IntegerParameter<InnerServerKey = crate::integer::ServerKey>,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+{
+    type Output = GenericIntegerBool<P>;
+
+    fn not(self) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|server_key| {
+            let key = &server_key.inner.key;
+            let accumulator = key.generate_accumulator(|x| u64::from(x == 0));
+            key.apply_lookup_table(&self.ciphertext, &accumulator)
+        });
+        GenericIntegerBool::new(ciphertext, self.id)
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use crate::integer::ciphertext::CompressedRadixCiphertext;
+use crate::integer::{RadixCiphertext, RadixClientKey, U256};
+use crate::typed_api::integers::client_key::GenericIntegerClientKey;
+use crate::typed_api::integers::parameters::IntegerParameter;
+use crate::typed_api::keys::RefKeyFromKeyChain;
+use crate::typed_api::traits::FheTryEncrypt;
+use crate::typed_api::ClientKey;
+
+use super::base::GenericInteger;
+
+/// The compressed version of a radix [GenericInteger]
+///
+/// It takes less space than its uncompressed counterpart, which makes it
+/// well suited to be sent to a server, but it has to be converted into
+/// a [GenericInteger] before doing any computation.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, CompressedFheUint8, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let compressed = CompressedFheUint8::try_encrypt(42u8, &client_key).unwrap();
+/// let a = FheUint8::from(compressed);
+///
+/// let decrypted: u8 = (a + 1u8).decrypt(&client_key);
+/// assert_eq!(decrypted, 43);
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedGenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::typed_api::integers) ciphertext: CompressedRadixCiphertext,
+    pub(in crate::typed_api::integers) id: P::Id,
+}
+
+impl<P> CompressedGenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::typed_api::integers) fn new(
+        ciphertext: CompressedRadixCiphertext,
+        id: P::Id,
+    ) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<P> From<CompressedGenericInteger<P>> for GenericInteger<P>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertext>,
+{
+    fn from(value: CompressedGenericInteger<P>) -> Self {
+        let inner = value.ciphertext.into();
+        Self::new(inner, value.id)
+    }
+}
+
+impl<P, T> FheTryEncrypt<T, ClientKey> for CompressedGenericInteger<P>
+where
+    T: Into<U256>,
+    P: IntegerParameter<InnerClientKey = RadixClientKey>,
+    P::Id: RefKeyFromKeyChain<Key = GenericIntegerClientKey<P>> + Default,
+{
+    type Error = crate::typed_api::errors::Error;
+
+    fn try_encrypt(value: T, key: &ClientKey) -> Result<Self, Self::Error> {
+        let value = value.into();
+        let id = P::Id::default();
+        let key = id.ref_key(key)?;
+        let ciphertext = key
+            .inner
+            .as_ref()
+            .encrypt_radix_compressed(value, key.inner.num_blocks());
+        Ok(Self::new(ciphertext, id))
+    }
+}
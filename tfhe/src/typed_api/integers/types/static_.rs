@@ -6,12 +6,15 @@ use crate::typed_api::integers::parameters::{
     StaticIntegerParameter, StaticRadixParameter,
 };
 use crate::typed_api::integers::public_key::GenericIntegerPublicKey;
-use crate::typed_api::integers::server_key::GenericIntegerServerKey;
+use crate::typed_api::integers::server_key::{
+    GenericIntegerCompressedServerKey, GenericIntegerServerKey,
+};
 use crate::typed_api::keys::RefKeyFromKeyChain;
 use crate::typed_api::traits::{FheDecrypt, FheEncrypt};
 use crate::typed_api::ClientKey;
 
 use super::base::GenericInteger;
+use super::compressed::CompressedGenericInteger;
 #[cfg(feature = "internal-keycache")]
 use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
 use crate::integer::wopbs::WopbsKey;
@@ -167,11 +170,18 @@ macro_rules! static_int_type {
             #[doc = concat!("ServerKey for the [", stringify!($name), "] data type.")]
             pub(in crate::typed_api::integers) type [<$name ServerKey>] = GenericIntegerServerKey<[<$name Parameters>]>;
 
+            #[doc = concat!("CompressedServerKey for the [", stringify!($name), "] data type.")]
+            pub(in crate::typed_api::integers) type [<$name CompressedServerKey>] = GenericIntegerCompressedServerKey<[<$name Parameters>]>;
+
             #[doc = concat!("An unsigned integer type with", stringify!($num_bits), "bits")]
             $(#[$outer])*
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type $name = GenericInteger<[<$name Parameters>]>;
 
+            #[doc = concat!("The compressed version of [", stringify!($name), "]")]
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<Compressed $name>] = CompressedGenericInteger<[<$name Parameters>]>;
+
             impl_ref_key_from_keychain!(
                 for <[<$name Parameters>] as ParameterType>::Id {
                     key_type: [<$name ClientKey>],
@@ -364,6 +374,42 @@ static_int_type! {
     }
 }
 
+static_int_type! {
+    {
+        num_bits: 32,
+        keychain_member: integer_key.uint32_key,
+        parameters: Radix {
+            block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2,
+            num_block: 16,
+            wopbs_block_parameters: crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 64,
+        keychain_member: integer_key.uint64_key,
+        parameters: Radix {
+            block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2,
+            num_block: 32,
+            wopbs_block_parameters: crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 128,
+        keychain_member: integer_key.uint128_key,
+        parameters: Radix {
+            block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2,
+            num_block: 64,
+            wopbs_block_parameters: crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2,
+        },
+    }
+}
+
 static_int_type! {
     {
         num_bits: 256,
@@ -413,3 +459,45 @@ impl FheDecrypt<u16> for FheUint16 {
         key.inner.decrypt(&self.ciphertext.borrow()) as u16
     }
 }
+
+impl FheEncrypt<u32, ClientKey> for FheUint32 {
+    #[track_caller]
+    fn encrypt(value: u32, key: &ClientKey) -> Self {
+        let id = <FheUint32Parameters as ParameterType>::Id::default();
+        let key = id.unwrapped_ref_key(key);
+        let ciphertext = key.inner.encrypt(u64::from(value));
+        Self::new(ciphertext, id)
+    }
+}
+
+impl FheDecrypt<u32> for FheUint32 {
+    #[track_caller]
+    fn decrypt(&self, key: &ClientKey) -> u32 {
+        let id = <FheUint32Parameters as ParameterType>::Id::default();
+        let key = id.unwrapped_ref_key(key);
+        key.inner.decrypt(&self.ciphertext.borrow()) as u32
+    }
+}
+
+impl FheEncrypt<u64, ClientKey> for FheUint64 {
+    #[track_caller]
+    fn encrypt(value: u64, key: &ClientKey) -> Self {
+        let id = <FheUint64Parameters as ParameterType>::Id::default();
+        let key = id.unwrapped_ref_key(key);
+        let ciphertext = key.inner.encrypt(value);
+        Self::new(ciphertext, id)
+    }
+}
+
+impl FheEncrypt<u128, ClientKey> for FheUint128 {
+    #[track_caller]
+    fn encrypt(value: u128, key: &ClientKey) -> Self {
+        let id = <FheUint128Parameters as ParameterType>::Id::default();
+        let key = id.unwrapped_ref_key(key);
+        let ciphertext = key
+            .inner
+            .as_ref()
+            .encrypt_radix(value, key.inner.num_blocks());
+        Self::new(ciphertext, id)
+    }
+}
@@ -19,8 +19,12 @@ use super::ServerKey;
 /// as they will allow to encrypt and decrypt data.
 ///
 /// This key **MUST NOT** be sent to the server.
+///
+/// It can be serialized, in which case the [Config] it was generated with
+/// is also recorded (see [KeyHeader](crate::typed_api::KeyHeader)).
 #[derive(Clone, Debug)]
 pub struct ClientKey {
+    pub(crate) config: Config,
     #[cfg(feature = "boolean")]
     pub(crate) boolean_key: BooleanClientKey,
     #[cfg(feature = "shortint")]
@@ -35,6 +39,7 @@ impl ClientKey {
         #[allow(unused_variables)]
         let config: Config = config.into();
         ClientKey {
+            config: config.clone(),
             #[cfg(feature = "boolean")]
             boolean_key: BooleanClientKey::from(config.boolean_config),
             #[cfg(feature = "shortint")]
@@ -44,6 +49,11 @@ impl ClientKey {
         }
     }
 
+    /// Returns the config this key was generated with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     /// Generates a new ServerKeyChain
     ///
     /// The `ServerKeyChain` generated is meant to be used to initialize the global state
@@ -53,6 +63,15 @@ impl ClientKey {
     }
 }
 
+impl_serde_for_key_with_header!(
+    ClientKey {
+        boolean_key: BooleanClientKey,
+        shortint_key: ShortIntClientKey,
+        integer_key: IntegerClientKey,
+    }
+    wrapped_with: std::convert::identity
+);
+
 /// Trait to be implemented on the client key types that have a corresponding member
 /// in the `ClientKeyChain`.
 ///
@@ -0,0 +1,246 @@
+//! This module defines the header written in front of serialized keys
+//!
+//! The layout of the keys depends on the version of the crate and on the cargo features
+//! (`boolean`, `shortint`, `integer`) it was built with, so a key serialized by one build
+//! may not be readable by another build.
+//!
+//! To detect this, the keys are serialized as a sequence of:
+//!
+//! 1. a [KeyHeader], whose layout does not depend on the features,
+//! 2. the [Config] the keys were generated with,
+//! 3. the key of each data type kind enabled in the build.
+//!
+//! When deserializing, the header is checked before anything else is read.
+use std::cell::Cell;
+
+use serde::de::{Error as _, SeqAccess};
+use serde::{Deserialize, Serialize};
+
+use crate::typed_api::config::Config;
+use crate::typed_api::errors::KeyHeaderMismatch;
+
+/// Version of the layout of the serialized keys
+///
+/// It has to be incremented each time the layout of the keys changes
+/// (e.g. when a data type is added to the config).
+const KEY_FORMAT_VERSION: u32 = 1;
+
+/// Records the build that serialized a key
+///
+/// Keys can only be deserialized by a build with the same header.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KeyHeader {
+    /// Version of the layout of the serialized keys
+    pub format_version: u32,
+    /// Version of the crate
+    pub crate_version: String,
+    pub boolean: bool,
+    pub shortint: bool,
+    pub integer: bool,
+}
+
+impl KeyHeader {
+    /// The header of the keys serialized by the current build
+    pub fn current() -> Self {
+        Self {
+            format_version: KEY_FORMAT_VERSION,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            boolean: cfg!(feature = "boolean"),
+            shortint: cfg!(feature = "shortint"),
+            integer: cfg!(feature = "integer"),
+        }
+    }
+
+    /// Checks that a key with this header can be deserialized by the current build
+    ///
+    /// As the header is the first element of a serialized key, with formats such as `bincode`,
+    /// it can be deserialized alone to get a typed error before trying to deserialize the key.
+    pub fn check_compatibility(&self) -> Result<(), KeyHeaderMismatch> {
+        let expected = Self::current();
+        if *self == expected {
+            Ok(())
+        } else {
+            Err(KeyHeaderMismatch {
+                expected,
+                found: self.clone(),
+            })
+        }
+    }
+}
+
+/// Number of elements in the sequence of a serialized key
+pub(super) const fn serialized_len() -> usize {
+    2 + cfg!(feature = "boolean") as usize
+        + cfg!(feature = "shortint") as usize
+        + cfg!(feature = "integer") as usize
+}
+
+/// Reads and checks the header, then reads the config
+///
+/// If the header does not match, the mismatch is also stored in `mismatch` when given,
+/// as the deserializer's error type can only carry it as a message.
+pub(super) fn deserialize_header_and_config<'de, A>(
+    seq: &mut A,
+    mismatch: Option<&Cell<Option<KeyHeaderMismatch>>>,
+) -> Result<Config, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let header = seq
+        .next_element::<KeyHeader>()?
+        .ok_or_else(|| A::Error::invalid_length(0, &"a key header"))?;
+    if let Err(error) = header.check_compatibility() {
+        let message = error.to_string();
+        if let Some(mismatch) = mismatch {
+            mismatch.set(Some(error));
+        }
+        return Err(A::Error::custom(message));
+    }
+
+    seq.next_element::<Config>()?
+        .ok_or_else(|| A::Error::invalid_length(1, &"a config"))
+}
+
+/// Reads the next key of the sequence
+#[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
+pub(super) fn next_key<'de, A, K>(seq: &mut A, name: &'static str) -> Result<K, A::Error>
+where
+    A: SeqAccess<'de>,
+    K: Deserialize<'de>,
+{
+    seq.next_element::<K>()?
+        .ok_or_else(|| A::Error::missing_field(name))
+}
+
+/// Implements `Serialize` and `Deserialize` for one of the keys,
+/// following the layout described in this module,
+/// as well as a `deserialize_checked` function returning typed errors.
+///
+/// It expects:
+/// - The key type, which must have a `config` member and one member per data type kind
+/// - The type of each of these members, once unwrapped
+/// - The function used to wrap the deserialized members
+macro_rules! impl_serde_for_key_with_header {
+    (
+        $key:ident {
+            boolean_key: $boolean_key:ty,
+            shortint_key: $shortint_key:ty,
+            integer_key: $integer_key:ty,
+        }
+        wrapped_with: $wrap:path
+    ) => {
+        impl serde::Serialize for $key {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeTuple;
+
+                let mut tuple =
+                    serializer.serialize_tuple(crate::typed_api::keys::header::serialized_len())?;
+                tuple.serialize_element(&crate::typed_api::keys::KeyHeader::current())?;
+                tuple.serialize_element(&self.config)?;
+                #[cfg(feature = "boolean")]
+                tuple.serialize_element(<_ as std::borrow::Borrow<$boolean_key>>::borrow(
+                    &self.boolean_key,
+                ))?;
+                #[cfg(feature = "shortint")]
+                tuple.serialize_element(<_ as std::borrow::Borrow<$shortint_key>>::borrow(
+                    &self.shortint_key,
+                ))?;
+                #[cfg(feature = "integer")]
+                tuple.serialize_element(<_ as std::borrow::Borrow<$integer_key>>::borrow(
+                    &self.integer_key,
+                ))?;
+                tuple.end()
+            }
+        }
+
+        ::paste::paste! {
+            struct [<$key Visitor>]<'a> {
+                mismatch:
+                    Option<&'a std::cell::Cell<Option<crate::typed_api::errors::KeyHeaderMismatch>>>,
+            }
+
+            impl<'de, 'a> serde::de::Visitor<'de> for [<$key Visitor>]<'a> {
+                type Value = $key;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str(concat!("struct ", stringify!($key)))
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: serde::de::SeqAccess<'de>,
+                {
+                    let config = crate::typed_api::keys::header::deserialize_header_and_config(
+                        &mut seq,
+                        self.mismatch,
+                    )?;
+                    #[cfg(feature = "boolean")]
+                    let boolean_key = crate::typed_api::keys::header::next_key::<
+                        _,
+                        $boolean_key,
+                    >(&mut seq, "boolean_key")?;
+                    #[cfg(feature = "shortint")]
+                    let shortint_key = crate::typed_api::keys::header::next_key::<
+                        _,
+                        $shortint_key,
+                    >(&mut seq, "shortint_key")?;
+                    #[cfg(feature = "integer")]
+                    let integer_key = crate::typed_api::keys::header::next_key::<
+                        _,
+                        $integer_key,
+                    >(&mut seq, "integer_key")?;
+
+                    Ok($key {
+                        config,
+                        #[cfg(feature = "boolean")]
+                        boolean_key: $wrap(boolean_key),
+                        #[cfg(feature = "shortint")]
+                        shortint_key: $wrap(shortint_key),
+                        #[cfg(feature = "integer")]
+                        integer_key: $wrap(integer_key),
+                    })
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $key {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_tuple(
+                        crate::typed_api::keys::header::serialized_len(),
+                        [<$key Visitor>] { mismatch: None },
+                    )
+                }
+            }
+
+            impl $key {
+                /// Deserializes the key
+                ///
+                /// Contrary to the `Deserialize` implementation, a key serialized by
+                /// an incompatible build is reported as
+                /// [Error::KeyHeaderMismatch](crate::typed_api::Error::KeyHeaderMismatch).
+                pub fn deserialize_checked<'de, D>(
+                    deserializer: D,
+                ) -> Result<Self, crate::typed_api::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let mismatch = std::cell::Cell::new(None);
+                    deserializer
+                        .deserialize_tuple(
+                            crate::typed_api::keys::header::serialized_len(),
+                            [<$key Visitor>] { mismatch: Some(&mismatch) },
+                        )
+                        .map_err(|error| match mismatch.take() {
+                            Some(mismatch) => crate::typed_api::Error::KeyHeaderMismatch(mismatch),
+                            None => crate::typed_api::Error::Deserialization(error.to_string()),
+                        })
+                }
+            }
+        }
+    };
+}
@@ -1,12 +1,15 @@
 #[macro_use]
+mod header;
+#[macro_use]
 mod client;
 #[macro_use]
 mod public;
 mod server;
 
 pub use client::{ClientKey, RefKeyFromKeyChain};
+pub use header::KeyHeader;
 pub use public::{PublicKey, RefKeyFromPublicKeyChain};
-pub use server::ServerKey;
+pub use server::{CompressedServerKey, ServerKey};
 
 use crate::typed_api::config::Config;
 
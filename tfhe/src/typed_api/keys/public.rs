@@ -10,9 +10,16 @@ use crate::typed_api::integers::IntegerPublicKey;
 #[cfg(feature = "shortint")]
 use crate::typed_api::shortints::ShortIntPublicKey;
 
+use crate::typed_api::config::Config;
+
 use super::ClientKey;
 
+/// Key that can be made public, and that allows to encrypt (only)
+///
+/// It can be serialized, in which case the [Config] it was generated with
+/// is also recorded (see [KeyHeader](crate::typed_api::KeyHeader)).
 pub struct PublicKey {
+    pub(crate) config: Config,
     #[cfg(feature = "boolean")]
     pub(crate) boolean_key: BooleanPublicKey,
     #[cfg(feature = "shortint")]
@@ -28,6 +35,7 @@ impl PublicKey {
         let _ = client_key;
 
         Self {
+            config: client_key.config.clone(),
             #[cfg(feature = "boolean")]
             boolean_key: BooleanPublicKey::new(&client_key.boolean_key),
             #[cfg(feature = "shortint")]
@@ -36,8 +44,22 @@ impl PublicKey {
             integer_key: IntegerPublicKey::new(&client_key.integer_key),
         }
     }
+
+    /// Returns the config this key was generated with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
 }
 
+impl_serde_for_key_with_header!(
+    PublicKey {
+        boolean_key: BooleanPublicKey,
+        shortint_key: ShortIntPublicKey,
+        integer_key: IntegerPublicKey,
+    }
+    wrapped_with: std::convert::identity
+);
+
 /// Trait to be implemented on the public key types that have a corresponding member
 /// in the `PublicKey`.
 ///
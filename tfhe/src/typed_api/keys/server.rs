@@ -1,13 +1,15 @@
 #[cfg(feature = "boolean")]
-use crate::typed_api::booleans::BooleanServerKey;
+use crate::typed_api::booleans::{BooleanCompressedServerKey, BooleanServerKey};
 #[cfg(feature = "integer")]
-use crate::typed_api::integers::IntegerServerKey;
+use crate::typed_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
 #[cfg(feature = "shortint")]
-use crate::typed_api::shortints::ShortIntServerKey;
+use crate::typed_api::shortints::{ShortIntCompressedServerKey, ShortIntServerKey};
 
 #[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
 use std::sync::Arc;
 
+use crate::typed_api::config::{Config, ConfigBuilder};
+
 use super::ClientKey;
 
 /// Key of the server
@@ -20,8 +22,11 @@ use super::ClientKey;
 // Keys are stored in an Arc, so that cloning them is cheap
 // (compared to an actual clone hundreds of MB / GB), and cheap cloning is needed for
 // multithreading with less overhead)
-#[derive(Clone, Default)]
+//
+// It can be serialized, in which case the Config it was generated with is also recorded.
+#[derive(Clone)]
 pub struct ServerKey {
+    pub(crate) config: Config,
     #[cfg(feature = "boolean")]
     pub(crate) boolean_key: Arc<BooleanServerKey>,
     #[cfg(feature = "shortint")]
@@ -34,6 +39,7 @@ impl ServerKey {
     #[allow(unused_variables)]
     pub(crate) fn new(keys: &ClientKey) -> Self {
         Self {
+            config: keys.config.clone(),
             #[cfg(feature = "boolean")]
             boolean_key: Arc::new(BooleanServerKey::new(&keys.boolean_key)),
             #[cfg(feature = "shortint")]
@@ -42,4 +48,117 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.integer_key)),
         }
     }
+
+    /// Returns the config this key was generated with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+}
+
+impl Default for ServerKey {
+    fn default() -> Self {
+        Self {
+            config: ConfigBuilder::all_disabled().build(),
+            #[cfg(feature = "boolean")]
+            boolean_key: Default::default(),
+            #[cfg(feature = "shortint")]
+            shortint_key: Default::default(),
+            #[cfg(feature = "integer")]
+            integer_key: Default::default(),
+        }
+    }
 }
+
+impl_serde_for_key_with_header!(
+    ServerKey {
+        boolean_key: BooleanServerKey,
+        shortint_key: ShortIntServerKey,
+        integer_key: IntegerServerKey,
+    }
+    wrapped_with: Arc::new
+);
+
+/// Compressed version of the [ServerKey]
+///
+/// It takes less space than the [ServerKey], which makes it
+/// better suited to be sent by the client to the server.
+///
+/// It has to be decompressed before the server can use it.
+///
+/// The WoPBS keys of the integer types have no compressed form,
+/// so they are not part of this key: a [ServerKey] obtained by decompressing it
+/// cannot be used for the `FheBootstrap` operations on integers.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shortint")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tfhe::prelude::*;
+/// use tfhe::{set_server_key, ClientKey, CompressedServerKey, ConfigBuilder, FheUint2};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+/// let client_key = ClientKey::generate(config);
+/// let compressed_server_key = CompressedServerKey::new(&client_key);
+///
+/// set_server_key(compressed_server_key.decompress());
+///
+/// let a = FheUint2::try_encrypt(1, &client_key)?;
+/// let b = FheUint2::try_encrypt(2, &client_key)?;
+/// let decrypted: u8 = (a + b).decrypt(&client_key);
+/// assert_eq!(decrypted, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CompressedServerKey {
+    pub(crate) config: Config,
+    #[cfg(feature = "boolean")]
+    pub(crate) boolean_key: BooleanCompressedServerKey,
+    #[cfg(feature = "shortint")]
+    pub(crate) shortint_key: ShortIntCompressedServerKey,
+    #[cfg(feature = "integer")]
+    pub(crate) integer_key: IntegerCompressedServerKey,
+}
+
+impl CompressedServerKey {
+    #[allow(unused_variables)]
+    pub fn new(keys: &ClientKey) -> Self {
+        Self {
+            config: keys.config.clone(),
+            #[cfg(feature = "boolean")]
+            boolean_key: BooleanCompressedServerKey::new(&keys.boolean_key),
+            #[cfg(feature = "shortint")]
+            shortint_key: ShortIntCompressedServerKey::new(&keys.shortint_key),
+            #[cfg(feature = "integer")]
+            integer_key: IntegerCompressedServerKey::new(&keys.integer_key),
+        }
+    }
+
+    /// Returns the config this key was generated with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Decompresses the key, so that it can be used by the server
+    pub fn decompress(self) -> ServerKey {
+        ServerKey {
+            config: self.config,
+            #[cfg(feature = "boolean")]
+            boolean_key: Arc::new(self.boolean_key.into()),
+            #[cfg(feature = "shortint")]
+            shortint_key: Arc::new(self.shortint_key.into()),
+            #[cfg(feature = "integer")]
+            integer_key: Arc::new(self.integer_key.into()),
+        }
+    }
+}
+
+impl_serde_for_key_with_header!(
+    CompressedServerKey {
+        boolean_key: BooleanCompressedServerKey,
+        shortint_key: ShortIntCompressedServerKey,
+        integer_key: IntegerCompressedServerKey,
+    }
+    wrapped_with: std::convert::identity
+);
@@ -1,8 +1,11 @@
 #![allow(unused_doc_comments)]
 pub use config::{Config, ConfigBuilder};
 pub use errors::{Error, OutOfRangeError};
-pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
-pub use keys::{generate_keys, ClientKey, PublicKey, ServerKey};
+pub use global_state::{
+    set_global_server_key, set_server_key, unset_global_server_key, unset_server_key,
+    with_server_key, with_server_key_as_context,
+};
+pub use keys::{generate_keys, ClientKey, CompressedServerKey, KeyHeader, PublicKey, ServerKey};
 
 #[cfg(test)]
 mod tests;
@@ -11,13 +14,16 @@ mod tests;
 pub use crate::typed_api::booleans::{CompressedFheBool, FheBool, FheBoolParameters};
 #[cfg(feature = "integer")]
 pub use crate::typed_api::integers::{
-    CrtParameters, FheUint10, FheUint12, FheUint14, FheUint16, FheUint256, FheUint8,
-    GenericInteger, RadixParameters,
+    CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
+    CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
+    CompressedFheUint8, CompressedGenericInteger, CrtParameters, FheUint10, FheUint12, FheUint128,
+    FheUint14, FheUint16, FheUint256, FheUint32, FheUint64, FheUint8, GenericInteger,
+    GenericIntegerBool, RadixParameters,
 };
 #[cfg(feature = "shortint")]
 pub use crate::typed_api::shortints::{
     CompressedFheUint2, CompressedFheUint3, CompressedFheUint4, FheUint2, FheUint2Parameters,
-    FheUint3, FheUint3Parameters, FheUint4, FheUint4Parameters,
+    FheUint3, FheUint3Parameters, FheUint4, FheUint4Parameters, GenericShortIntBool,
 };
 #[macro_use]
 mod details;
@@ -1,8 +1,11 @@
-pub(crate) use keys::{ShortIntClientKey, ShortIntConfig, ShortIntPublicKey, ShortIntServerKey};
+pub(crate) use keys::{
+    ShortIntClientKey, ShortIntCompressedServerKey, ShortIntConfig, ShortIntPublicKey,
+    ShortIntServerKey,
+};
 pub use types::{
     CompressedFheUint2, CompressedFheUint3, CompressedFheUint4, CompressedGenericShortint,
     FheUint2, FheUint2Parameters, FheUint3, FheUint3Parameters, FheUint4, FheUint4Parameters,
-    GenericShortInt,
+    GenericShortInt, GenericShortIntBool,
 };
 
 mod client_key;
@@ -5,11 +5,11 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "internal-keycache")]
 use crate::shortint::keycache::KEY_CACHE;
-use crate::shortint::ServerKey;
+use crate::shortint::{CompressedServerKey, ServerKey};
 
 use super::client_key::GenericShortIntClientKey;
 use super::parameters::ShortIntegerParameter;
-use super::types::GenericShortInt;
+use super::types::{GenericShortInt, GenericShortIntBool};
 
 /// The internal key of a short integer type
 ///
@@ -248,143 +248,136 @@ where
         &self,
         lhs: &GenericShortInt<P>,
         rhs: &GenericShortInt<P>,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_less(
             &mut lhs.ciphertext.borrow_mut(),
             &mut rhs.ciphertext.borrow_mut(),
         );
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_less_or_equal(
         &self,
         lhs: &GenericShortInt<P>,
         rhs: &GenericShortInt<P>,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_less_or_equal(
             &mut lhs.ciphertext.borrow_mut(),
             &mut rhs.ciphertext.borrow_mut(),
         );
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_greater(
         &self,
         lhs: &GenericShortInt<P>,
         rhs: &GenericShortInt<P>,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_greater(
             &mut lhs.ciphertext.borrow_mut(),
             &mut rhs.ciphertext.borrow_mut(),
         );
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_greater_or_equal(
         &self,
         lhs: &GenericShortInt<P>,
         rhs: &GenericShortInt<P>,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_greater_or_equal(
             &mut lhs.ciphertext.borrow_mut(),
             &mut rhs.ciphertext.borrow_mut(),
         );
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_equal(
         &self,
         lhs: &GenericShortInt<P>,
         rhs: &GenericShortInt<P>,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_equal(
             &mut lhs.ciphertext.borrow_mut(),
             &mut rhs.ciphertext.borrow_mut(),
         );
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
+    }
+
+    pub(crate) fn smart_not_equal(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: &GenericShortInt<P>,
+    ) -> GenericShortIntBool<P> {
+        let ciphertext = self.key.smart_not_equal(
+            &mut lhs.ciphertext.borrow_mut(),
+            &mut rhs.ciphertext.borrow_mut(),
+        );
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_equal(
         &self,
         lhs: &GenericShortInt<P>,
         scalar: u8,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self
             .key
             .smart_scalar_equal(&lhs.ciphertext.borrow(), scalar);
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
+    }
+
+    pub(crate) fn smart_scalar_not_equal(
+        &self,
+        lhs: &GenericShortInt<P>,
+        scalar: u8,
+    ) -> GenericShortIntBool<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_not_equal(&lhs.ciphertext.borrow(), scalar);
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_greater_or_equal(
         &self,
         lhs: &GenericShortInt<P>,
         scalar: u8,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self
             .key
             .smart_scalar_greater_or_equal(&lhs.ciphertext.borrow(), scalar);
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_less_or_equal(
         &self,
         lhs: &GenericShortInt<P>,
         scalar: u8,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self
             .key
             .smart_scalar_less_or_equal(&lhs.ciphertext.borrow(), scalar);
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_greater(
         &self,
         lhs: &GenericShortInt<P>,
         scalar: u8,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self
             .key
             .smart_scalar_greater(&lhs.ciphertext.borrow(), scalar);
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_less(
         &self,
         lhs: &GenericShortInt<P>,
         scalar: u8,
-    ) -> GenericShortInt<P> {
+    ) -> GenericShortIntBool<P> {
         let ciphertext = self.key.smart_scalar_less(&lhs.ciphertext.borrow(), scalar);
-        GenericShortInt {
-            ciphertext: RefCell::new(ciphertext),
-            id: lhs.id,
-        }
+        GenericShortIntBool::new(ciphertext, lhs.id)
     }
 
     pub(crate) fn smart_scalar_left_shift(
@@ -439,6 +432,31 @@ where
         }
     }
 
+    pub(crate) fn smart_if_then_else(
+        &self,
+        condition: &GenericShortIntBool<P>,
+        then_value: &GenericShortInt<P>,
+        else_value: &GenericShortInt<P>,
+    ) -> GenericShortInt<P> {
+        let mut condition = condition.ciphertext.clone();
+        let mut then_part = self.key.smart_evaluate_bivariate_function(
+            &mut condition,
+            &mut then_value.ciphertext.borrow_mut(),
+            |condition, value| if condition == 1 { value } else { 0 },
+        );
+        let mut else_part = self.key.smart_evaluate_bivariate_function(
+            &mut condition,
+            &mut else_value.ciphertext.borrow_mut(),
+            |condition, value| if condition == 1 { 0 } else { value },
+        );
+        // One of the two parts is always zero
+        let ciphertext = self.key.smart_add(&mut then_part, &mut else_part);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: then_value.id,
+        }
+    }
+
     pub(crate) fn smart_neg(&self, lhs: &GenericShortInt<P>) -> GenericShortInt<P> {
         let ciphertext = self.key.smart_neg(&mut lhs.ciphertext.borrow_mut());
         GenericShortInt {
@@ -497,3 +515,36 @@ where
         }
     }
 }
+
+/// The compressed version of [GenericShortIntServerKey]
+///
+/// A wrapper around `tfhe-shortint` `CompressedServerKey`
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenericShortIntCompressedServerKey<P: ShortIntegerParameter> {
+    pub(super) key: CompressedServerKey,
+    _marker: PhantomData<P>,
+}
+
+impl<P> GenericShortIntCompressedServerKey<P>
+where
+    P: ShortIntegerParameter,
+{
+    pub(crate) fn new(client_key: &GenericShortIntClientKey<P>) -> Self {
+        Self {
+            key: CompressedServerKey::new(&client_key.key),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<P> From<GenericShortIntCompressedServerKey<P>> for GenericShortIntServerKey<P>
+where
+    P: ShortIntegerParameter,
+{
+    fn from(compressed: GenericShortIntCompressedServerKey<P>) -> Self {
+        Self {
+            key: compressed.key.into(),
+            _marker: Default::default(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::typed_api::prelude::*;
-use crate::typed_api::{generate_keys, CompressedFheUint2, ConfigBuilder, FheUint2};
+use crate::typed_api::{
+    generate_keys, set_server_key, CompressedFheUint2, ConfigBuilder, FheUint2,
+};
 
 #[test]
 fn test_shortint_compressed() {
@@ -11,3 +13,40 @@ fn test_shortint_compressed() {
     let decompressed = a.decrypt(&client_key);
     assert_eq!(decompressed, 2);
 }
+
+#[test]
+fn test_uint2_compare() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    for clear_a in 0..4u8 {
+        for clear_b in 0..4u8 {
+            let a = FheUint2::try_encrypt(clear_a, &client_key).unwrap();
+            let b = FheUint2::try_encrypt(clear_b, &client_key).unwrap();
+
+            let decrypted_result: bool = a.eq(&b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a == clear_b);
+            let decrypted_result: bool = a.ne(&b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a != clear_b);
+            let decrypted_result: bool = a.lt(&b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a < clear_b);
+            let decrypted_result: bool = a.ge(clear_b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a >= clear_b);
+
+            let decrypted_result: u8 = a.max(&b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a.max(clear_b));
+            let decrypted_result: u8 = a.min(&b).decrypt(&client_key);
+            assert_eq!(decrypted_result, clear_a.min(clear_b));
+
+            let result = (a.gt(0) & !b.eq(3)).select(&a, &b);
+            let decrypted_result: u8 = result.decrypt(&client_key);
+            let expected = if clear_a > 0 && clear_b != 3 {
+                clear_a
+            } else {
+                clear_b
+            };
+            assert_eq!(decrypted_result, expected);
+        }
+    }
+}
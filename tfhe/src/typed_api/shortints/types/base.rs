@@ -17,7 +17,7 @@ use crate::typed_api::traits::{
 };
 use crate::typed_api::PublicKey;
 
-use super::{GenericShortIntClientKey, GenericShortIntServerKey};
+use super::{GenericShortIntBool, GenericShortIntClientKey, GenericShortIntServerKey};
 
 use crate::typed_api::shortints::parameters::{ShortIntegerParameter, StaticShortIntegerParameter};
 use crate::typed_api::shortints::public_key::GenericShortIntPublicKey;
@@ -293,29 +293,49 @@ where
     }
 }
 
+impl<P> GenericShortInt<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+{
+    pub fn max(&self, rhs: &Self) -> Self {
+        if std::ptr::eq(self, rhs) {
+            return self.clone();
+        }
+        self.bivariate_function(rhs, std::cmp::max)
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        if std::ptr::eq(self, rhs) {
+            return self.clone();
+        }
+        self.bivariate_function(rhs, std::cmp::min)
+    }
+}
+
 impl<P> FheOrd<u8> for GenericShortInt<P>
 where
     P: ShortIntegerParameter,
     P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
 {
-    type Output = Self;
+    type Output = GenericShortIntBool<P>;
 
-    fn lt(&self, rhs: u8) -> Self {
+    fn lt(&self, rhs: u8) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_scalar_less(self, rhs))
     }
 
-    fn le(&self, rhs: u8) -> Self {
+    fn le(&self, rhs: u8) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_scalar_less_or_equal(self, rhs))
     }
 
-    fn gt(&self, rhs: u8) -> Self {
+    fn gt(&self, rhs: u8) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_scalar_greater(self, rhs))
     }
 
-    fn ge(&self, rhs: u8) -> Self {
+    fn ge(&self, rhs: u8) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_scalar_greater_or_equal(self, rhs))
     }
@@ -326,12 +346,17 @@ where
     P: ShortIntegerParameter,
     P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
 {
-    type Output = Self;
+    type Output = GenericShortIntBool<P>;
 
     fn eq(&self, rhs: u8) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_scalar_equal(self, rhs))
     }
+
+    fn ne(&self, rhs: u8) -> Self::Output {
+        self.id
+            .with_unwrapped_global(|server_key| server_key.smart_scalar_not_equal(self, rhs))
+    }
 }
 
 impl<P, B> FheOrd<B> for GenericShortInt<P>
@@ -340,7 +365,7 @@ where
     P: ShortIntegerParameter,
     P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
 {
-    type Output = Self;
+    type Output = GenericShortIntBool<P>;
 
     fn lt(&self, other: B) -> Self::Output {
         self.id
@@ -371,12 +396,17 @@ where
     P: ShortIntegerParameter,
     P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
 {
-    type Output = Self;
+    type Output = GenericShortIntBool<P>;
 
-    fn eq(&self, other: B) -> Self {
+    fn eq(&self, other: B) -> Self::Output {
         self.id
             .with_unwrapped_global(|server_key| server_key.smart_equal(self, other.borrow()))
     }
+
+    fn ne(&self, other: B) -> Self::Output {
+        self.id
+            .with_unwrapped_global(|server_key| server_key.smart_not_equal(self, other.borrow()))
+    }
 }
 
 impl<P> FheBootstrap for GenericShortInt<P>
@@ -0,0 +1,160 @@
+use std::borrow::Borrow;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::shortint::ciphertext::CiphertextBig as ShortintCiphertext;
+use crate::typed_api::global_state::WithGlobalKey;
+use crate::typed_api::keys::{ClientKey, RefKeyFromKeyChain};
+use crate::typed_api::shortints::parameters::ShortIntegerParameter;
+use crate::typed_api::traits::FheDecrypt;
+
+use super::{GenericShortInt, GenericShortIntClientKey, GenericShortIntServerKey};
+
+/// The encrypted boolean returned by comparisons of [GenericShortInt]
+///
+/// It is a shortint ciphertext encrypting either 1 (true) or 0 (false),
+/// and uses the keys of the short integer type that produced it.
+///
+/// It supports the `&`, `|`, `^` and `!` operators,
+/// and can be used to [select](Self::select) between two short integers.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shortint")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint2};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint2::try_encrypt(1, &client_key)?;
+/// let b = FheUint2::try_encrypt(3, &client_key)?;
+///
+/// let is_in_range = a.ge(1) & a.le(&b);
+/// let decrypted: bool = is_in_range.decrypt(&client_key);
+/// assert!(decrypted);
+///
+/// let min = a.lt(&b).select(&a, &b);
+/// let decrypted: u8 = min.decrypt(&client_key);
+/// assert_eq!(decrypted, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "shortint"))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenericShortIntBool<P: ShortIntegerParameter> {
+    pub(in crate::typed_api::shortints) ciphertext: ShortintCiphertext,
+    pub(in crate::typed_api::shortints) id: P::Id,
+}
+
+impl<P> GenericShortIntBool<P>
+where
+    P: ShortIntegerParameter,
+{
+    pub(in crate::typed_api::shortints) fn new(ciphertext: ShortintCiphertext, id: P::Id) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<P> GenericShortIntBool<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+{
+    /// Returns `then_value` if self encrypts true, `else_value` otherwise
+    pub fn select(
+        &self,
+        then_value: &GenericShortInt<P>,
+        else_value: &GenericShortInt<P>,
+    ) -> GenericShortInt<P> {
+        self.id.with_unwrapped_global(|server_key| {
+            server_key.smart_if_then_else(self, then_value, else_value)
+        })
+    }
+}
+
+impl<P> FheDecrypt<bool> for GenericShortIntBool<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = GenericShortIntClientKey<P>>,
+{
+    fn decrypt(&self, key: &ClientKey) -> bool {
+        let key = self.id.unwrapped_ref_key(key);
+        key.key.decrypt(&self.ciphertext) != 0
+    }
+}
+
+// The ciphertexts only ever encrypt 0 or 1, so the unchecked
+// operations can be used without any carry cleaning.
+macro_rules! short_int_bool_impl_operation (
+    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+        impl<P, B> $trait_name<B> for GenericShortIntBool<P>
+        where
+            B: Borrow<Self>,
+            P: ShortIntegerParameter,
+            P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+        {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $trait_name<B>>::$trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $trait_name<B> for &GenericShortIntBool<P>
+        where
+            B: Borrow<GenericShortIntBool<P>>,
+            P: ShortIntegerParameter,
+            P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+        {
+            type Output = GenericShortIntBool<P>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|server_key| {
+                    server_key
+                        .key
+                        .$key_method(&self.ciphertext, &rhs.borrow().ciphertext)
+                });
+                GenericShortIntBool::new(ciphertext, self.id)
+            }
+        }
+    }
+);
+
+short_int_bool_impl_operation!(BitAnd(bitand) => unchecked_bitand);
+short_int_bool_impl_operation!(BitOr(bitor) => unchecked_bitor);
+short_int_bool_impl_operation!(BitXor(bitxor) => unchecked_bitxor);
+
+impl<P> Not for GenericShortIntBool<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+impl<P> Not for &GenericShortIntBool<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+{
+    type Output = GenericShortIntBool<P>;
+
+    fn not(self) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|server_key| {
+            let accumulator = server_key.key.generate_accumulator(|x| u64::from(x == 0));
+            server_key
+                .key
+                .apply_lookup_table(&self.ciphertext, &accumulator)
+        });
+        GenericShortIntBool::new(ciphertext, self.id)
+    }
+}
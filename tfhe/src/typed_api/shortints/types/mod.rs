@@ -1,4 +1,5 @@
 pub use base::GenericShortInt;
+pub use boolean::GenericShortIntBool;
 pub use compressed::CompressedGenericShortint;
 
 pub use static_::{
@@ -8,8 +9,9 @@ pub use static_::{
 
 use super::client_key::GenericShortIntClientKey;
 use super::public_key::GenericShortIntPublicKey;
-use super::server_key::GenericShortIntServerKey;
+use super::server_key::{GenericShortIntCompressedServerKey, GenericShortIntServerKey};
 
 mod base;
+mod boolean;
 mod compressed;
 pub(crate) mod static_;
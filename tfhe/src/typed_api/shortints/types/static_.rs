@@ -8,7 +8,10 @@ use crate::shortint::parameters::{
 
 use crate::typed_api::shortints::{CompressedGenericShortint, GenericShortInt};
 
-use super::{GenericShortIntClientKey, GenericShortIntPublicKey, GenericShortIntServerKey};
+use super::{
+    GenericShortIntClientKey, GenericShortIntCompressedServerKey, GenericShortIntPublicKey,
+    GenericShortIntServerKey,
+};
 
 use crate::typed_api::shortints::parameters::{ShortIntegerParameter, StaticShortIntegerParameter};
 
@@ -19,7 +22,7 @@ use paste::paste;
 /// It allows to customize the same parameters as the ones
 /// from the underlying `crate::shortint` with the exception of
 /// the number of bits of message as its embeded in the type.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct ShortIntegerParameterSet<const MESSAGE_BITS: u8> {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
@@ -162,6 +165,7 @@ macro_rules! static_shortint_type {
             pub(in crate::typed_api) type [<$name ClientKey>] = GenericShortIntClientKey<[<$name Parameters>]>;
             pub(in crate::typed_api) type [<$name PublicKey>] = GenericShortIntPublicKey<[<$name Parameters>]>;
             pub(in crate::typed_api) type [<$name ServerKey>] = GenericShortIntServerKey<[<$name Parameters>]>;
+            pub(in crate::typed_api) type [<$name CompressedServerKey>] = GenericShortIntCompressedServerKey<[<$name Parameters>]>;
 
             $(#[$outer])*
             #[doc=concat!("An unsigned integer type with ", stringify!($num_bits), " bits.")]
@@ -80,3 +80,152 @@ fn test_with_context() {
     let d = r.decrypt(&cks);
     assert!(d);
 }
+
+#[cfg(feature = "boolean")]
+#[test]
+fn test_serialize_keys() {
+    use crate::typed_api::{set_server_key, Error, KeyHeader, ServerKey};
+    use bincode::Options;
+
+    let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+
+    let (cks, sks) = generate_keys(config);
+    let pks = PublicKey::new(&cks);
+
+    let cks: ClientKey = bincode::deserialize(&bincode::serialize(&cks).unwrap()).unwrap();
+    let pks: PublicKey = bincode::deserialize(&bincode::serialize(&pks).unwrap()).unwrap();
+    let serialized_sks = bincode::serialize(&sks).unwrap();
+    let sks: ServerKey = bincode::deserialize(&serialized_sks).unwrap();
+
+    assert!(cks.config().boolean_config.bool_params.is_some());
+    assert!(pks.config().boolean_config.bool_params.is_some());
+    assert!(sks.config().boolean_config.bool_params.is_some());
+
+    set_server_key(sks);
+    let a = FheBool::encrypt(true, &cks);
+    let b = FheBool::encrypt(true, &pks);
+    let c = a & b;
+    let decrypted = c.decrypt(&cks);
+    assert!(decrypted);
+
+    let header: KeyHeader = bincode::deserialize(&serialized_sks).unwrap();
+    assert_eq!(header, KeyHeader::current());
+    let header_len = bincode::serialized_size(&header).unwrap() as usize;
+
+    // Pretend the key was serialized by a build with a different set of features,
+    // or by a different version of the crate
+    let mismatched_headers = [
+        KeyHeader {
+            integer: !header.integer,
+            ..header.clone()
+        },
+        KeyHeader {
+            crate_version: "0.0.0".to_string(),
+            ..header.clone()
+        },
+        KeyHeader {
+            format_version: header.format_version + 1,
+            ..header.clone()
+        },
+    ];
+    for mismatched_header in mismatched_headers {
+        let mut serialized_sks = serialized_sks.clone();
+        serialized_sks.splice(
+            ..header_len,
+            bincode::serialize(&mismatched_header).unwrap(),
+        );
+
+        let header: KeyHeader = bincode::deserialize(&serialized_sks).unwrap();
+        let error = header.check_compatibility().unwrap_err();
+        assert_eq!(error.expected, KeyHeader::current());
+        assert_eq!(error.found, mismatched_header);
+        assert!(bincode::deserialize::<ServerKey>(&serialized_sks).is_err());
+
+        let mut deserializer = bincode::Deserializer::from_slice(
+            &serialized_sks,
+            bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .allow_trailing_bytes(),
+        );
+        let error = ServerKey::deserialize_checked(&mut deserializer)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            Error::KeyHeaderMismatch(header.check_compatibility().unwrap_err())
+        );
+    }
+
+    // Errors that are not about the header are still reported
+    let error = ServerKey::deserialize_checked(&mut bincode::Deserializer::from_slice(
+        &serialized_sks[..header_len + 1],
+        bincode::DefaultOptions::new().with_fixint_encoding(),
+    ))
+    .err()
+    .unwrap();
+    assert!(matches!(error, Error::Deserialization(_)));
+}
+
+/// The process-wide keys are visible to all the tests running in parallel,
+/// so the tests setting them hold this lock for writing, and the tests
+/// that run computations on a thread without keys hold it for reading.
+#[cfg(feature = "boolean")]
+static GLOBAL_SERVER_KEY_LOCK: std::sync::RwLock<()> = std::sync::RwLock::new(());
+
+#[cfg(feature = "boolean")]
+#[test]
+fn test_with_server_key() {
+    use crate::typed_api::{set_server_key, unset_server_key, with_server_key, ServerKey};
+
+    let _guard = GLOBAL_SERVER_KEY_LOCK
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let a = FheBool::encrypt(false, &cks);
+    let b = FheBool::encrypt(true, &cks);
+
+    let r = with_server_key(&sks, || &a | &b);
+    assert!(r.decrypt(&cks));
+    // The key was only used for the duration of the call
+    assert!(unset_server_key()
+        .config()
+        .boolean_config
+        .bool_params
+        .is_none());
+    assert!(std::panic::catch_unwind(|| &a & &b).is_err());
+
+    // The keys of the thread are restored, even if the function panics
+    set_server_key(sks.clone());
+    let result = std::panic::catch_unwind(|| {
+        with_server_key(&ServerKey::default(), || panic!("computation failed"));
+    });
+    assert!(result.is_err());
+    let r = &a & &b;
+    assert!(!r.decrypt(&cks));
+}
+
+#[cfg(feature = "boolean")]
+#[test]
+fn test_global_server_key() {
+    use crate::typed_api::{set_global_server_key, unset_global_server_key};
+
+    let _guard = GLOBAL_SERVER_KEY_LOCK
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let a = FheBool::encrypt(true, &cks);
+    let b = FheBool::encrypt(true, &cks);
+
+    set_global_server_key(sks);
+    let r = std::thread::spawn(move || a & b).join().unwrap();
+    assert!(unset_global_server_key().is_some());
+    assert!(r.decrypt(&cks));
+}
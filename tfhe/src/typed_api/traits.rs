@@ -81,6 +81,14 @@ pub trait FheEq<Rhs = Self> {
     type Output;
 
     fn eq(&self, other: Rhs) -> Self::Output;
+
+    /// Tests for inequality, by default the negation of [eq](Self::eq)
+    fn ne(&self, other: Rhs) -> Self::Output
+    where
+        Self::Output: std::ops::Not<Output = Self::Output>,
+    {
+        !self.eq(other)
+    }
 }
 
 /// Trait for fully homomorphic comparisons.
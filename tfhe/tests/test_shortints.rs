@@ -39,23 +39,23 @@ fn test_scalar_comparison_fhe_uint_3() -> Result<(), Box<dyn std::error::Error>>
 
     let mut b = a.eq(2);
     let decrypted = b.decrypt(&keys);
-    assert_eq!(decrypted, 1);
+    assert!(decrypted);
 
     b = a.ge(2);
     let decrypted = b.decrypt(&keys);
-    assert_eq!(decrypted, 1);
+    assert!(decrypted);
 
     b = a.gt(2);
     let decrypted = b.decrypt(&keys);
-    assert_eq!(decrypted, 0);
+    assert!(!decrypted);
 
     b = a.le(2);
     let decrypted = b.decrypt(&keys);
-    assert_eq!(decrypted, 1);
+    assert!(decrypted);
 
     b = a.lt(2);
     let decrypted = b.decrypt(&keys);
-    assert_eq!(decrypted, 0);
+    assert!(!decrypted);
 
     Ok(())
 }
@@ -180,8 +180,9 @@ fn test_branchless_min_max() -> Result<(), Box<dyn std::error::Error>> {
     let x = FheUint4::try_encrypt(12, &keys)?;
     let y = FheUint4::try_encrypt(4, &keys)?;
 
-    let min = &y ^ (&x ^ &y) & -(x.lt(&y));
-    let max = &x ^ (&x ^ &y) & -(x.lt(&y));
+    let x_is_lower = x.lt(&y);
+    let min = x_is_lower.select(&x, &y);
+    let max = x_is_lower.select(&y, &x);
 
     assert_eq!(min.decrypt(&keys), 4);
     assert_eq!(max.decrypt(&keys), 12);